@@ -1,6 +1,6 @@
 use crate::{
-    First, Main, MainSchedulePlugin, PlaceholderPlugin, Plugin, Plugins, PluginsState, SubApp,
-    SubApps,
+    plugin::sort_by_dependencies, First, Main, MainSchedulePlugin, PlaceholderPlugin, Plugin,
+    Plugins, PluginsState, SubApp, SubApps,
 };
 use alloc::{
     boxed::Box,
@@ -48,6 +48,15 @@ pub type InternedAppLabel = Interned<dyn AppLabel>;
 pub(crate) enum AppError {
     #[error("duplicate plugin {plugin_name:?}")]
     DuplicatePlugin { plugin_name: String },
+    #[error(
+        "missing plugin dependencies, add them before or together with the plugins requiring them:{}",
+        missing.iter().map(|(plugin_name, dependencies)| {
+            alloc::format!("\n  - {plugin_name} requires {}", dependencies.join(", "))
+        }).collect::<String>()
+    )]
+    MissingPluginDependencies {
+        missing: Vec<(String, Vec<&'static str>)>,
+    },
 }
 
 /// [`App`] is the primary API for writing user applications. It automates the setup of a
@@ -249,10 +258,15 @@ impl App {
         overall_plugins_state
     }
 
-    /// Runs [`Plugin::finish`] for each plugin. This is usually called by the event loop once all
-    /// plugins are ready, but can be useful for situations where you want to use [`App::update`].
+    /// Runs [`Plugin::finish`] for each plugin, after the plugins it depends on. This is usually
+    /// called by the event loop once all plugins are ready, but can be useful for situations where
+    /// you want to use [`App::update`].
     pub fn finish(&mut self) {
+        #[cfg(feature = "config")]
+        crate::config::apply_to_resources(self.world_mut());
+
         // plugins installed to main should see all sub-apps
+        self.main_mut().sort_plugin_registry();
         let plugins = core::mem::take(&mut self.main_mut().plugin_registry);
         for plugin in &plugins {
             plugin.finish(self);
@@ -261,12 +275,17 @@ impl App {
         main.plugin_registry = plugins;
         main.plugins_state = PluginsState::Finished;
         self.sub_apps.iter_mut().skip(1).for_each(SubApp::finish);
+
+        #[cfg(feature = "config")]
+        crate::config::finish(self);
     }
 
-    /// Runs [`Plugin::cleanup`] for each plugin. This is usually called by the event loop after
-    /// [`App::finish`], but can be useful for situations where you want to use [`App::update`].
+    /// Runs [`Plugin::cleanup`] for each plugin, after the plugins it depends on. This is usually
+    /// called by the event loop after [`App::finish`], but can be useful for situations where you
+    /// want to use [`App::update`].
     pub fn cleanup(&mut self) {
         // plugins installed to main should see all sub-apps
+        self.main_mut().sort_plugin_registry();
         let plugins = core::mem::take(&mut self.main_mut().plugin_registry);
         for plugin in &plugins {
             plugin.cleanup(self);
@@ -474,6 +493,16 @@ impl App {
             .plugin_registry
             .push(Box::new(PlaceholderPlugin));
 
+        // Apply config overrides to the plugin settings before they're used to build it, and
+        // to the resources added so far.
+        #[cfg(feature = "config")]
+        let plugin = {
+            let mut plugin = plugin;
+            crate::config::apply_to_resources(self.world_mut());
+            crate::config::apply_to_plugin(self.world_mut(), plugin.as_mut());
+            plugin
+        };
+
         self.main_mut().plugin_build_depth += 1;
 
         let f = AssertUnwindSafe(|| plugin.build(self));
@@ -498,6 +527,38 @@ impl App {
         Ok(self)
     }
 
+    /// Builds a batch of plugins collected from a single [`App::add_plugins`] call, after sorting
+    /// them by their [dependencies](Plugin::dependencies).
+    ///
+    /// Once the outermost batch is built, panics if any plugin added since is missing a required
+    /// dependency.
+    #[track_caller]
+    pub(crate) fn add_plugin_batch(&mut self, mut batch: Vec<(Box<dyn Plugin>, Option<String>)>) {
+        sort_by_dependencies(&mut batch, |(plugin, _)| &**plugin);
+
+        let first_index = self.main().plugin_registry.len();
+        for (plugin, group_name) in batch {
+            if let Err(AppError::DuplicatePlugin { plugin_name }) = self.add_boxed_plugin(plugin) {
+                match group_name {
+                    Some(group_name) => panic!(
+                        "Error adding plugin {plugin_name} in group {group_name}: plugin was already added in application"
+                    ),
+                    None => panic!(
+                        "Error adding plugin {plugin_name}: : plugin was already added in application"
+                    ),
+                }
+            }
+        }
+
+        // Plugins added while building other plugins are checked along with the outermost batch,
+        // since their dependencies may be added after them by the same parent.
+        if !self.is_building_plugins() {
+            if let Err(error) = self.main().check_plugin_dependencies(first_index) {
+                panic!("{error}");
+            }
+        }
+    }
+
     /// Returns `true` if the [`Plugin`] has already been added.
     pub fn is_plugin_added<T>(&self) -> bool
     where
@@ -565,9 +626,13 @@ impl App {
     ///     .add_plugins((MinimalPlugins, LogPlugin));
     /// ```
     ///
+    /// Plugins added in the same call are built after the plugins they
+    /// [depend on](Plugin::dependencies), regardless of the order they are listed in.
+    ///
     /// # Panics
     ///
-    /// Panics if one of the plugins had already been added to the application.
+    /// Panics if one of the plugins had already been added to the application, or if one of the
+    /// plugins requires a plugin that was neither added before nor in the same call.
     ///
     /// [`PluginGroup`]:super::PluginGroup
     #[track_caller]
@@ -1429,7 +1494,7 @@ mod tests {
         world::{FromWorld, World},
     };
 
-    use crate::{App, AppExit, Plugin, SubApp, Update};
+    use crate::{App, AppExit, Plugin, PluginDependencies, SubApp, Update};
 
     struct PluginA;
     impl Plugin for PluginA {
@@ -1484,6 +1549,83 @@ mod tests {
         App::new().add_plugins((PluginD, PluginD));
     }
 
+    #[derive(Resource, Default)]
+    struct FinishOrder(alloc::vec::Vec<&'static str>);
+
+    struct Dependency;
+    impl Plugin for Dependency {
+        fn build(&self, app: &mut App) {
+            app.init_resource::<FinishOrder>();
+        }
+        fn finish(&self, app: &mut App) {
+            app.world_mut()
+                .resource_mut::<FinishOrder>()
+                .0
+                .push("Dependency");
+        }
+    }
+
+    struct OptionalDependency;
+    impl Plugin for OptionalDependency {
+        fn build(&self, _app: &mut App) {}
+        fn finish(&self, app: &mut App) {
+            app.world_mut()
+                .resource_mut::<FinishOrder>()
+                .0
+                .push("OptionalDependency");
+        }
+    }
+
+    struct Dependent;
+    impl Plugin for Dependent {
+        fn build(&self, app: &mut App) {
+            assert!(app.world().contains_resource::<FinishOrder>());
+        }
+        fn finish(&self, app: &mut App) {
+            app.world_mut()
+                .resource_mut::<FinishOrder>()
+                .0
+                .push("Dependent");
+        }
+        fn dependencies(&self) -> PluginDependencies {
+            PluginDependencies::new()
+                .require::<Dependency>()
+                .optional::<OptionalDependency>()
+        }
+    }
+
+    #[test]
+    fn plugins_are_built_and_finished_after_their_dependencies() {
+        let mut app = App::new();
+        app.add_plugins((Dependent, Dependency))
+            .add_plugins(OptionalDependency);
+        app.finish();
+
+        assert_eq!(
+            app.world().resource::<FinishOrder>().0,
+            ["Dependency", "OptionalDependency", "Dependent"]
+        );
+    }
+
+    #[test]
+    fn optional_dependencies_can_be_missing() {
+        App::new().add_plugins((Dependency, Dependent)).finish();
+    }
+
+    #[test]
+    #[should_panic(expected = "requires bevy_app::app::tests::Dependency")]
+    fn missing_required_dependency_panics() {
+        struct NeedsDependency;
+        impl Plugin for NeedsDependency {
+            fn build(&self, _app: &mut App) {}
+            fn dependencies(&self) -> PluginDependencies {
+                PluginDependencies::new().require::<Dependency>()
+            }
+        }
+
+        App::new().add_plugins(NeedsDependency);
+    }
+
     #[test]
     #[should_panic]
     fn cant_call_app_run_from_plugin_build() {
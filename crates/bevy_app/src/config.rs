@@ -0,0 +1,765 @@
+//! Overrides for plugin settings and resources, loaded at startup from config files and
+//! environment variables.
+
+use crate::{App, Plugin};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_ecs::{
+    reflect::{AppTypeRegistry, ReflectFromWorld, ReflectResource},
+    resource::Resource,
+    world::{Mut, World},
+};
+use bevy_platform_support::collections::{HashMap, HashSet};
+use bevy_reflect::{
+    serde::TypedReflectDeserializer, DynamicEnum, DynamicVariant, PartialReflect, ReflectMut,
+    TypeRegistry,
+};
+use log::{error, warn};
+use serde::de::DeserializeSeed;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+pub use ron::Value as ConfigValue;
+
+/// Overrides for the settings of [`Plugin`]s and for [`Resource`]s, loaded from config files
+/// and environment variables so they can be tweaked without rebuilding the app.
+///
+/// Overrides are keyed by the full [type path] of the plugin or resource, and only need to
+/// list the fields that change:
+///
+/// ```ron
+/// {
+///     "bevy_window::WindowPlugin": (
+///         primary_window: Some((title: "QA build", present_mode: "Immediate")),
+///     ),
+///     "my_game::Difficulty": (enemy_health: 2.5),
+/// }
+/// ```
+///
+/// Structs, tuples and the current variant of enums are overridden field by field. A unit
+/// variant of an enum is selected by writing its name as a string, and an `Option` is cleared
+/// with `None`. Any other value replaces the target entirely, which requires its type to be
+/// registered in the [`AppTypeRegistry`].
+///
+/// Insert the config as a resource before adding the plugins it configures:
+///
+/// ```no_run
+/// # use bevy_app::{App, AppConfig};
+/// App::new()
+///     .insert_resource(AppConfig::load("config.ron", "APP_CONFIG").unwrap());
+///     // .add_plugins(DefaultPlugins)
+/// ```
+///
+/// Plugin overrides are applied right before [`Plugin::build`], to plugins that expose their
+/// settings through [`Plugin::settings_mut`], after registering their types with
+/// [`Plugin::register_settings`]. Resource overrides apply to resources registered
+/// with `#[reflect(Resource)]`, as soon as they exist: before each plugin is built, before
+/// plugins [finish](Plugin::finish), and once they have finished, which also covers the
+/// resources of sub-apps. Resources still missing then are created if their type reflects
+/// `FromWorld`. Overrides that can't be applied are logged and skipped.
+///
+/// [type path]: bevy_reflect::TypePath::type_path
+#[derive(Resource, Debug, Default, Clone)]
+pub struct AppConfig {
+    overrides: HashMap<String, ConfigValue>,
+    applied: HashSet<String>,
+    failed: HashSet<String>,
+}
+
+impl AppConfig {
+    /// Creates a config without any overrides.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads the config file at `path` if it exists, followed by the overrides in the
+    /// `env_var` environment variable if it is set.
+    ///
+    /// See [`with_file`](Self::with_file) and [`with_env`](Self::with_env).
+    pub fn load(path: impl AsRef<Path>, env_var: &str) -> Result<Self, AppConfigError> {
+        let path = path.as_ref();
+        let config = if path.exists() {
+            Self::new().with_file(path)?
+        } else {
+            Self::new()
+        };
+        config.with_env(env_var)
+    }
+
+    /// Merges in the overrides of the config file at `path`.
+    ///
+    /// The format is picked from the file extension: `.ron` files are parsed as RON, and
+    /// `.toml` files as TOML with the `config_toml` feature.
+    pub fn with_file(self, path: impl AsRef<Path>) -> Result<Self, AppConfigError> {
+        let path = path.as_ref();
+        let text = std::fs::read_to_string(path).map_err(|error| AppConfigError::Io {
+            path: path.to_path_buf(),
+            error,
+        })?;
+        match path.extension().and_then(|extension| extension.to_str()) {
+            Some("ron") => self.with_ron(&text),
+            #[cfg(feature = "config_toml")]
+            Some("toml") => self.with_toml(&text),
+            _ => Err(AppConfigError::UnsupportedFormat(path.to_path_buf())),
+        }
+    }
+
+    /// Merges in the RON overrides in the `env_var` environment variable, if it is set.
+    pub fn with_env(self, env_var: &str) -> Result<Self, AppConfigError> {
+        match std::env::var(env_var) {
+            Ok(text) => self.with_ron(&text),
+            Err(_) => Ok(self),
+        }
+    }
+
+    /// Merges in the overrides of a RON document.
+    pub fn with_ron(mut self, text: &str) -> Result<Self, AppConfigError> {
+        let value =
+            ron::from_str(text).map_err(|error| AppConfigError::Parse(error.to_string()))?;
+        self.merge_document(value)?;
+        Ok(self)
+    }
+
+    /// Merges in the overrides of a TOML document.
+    #[cfg(feature = "config_toml")]
+    pub fn with_toml(mut self, text: &str) -> Result<Self, AppConfigError> {
+        let value =
+            toml::from_str(text).map_err(|error| AppConfigError::Parse(error.to_string()))?;
+        self.merge_document(value)?;
+        Ok(self)
+    }
+
+    /// Merges `value` into the overrides for the type with the given [type path].
+    ///
+    /// Maps are merged key by key, and any other value replaces the previous override.
+    ///
+    /// [type path]: bevy_reflect::TypePath::type_path
+    pub fn insert(&mut self, type_path: impl Into<String>, value: ConfigValue) -> &mut Self {
+        let type_path = type_path.into();
+        match self.overrides.get_mut(&type_path) {
+            Some(existing) => merge(existing, value),
+            None => {
+                self.overrides.insert(type_path, value);
+            }
+        }
+        self
+    }
+
+    /// Returns the override for the type with the given [type path], if any.
+    ///
+    /// [type path]: bevy_reflect::TypePath::type_path
+    pub fn get(&self, type_path: &str) -> Option<&ConfigValue> {
+        self.overrides.get(type_path)
+    }
+
+    /// Applies the override for the type of `target`, if any.
+    ///
+    /// Returns `true` if there was an override to apply.
+    pub fn apply(
+        &self,
+        target: &mut dyn PartialReflect,
+        registry: &TypeRegistry,
+    ) -> Result<bool, AppConfigError> {
+        let type_path = target.reflect_type_path().to_string();
+        let Some(value) = self.overrides.get(&type_path) else {
+            return Ok(false);
+        };
+        apply_value(target, value, registry, &type_path)?;
+        Ok(true)
+    }
+
+    /// Returns `true` if the override for `type_path` hasn't been applied yet, nor failed to.
+    fn is_pending(&self, type_path: &str) -> bool {
+        !self.applied.contains(type_path) && !self.failed.contains(type_path)
+    }
+
+    fn merge_document(&mut self, document: ConfigValue) -> Result<(), AppConfigError> {
+        let ConfigValue::Map(overrides) = document else {
+            return Err(AppConfigError::NotAMap);
+        };
+        for (type_path, value) in overrides {
+            let ConfigValue::String(type_path) = type_path else {
+                return Err(AppConfigError::NotAMap);
+            };
+            self.insert(type_path, value);
+        }
+        Ok(())
+    }
+}
+
+/// An error loading or applying an [`AppConfig`].
+#[derive(Error, Debug)]
+pub enum AppConfigError {
+    /// The config file couldn't be read.
+    #[error("failed to read config file {path:?}: {error}")]
+    Io {
+        /// The path of the config file.
+        path: PathBuf,
+        /// The underlying error.
+        error: std::io::Error,
+    },
+    /// The config file extension doesn't match a supported format.
+    #[error("unsupported config file format {0:?}")]
+    UnsupportedFormat(PathBuf),
+    /// The config couldn't be parsed.
+    #[error("failed to parse config: {0}")]
+    Parse(String),
+    /// The config isn't a map from type paths to overrides.
+    #[error("config must be a map from type paths to overrides")]
+    NotAMap,
+    /// An override doesn't match the type it is applied to.
+    #[error("invalid override for `{path}`: {message}")]
+    InvalidOverride {
+        /// The type path of the overridden type, followed by the path of the invalid field.
+        path: String,
+        /// What went wrong.
+        message: String,
+    },
+}
+
+/// Applies the override for `plugin`, if it has one and exposes its settings.
+pub(crate) fn apply_to_plugin(world: &mut World, plugin: &mut dyn Plugin) {
+    let Some(config) = world.get_resource::<AppConfig>() else {
+        return;
+    };
+    let Some(settings) = plugin.settings_mut() else {
+        return;
+    };
+    let type_path = settings.reflect_type_path().to_string();
+    if config.get(&type_path).is_none() {
+        return;
+    }
+
+    plugin.register_settings(&mut world.resource::<AppTypeRegistry>().write());
+    let settings = plugin.settings_mut().unwrap();
+    let registry = world.resource::<AppTypeRegistry>().read();
+    let result = config.apply(settings, &registry);
+    drop(registry);
+    let mut config = world.resource_mut::<AppConfig>();
+    match result {
+        Ok(_) => {
+            config.applied.insert(type_path);
+        }
+        Err(error) => {
+            error!("{error}");
+            config.failed.insert(type_path);
+        }
+    }
+}
+
+/// Applies the pending overrides for the resources that exist in the main world.
+///
+/// This runs before each plugin is built and before plugins finish, so that plugins see the
+/// overridden values of the resources added before them.
+pub(crate) fn apply_to_resources(world: &mut World) {
+    if !world.contains_resource::<AppConfig>() {
+        return;
+    }
+    world.resource_scope(|world, mut config: Mut<AppConfig>| {
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        apply_resource_overrides(&mut config, &registry.read(), world, false);
+    });
+}
+
+/// Applies the pending overrides for the resources added while plugins finished, in the main
+/// world and in every sub-app, then creates the missing resources that reflect `FromWorld` in
+/// the main world, and warns about the overrides that were never applied.
+pub(crate) fn finish(app: &mut App) {
+    let sub_apps = &mut app.sub_apps;
+    let Some(mut config) = sub_apps.main.world_mut().remove_resource::<AppConfig>() else {
+        return;
+    };
+    let registry = sub_apps.main.world().resource::<AppTypeRegistry>().clone();
+    let registry = registry.read();
+
+    for sub_app in sub_apps.sub_apps.values_mut() {
+        apply_resource_overrides(&mut config, &registry, sub_app.world_mut(), false);
+    }
+    let main_world = sub_apps.main.world_mut();
+    apply_resource_overrides(&mut config, &registry, main_world, true);
+
+    let mut pending: Vec<_> = config
+        .overrides
+        .keys()
+        .filter(|type_path| config.is_pending(type_path))
+        .collect();
+    pending.sort();
+    for type_path in pending {
+        let is_resource = registry
+            .get_with_type_path(type_path)
+            .is_some_and(|registration| registration.data::<ReflectResource>().is_some());
+        if is_resource {
+            warn!("Config override for `{type_path}` was not applied: the resource doesn't exist and can't be created with `FromWorld`");
+        } else {
+            warn!("Config override for `{type_path}` was not applied: it is not a plugin with reflected settings or a resource registered with `#[reflect(Resource)]`");
+        }
+    }
+
+    drop(registry);
+    main_world.insert_resource(config);
+}
+
+/// Applies the pending overrides for the resources that exist in `world`. With
+/// `create_missing`, missing resources whose type reflects `FromWorld` are created first.
+fn apply_resource_overrides(
+    config: &mut AppConfig,
+    registry: &TypeRegistry,
+    world: &mut World,
+    create_missing: bool,
+) {
+    let mut pending: Vec<_> = config
+        .overrides
+        .iter()
+        .filter(|(type_path, _)| config.is_pending(type_path))
+        .map(|(type_path, value)| (type_path.clone(), value.clone()))
+        .collect();
+    pending.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+    for (type_path, value) in pending {
+        let Some(registration) = registry.get_with_type_path(&type_path) else {
+            continue;
+        };
+        let Some(reflect_resource) = registration.data::<ReflectResource>() else {
+            continue;
+        };
+
+        let result = match reflect_resource.reflect_mut(world) {
+            Some(mut resource) => apply_value(
+                resource.as_partial_reflect_mut(),
+                &value,
+                registry,
+                &type_path,
+            ),
+            None => {
+                let Some(reflect_from_world) = registration
+                    .data::<ReflectFromWorld>()
+                    .filter(|_| create_missing)
+                else {
+                    continue;
+                };
+                let mut resource = reflect_from_world.from_world(world);
+                apply_value(
+                    resource.as_partial_reflect_mut(),
+                    &value,
+                    registry,
+                    &type_path,
+                )
+                .map(|()| reflect_resource.insert(world, resource.as_partial_reflect(), registry))
+            }
+        };
+        match result {
+            Ok(()) => {
+                config.applied.insert(type_path);
+            }
+            Err(error) => {
+                error!("{error}");
+                config.failed.insert(type_path);
+            }
+        }
+    }
+}
+
+fn merge(target: &mut ConfigValue, value: ConfigValue) {
+    match (target, value) {
+        (ConfigValue::Map(target), ConfigValue::Map(values)) => {
+            for (key, value) in values {
+                let value = match target.remove(&key) {
+                    Some(mut existing) => {
+                        merge(&mut existing, value);
+                        existing
+                    }
+                    None => value,
+                };
+                target.insert(key, value);
+            }
+        }
+        (target, value) => *target = value,
+    }
+}
+
+fn invalid(path: &str, message: impl Into<String>) -> AppConfigError {
+    AppConfigError::InvalidOverride {
+        path: path.to_string(),
+        message: message.into(),
+    }
+}
+
+fn apply_value(
+    target: &mut dyn PartialReflect,
+    value: &ConfigValue,
+    registry: &TypeRegistry,
+    path: &str,
+) -> Result<(), AppConfigError> {
+    match value {
+        ConfigValue::Map(fields) => {
+            if let ReflectMut::Struct(target) = target.reflect_mut() {
+                for (name, value) in fields.iter() {
+                    let ConfigValue::String(name) = name else {
+                        return Err(invalid(path, "field names must be strings"));
+                    };
+                    let field = target
+                        .field_mut(name)
+                        .ok_or_else(|| invalid(path, format!("no field named `{name}`")))?;
+                    apply_value(field, value, registry, &format!("{path}.{name}"))?;
+                }
+                return Ok(());
+            }
+            if let ReflectMut::Enum(target) = target.reflect_mut() {
+                if target.field_len() > 0 && target.name_at(0).is_some() {
+                    for (name, value) in fields.iter() {
+                        let ConfigValue::String(name) = name else {
+                            return Err(invalid(path, "field names must be strings"));
+                        };
+                        let variant = target.variant_name().to_string();
+                        let field = target.field_mut(name).ok_or_else(|| {
+                            invalid(
+                                path,
+                                format!("variant `{variant}` has no field named `{name}`"),
+                            )
+                        })?;
+                        apply_value(field, value, registry, &format!("{path}.{name}"))?;
+                    }
+                    return Ok(());
+                }
+            }
+        }
+        ConfigValue::Seq(items) => {
+            if let ReflectMut::TupleStruct(target) = target.reflect_mut() {
+                if target.field_len() != items.len() {
+                    return Err(invalid(
+                        path,
+                        format!("expected {} fields", target.field_len()),
+                    ));
+                }
+                for (index, item) in items.iter().enumerate() {
+                    let field = target.field_mut(index).unwrap();
+                    apply_value(field, item, registry, &format!("{path}.{index}"))?;
+                }
+                return Ok(());
+            }
+            if let ReflectMut::Tuple(target) = target.reflect_mut() {
+                if target.field_len() != items.len() {
+                    return Err(invalid(
+                        path,
+                        format!("expected {} fields", target.field_len()),
+                    ));
+                }
+                for (index, item) in items.iter().enumerate() {
+                    let field = target.field_mut(index).unwrap();
+                    apply_value(field, item, registry, &format!("{path}.{index}"))?;
+                }
+                return Ok(());
+            }
+        }
+        ConfigValue::String(variant) => {
+            if let ReflectMut::Enum(target) = target.reflect_mut() {
+                if !is_option(target.reflect_type_path()) {
+                    return target
+                        .try_apply(&DynamicEnum::new(variant.clone(), DynamicVariant::Unit))
+                        .map_err(|error| invalid(path, error.to_string()));
+                }
+            }
+        }
+        ConfigValue::Option(None) => {
+            if let ReflectMut::Enum(target) = target.reflect_mut() {
+                return target
+                    .try_apply(&DynamicEnum::new("None", DynamicVariant::Unit))
+                    .map_err(|error| invalid(path, error.to_string()));
+            }
+        }
+        ConfigValue::Option(Some(inner)) => {
+            if let ReflectMut::Enum(target) = target.reflect_mut() {
+                if target.variant_name() == "Some" {
+                    let field = target.field_at_mut(0).unwrap();
+                    return apply_value(field, inner, registry, &format!("{path}.0"));
+                }
+            }
+        }
+        _ => {}
+    }
+
+    // Formats without options, like TOML, write `Some(value)` as `value`.
+    if let ReflectMut::Enum(target) = target.reflect_mut() {
+        if is_option(target.reflect_type_path()) && !matches!(value, ConfigValue::Option(_)) {
+            return match target.field_at_mut(0) {
+                Some(field) => apply_value(field, value, registry, &format!("{path}.0")),
+                None => replace(
+                    target.as_partial_reflect_mut(),
+                    &ConfigValue::Option(Some(alloc::boxed::Box::new(value.clone()))),
+                    registry,
+                    path,
+                ),
+            };
+        }
+    }
+
+    replace(target, value, registry, path)
+}
+
+/// Replaces `target` with `value`, deserialized as the type of `target`.
+fn replace(
+    target: &mut dyn PartialReflect,
+    value: &ConfigValue,
+    registry: &TypeRegistry,
+    path: &str,
+) -> Result<(), AppConfigError> {
+    let type_info = target
+        .get_represented_type_info()
+        .ok_or_else(|| invalid(path, "the type is not known"))?;
+    let registration = registry.get(type_info.type_id()).ok_or_else(|| {
+        invalid(
+            path,
+            format!("`{}` is not registered", type_info.type_path()),
+        )
+    })?;
+    let replacement = TypedReflectDeserializer::new(registration, registry)
+        .deserialize(value.clone())
+        .map_err(|error| invalid(path, error.to_string()))?;
+    target
+        .try_apply(replacement.as_ref())
+        .map_err(|error| invalid(path, error.to_string()))
+}
+
+fn is_option(type_path: &str) -> bool {
+    type_path.starts_with("core::option::Option<")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AppConfig, ConfigValue};
+    use crate::{App, AppLabel, Plugin, SubApp};
+    use alloc::string::{String, ToString};
+    use bevy_ecs::{
+        reflect::{ReflectFromWorld, ReflectResource},
+        resource::Resource,
+    };
+    use bevy_reflect::{PartialReflect, Reflect};
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    enum Mode {
+        Fast,
+        Slow,
+    }
+
+    #[derive(Reflect, Clone, Debug, PartialEq)]
+    struct Window {
+        title: String,
+        size: (u32, u32),
+    }
+
+    #[derive(Reflect, Clone, Debug)]
+    struct ConfigurablePlugin {
+        mode: Mode,
+        window: Option<Window>,
+        scale: f32,
+    }
+
+    impl Plugin for ConfigurablePlugin {
+        fn build(&self, app: &mut App) {
+            app.insert_resource(Settings(self.clone()));
+        }
+
+        fn settings_mut(&mut self) -> Option<&mut dyn PartialReflect> {
+            Some(self)
+        }
+    }
+
+    #[derive(Resource)]
+    struct Settings(ConfigurablePlugin);
+
+    #[derive(Resource, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Resource, FromWorld)]
+    struct Difficulty {
+        enemy_health: f32,
+        waves: u32,
+    }
+
+    #[test]
+    fn overrides_plugin_settings_and_resources() {
+        let config = AppConfig::new()
+            .with_ron(
+                r#"{
+                    "bevy_app::config::tests::ConfigurablePlugin": (
+                        mode: "Slow",
+                        window: Some((title: "from file")),
+                    ),
+                    "bevy_app::config::tests::Difficulty": (enemy_health: 2.5),
+                }"#,
+            )
+            .unwrap()
+            .with_ron(
+                r#"{
+                    "bevy_app::config::tests::ConfigurablePlugin": (scale: 2),
+                    "bevy_app::config::tests::Difficulty": (waves: 3),
+                }"#,
+            )
+            .unwrap();
+
+        let mut app = App::new();
+        app.register_type::<Difficulty>()
+            .insert_resource(config)
+            .add_plugins(ConfigurablePlugin {
+                mode: Mode::Fast,
+                window: Some(Window {
+                    title: "default".to_string(),
+                    size: (1280, 720),
+                }),
+                scale: 1.0,
+            });
+        app.finish();
+
+        let settings = &app.world().resource::<Settings>().0;
+        assert_eq!(settings.mode, Mode::Slow);
+        assert_eq!(
+            settings.window,
+            Some(Window {
+                title: "from file".to_string(),
+                size: (1280, 720),
+            })
+        );
+        assert_eq!(settings.scale, 2.0);
+        assert_eq!(
+            *app.world().resource::<Difficulty>(),
+            Difficulty {
+                enemy_health: 2.5,
+                waves: 3,
+            }
+        );
+    }
+
+    #[test]
+    fn applies_resource_overrides_as_soon_as_resources_exist() {
+        #[derive(Resource, Reflect, Default)]
+        #[reflect(Resource)]
+        struct Volume {
+            level: f32,
+        }
+
+        #[derive(Resource, Reflect, Default)]
+        #[reflect(Resource)]
+        struct Gamma {
+            value: f32,
+        }
+
+        use crate::{self as bevy_app};
+
+        #[derive(AppLabel, Debug, Clone, PartialEq, Eq, Hash)]
+        struct AudioApp;
+
+        struct EarlyPlugin;
+
+        impl Plugin for EarlyPlugin {
+            fn build(&self, app: &mut App) {
+                app.init_resource::<Difficulty>();
+            }
+        }
+
+        struct LatePlugin;
+
+        impl Plugin for LatePlugin {
+            fn build(&self, app: &mut App) {
+                // Resources added by earlier plugins are overridden before later plugins build.
+                assert_eq!(app.world().resource::<Difficulty>().waves, 3);
+
+                let mut audio_app = SubApp::new();
+                audio_app.insert_resource(Volume { level: 1.0 });
+                app.insert_sub_app(AudioApp, audio_app);
+            }
+
+            fn finish(&self, app: &mut App) {
+                app.insert_resource(Gamma { value: 1.0 });
+            }
+        }
+
+        let config = AppConfig::new()
+            .with_ron(
+                r#"{
+                    "bevy_app::config::tests::Difficulty": (waves: 3),
+                    "bevy_app::config::tests::Volume": (level: 0.5),
+                    "bevy_app::config::tests::Gamma": (value: 2.2),
+                    "bevy_app::config::tests::ConfigurablePlugin": (scale: "large"),
+                }"#,
+            )
+            .unwrap();
+
+        let mut app = App::new();
+        app.register_type::<Difficulty>()
+            .register_type::<Volume>()
+            .register_type::<Gamma>()
+            .insert_resource(config)
+            .add_plugins((EarlyPlugin, LatePlugin))
+            .add_plugins(ConfigurablePlugin {
+                mode: Mode::Fast,
+                window: None,
+                scale: 1.0,
+            });
+        app.finish();
+
+        assert_eq!(app.world().resource::<Gamma>().value, 2.2);
+        assert_eq!(
+            app.sub_app(AudioApp).world().resource::<Volume>().level,
+            0.5
+        );
+
+        // An override that fails to apply isn't reported as applied.
+        assert_eq!(app.world().resource::<Settings>().0.scale, 1.0);
+        let config = app.world().resource::<AppConfig>();
+        assert!(!config
+            .applied
+            .contains("bevy_app::config::tests::ConfigurablePlugin"));
+        assert!(config
+            .failed
+            .contains("bevy_app::config::tests::ConfigurablePlugin"));
+    }
+
+    #[test]
+    fn merges_nested_overrides() {
+        let mut config = AppConfig::new();
+        config
+            .insert(
+                "a::Type",
+                ron::from_str("(nested: (x: 1, y: 2), flag: true)").unwrap(),
+            )
+            .insert("a::Type", ron::from_str("(nested: (y: 3))").unwrap());
+
+        let expected: ConfigValue = ron::from_str("(nested: (x: 1, y: 3), flag: true)").unwrap();
+        assert_eq!(config.get("a::Type"), Some(&expected));
+    }
+
+    #[cfg(feature = "config_toml")]
+    #[test]
+    fn applies_toml_overrides() {
+        let config = AppConfig::new()
+            .with_toml(
+                r#"
+                ["bevy_app::config::tests::ConfigurablePlugin"]
+                mode = "Slow"
+                window = { title = "from toml", size = [800, 600] }
+                "#,
+            )
+            .unwrap();
+
+        // Setting a `None` option replaces it entirely, so its type must be registered.
+        let mut app = App::new();
+        app.register_type::<Option<Window>>()
+            .insert_resource(config)
+            .add_plugins(ConfigurablePlugin {
+                mode: Mode::Fast,
+                window: None,
+                scale: 1.0,
+            });
+
+        let settings = &app.world().resource::<Settings>().0;
+        assert_eq!(settings.mode, Mode::Slow);
+        assert_eq!(
+            settings.window,
+            Some(Window {
+                title: "from toml".to_string(),
+                size: (800, 600),
+            })
+        );
+    }
+}
@@ -21,8 +21,12 @@ extern crate std;
 extern crate alloc;
 
 mod app;
+#[cfg(feature = "config")]
+mod config;
 mod main_schedule;
 mod panic_handler;
+#[cfg(all(feature = "std", feature = "bevy_tasks"))]
+mod pipelined_sub_app;
 mod plugin;
 mod plugin_group;
 mod schedule_runner;
@@ -33,8 +37,12 @@ mod task_pool_plugin;
 mod terminal_ctrl_c_handler;
 
 pub use app::*;
+#[cfg(feature = "config")]
+pub use config::*;
 pub use main_schedule::*;
 pub use panic_handler::*;
+#[cfg(all(feature = "std", feature = "bevy_tasks"))]
+pub use pipelined_sub_app::*;
 pub use plugin::*;
 pub use plugin_group::*;
 pub use schedule_runner::*;
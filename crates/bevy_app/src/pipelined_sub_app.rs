@@ -0,0 +1,304 @@
+use crate::{App, AppExit, AppLabel, InternedAppLabel, Plugin, SubApp};
+use async_channel::{Receiver, Sender};
+use bevy_ecs::{
+    resource::Resource,
+    schedule::MainThreadExecutor,
+    world::{Mut, World},
+};
+use bevy_tasks::ComputeTaskPool;
+use core::marker::PhantomData;
+use log::{debug, warn};
+
+/// Channels used by the main app to send the pipelined [`SubApp`] labeled with `L` to and from
+/// the thread it runs on.
+///
+/// This resource is inserted into the main world by [`PipelinedSubAppPlugin`].
+#[derive(Resource)]
+pub struct PipelinedSubAppChannels<L: AppLabel> {
+    app_to_sub_app_sender: Sender<SubApp>,
+    sub_app_to_app_receiver: Receiver<SubApp>,
+    sub_app_in_thread: bool,
+    _label: PhantomData<fn() -> L>,
+}
+
+impl<L: AppLabel> PipelinedSubAppChannels<L> {
+    /// Create a `PipelinedSubAppChannels` from a [`async_channel::Sender`] and
+    /// [`async_channel::Receiver`].
+    pub fn new(
+        app_to_sub_app_sender: Sender<SubApp>,
+        sub_app_to_app_receiver: Receiver<SubApp>,
+    ) -> Self {
+        Self {
+            app_to_sub_app_sender,
+            sub_app_to_app_receiver,
+            sub_app_in_thread: false,
+            _label: PhantomData,
+        }
+    }
+
+    /// Send the `sub_app` to its thread.
+    pub fn send_blocking(&mut self, sub_app: SubApp) {
+        self.app_to_sub_app_sender.send_blocking(sub_app).unwrap();
+        self.sub_app_in_thread = true;
+    }
+
+    /// Receive the `sub_app` from its thread.
+    /// Return `None` if the thread has panicked.
+    pub async fn recv(&mut self) -> Option<SubApp> {
+        let sub_app = self.sub_app_to_app_receiver.recv().await.ok()?;
+        self.sub_app_in_thread = false;
+        Some(sub_app)
+    }
+}
+
+impl<L: AppLabel> Drop for PipelinedSubAppChannels<L> {
+    fn drop(&mut self) {
+        if self.sub_app_in_thread {
+            // Any non-send data in the sub-app world was initialized on the main thread.
+            // So on dropping the main world and ending the app, we block and wait for
+            // the sub-app world to return to drop it. Which allows the non-send data
+            // drop methods to run on the correct thread.
+            self.sub_app_to_app_receiver.recv_blocking().ok();
+        }
+    }
+}
+
+/// Moves a [`SubApp`] to its own thread, so that it updates in parallel with the main app.
+///
+/// The sub-app's update for frame N runs at the same time as the main app's update for
+/// frame N + 1. Several sub-apps can be pipelined this way, each on its own thread.
+///
+/// ```text
+/// |--------------------|--------------------|--------------------|--------------------|
+/// | main thread        | frame 1 main app   | frame 2 main app   | frame 3 main app   |
+/// |--------------------|--------------------|--------------------|--------------------|
+/// | sub-app thread     |                    | frame 1 sub-app    | frame 2 sub-app    |
+/// |--------------------|--------------------|--------------------|--------------------|
+/// ```
+///
+/// The sub-app's [extract function](SubApp::set_extract) is the only sync point between the
+/// two worlds. At the end of each main app update, the main thread waits for the sub-app to
+/// finish its previous update, runs its extract function with access to both worlds, and
+/// sends it back to its thread. Data flowing back to the main app, such as simulation
+/// results, should be copied out during extract too.
+///
+/// The plugin replaces the sub-app with a proxy sub-app under `proxy_label`, which runs
+/// extract on the main thread. Its default schedule can be used to run logic on the main
+/// thread right after extract.
+///
+/// The sub-app must be inserted before this plugin is built, and is moved to its thread
+/// during [`Plugin::cleanup`]. Waiting for the sub-app requires the `multi_threaded` feature
+/// of `bevy_tasks`. The channels used to sync with the thread are stored in the main world
+/// as a [`PipelinedSubAppChannels<L>`] resource, so each pipelined sub-app needs a label of its
+/// own type.
+///
+/// With the `trace` feature, the work of the thread is recorded under a
+/// `pipelined sub app thread` span, and each update of the sub-app under a `sub app` span,
+/// both named after the sub-app's label.
+///
+/// ```
+/// # use bevy_app::{App, AppLabel, PipelinedSubAppPlugin, SubApp};
+/// #[derive(AppLabel, Debug, Clone, PartialEq, Eq, Hash)]
+/// struct PhysicsApp;
+///
+/// #[derive(AppLabel, Debug, Clone, PartialEq, Eq, Hash)]
+/// struct PhysicsExtractApp;
+///
+/// let mut physics = SubApp::new();
+/// physics.set_extract(|main_world, physics_world| {
+///     // Copy inputs into `physics_world`, and results back into `main_world`.
+/// });
+///
+/// let mut app = App::new();
+/// app.insert_sub_app(PhysicsApp, physics);
+/// app.add_plugins(PipelinedSubAppPlugin::new(PhysicsApp, PhysicsExtractApp));
+/// ```
+pub struct PipelinedSubAppPlugin<L: AppLabel> {
+    sub_app: InternedAppLabel,
+    proxy: InternedAppLabel,
+    _label: PhantomData<fn() -> L>,
+}
+
+impl<L: AppLabel> PipelinedSubAppPlugin<L> {
+    /// Creates a plugin that pipelines the sub-app with the `sub_app` label, using a proxy
+    /// sub-app with the `proxy` label to sync it with the main app.
+    pub fn new(sub_app: L, proxy: impl AppLabel) -> Self {
+        Self {
+            sub_app: sub_app.intern(),
+            proxy: proxy.intern(),
+            _label: PhantomData,
+        }
+    }
+}
+
+impl<L: AppLabel> Plugin for PipelinedSubAppPlugin<L> {
+    fn build(&self, app: &mut App) {
+        if app.get_sub_app(self.sub_app).is_none() {
+            warn!(
+                "Sub-app {:?} does not exist, so it won't be pipelined",
+                self.sub_app
+            );
+            return;
+        }
+        if !app.world().contains_resource::<MainThreadExecutor>() {
+            app.insert_resource(MainThreadExecutor::new());
+        }
+
+        let mut proxy = SubApp::new();
+        proxy.set_extract(pipelined_extract::<L>);
+        app.insert_sub_app(self.proxy, proxy);
+    }
+
+    // Sets up the sub-app thread and the channels used to sync with it.
+    fn cleanup(&self, app: &mut App) {
+        if app.get_sub_app(self.proxy).is_none() {
+            return;
+        }
+
+        let (app_to_sub_app_sender, app_to_sub_app_receiver) = async_channel::bounded::<SubApp>(1);
+        let (sub_app_to_app_sender, sub_app_to_app_receiver) = async_channel::bounded::<SubApp>(1);
+
+        let label = self.sub_app;
+        let mut sub_app = app.remove_sub_app(label).unwrap_or_else(|| {
+            panic!("Unable to get sub-app {label:?}. Another plugin may have removed it before PipelinedSubAppPlugin")
+        });
+
+        // clone main thread executor to the sub-app world
+        let executor = app.world().resource::<MainThreadExecutor>();
+        sub_app.world_mut().insert_resource(executor.clone());
+
+        sub_app_to_app_sender.send_blocking(sub_app).unwrap();
+
+        app.insert_resource(PipelinedSubAppChannels::<L>::new(
+            app_to_sub_app_sender,
+            sub_app_to_app_receiver,
+        ));
+
+        std::thread::spawn(move || {
+            #[cfg(feature = "trace")]
+            let _span = tracing::info_span!("pipelined sub app thread", name = ?label).entered();
+
+            let compute_task_pool = ComputeTaskPool::get();
+            loop {
+                // run a scope here to allow main world to use this thread while it's waiting for the sub-app
+                let sent_app = compute_task_pool
+                    .scope(|s| {
+                        s.spawn(async { app_to_sub_app_receiver.recv().await });
+                    })
+                    .pop();
+                let Some(Ok(mut sub_app)) = sent_app else {
+                    break;
+                };
+
+                {
+                    #[cfg(feature = "trace")]
+                    let _sub_app_span = tracing::info_span!("sub app", name = ?label).entered();
+                    sub_app.update();
+                }
+
+                if sub_app_to_app_sender.send_blocking(sub_app).is_err() {
+                    break;
+                }
+            }
+
+            debug!("exiting pipelined sub-app thread for {label:?}");
+        });
+    }
+}
+
+// This function waits for the pipelined sub-app to be received,
+// runs extract, and then sends the sub-app back to its thread.
+fn pipelined_extract<L: AppLabel>(app_world: &mut World, _proxy_world: &mut World) {
+    if !app_world.contains_resource::<PipelinedSubAppChannels<L>>() {
+        // The sub-app hasn't been moved to its thread yet.
+        return;
+    }
+    app_world.resource_scope(|world, main_thread_executor: Mut<MainThreadExecutor>| {
+        world.resource_scope(|world, mut channels: Mut<PipelinedSubAppChannels<L>>| {
+            // we use a scope here to run any main thread tasks that the sub-app still needs to run
+            // while we wait for the sub-app to be received.
+            if let Some(mut sub_app) = ComputeTaskPool::get()
+                .scope_with_executor(true, Some(&*main_thread_executor.0), |s| {
+                    s.spawn(async { channels.recv().await });
+                })
+                .pop()
+                .unwrap()
+            {
+                sub_app.extract(world);
+
+                channels.send_blocking(sub_app);
+            } else {
+                // Sub-app thread panicked
+                world.send_event(AppExit::error());
+            }
+        });
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PipelinedSubAppChannels, PipelinedSubAppPlugin};
+    use crate::{self as bevy_app, App, AppLabel, SubApp, Update};
+    use bevy_ecs::{
+        resource::Resource,
+        schedule::ScheduleLabel,
+        system::{Res, ResMut},
+    };
+    use bevy_tasks::{ComputeTaskPool, TaskPool};
+
+    #[derive(AppLabel, Debug, Clone, PartialEq, Eq, Hash)]
+    struct SimulationApp;
+
+    #[derive(AppLabel, Debug, Clone, PartialEq, Eq, Hash)]
+    struct SimulationExtractApp;
+
+    #[derive(Resource, Default)]
+    struct Frame(u32);
+
+    #[derive(Resource, Default)]
+    struct Simulated(u32);
+
+    #[test]
+    fn sub_app_updates_in_parallel_and_syncs_during_extract() {
+        ComputeTaskPool::get_or_init(TaskPool::default);
+
+        let mut simulation = SubApp::new();
+        simulation.update_schedule = Some(Update.intern());
+        simulation
+            .init_resource::<Frame>()
+            .init_resource::<Simulated>()
+            .add_systems(
+                Update,
+                |frame: Res<Frame>, mut simulated: ResMut<Simulated>| {
+                    simulated.0 = frame.0;
+                },
+            )
+            .set_extract(|main_world, simulation_world| {
+                simulation_world.resource_mut::<Frame>().0 = main_world.resource::<Frame>().0;
+                main_world.resource_mut::<Simulated>().0 =
+                    simulation_world.resource::<Simulated>().0;
+            });
+
+        let mut app = App::new();
+        app.init_resource::<Frame>()
+            .init_resource::<Simulated>()
+            .add_systems(Update, |mut frame: ResMut<Frame>| frame.0 += 1)
+            .insert_sub_app(SimulationApp, simulation);
+        app.add_plugins(PipelinedSubAppPlugin::new(
+            SimulationApp,
+            SimulationExtractApp,
+        ));
+        app.finish();
+        app.cleanup();
+        assert!(app.get_sub_app(SimulationApp).is_none());
+        assert!(app
+            .world()
+            .contains_resource::<PipelinedSubAppChannels<SimulationApp>>());
+
+        for frame in 1..=3 {
+            app.update();
+            // Each extract returns the results of the previous frame's simulation.
+            assert_eq!(app.world().resource::<Simulated>().0, frame - 1);
+        }
+    }
+}
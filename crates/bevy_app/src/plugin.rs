@@ -1,5 +1,6 @@
 use crate::App;
-use core::any::Any;
+use alloc::vec::Vec;
+use core::any::{Any, TypeId};
 use downcast_rs::{impl_downcast, Downcast};
 
 /// A collection of Bevy app logic and configuration.
@@ -21,6 +22,13 @@ use downcast_rs::{impl_downcast, Downcast};
 /// * it will then call all registered [`Plugin::finish`]
 /// * and call all registered [`Plugin::cleanup`]
 ///
+/// ## Dependencies
+///
+/// A plugin can declare the plugins it depends on with [`Plugin::dependencies`]. Dependencies
+/// added in the same [`App::add_plugins`] call are built first, and all plugins are finished
+/// and cleaned up after their dependencies. Adding a plugin whose required dependencies are
+/// missing panics with a list of the missing plugins.
+///
 /// ## Defining a plugin.
 ///
 /// Most plugins are simply functions that add configuration to an [`App`].
@@ -89,6 +97,55 @@ pub trait Plugin: Downcast + Any + Send + Sync {
     fn is_unique(&self) -> bool {
         true
     }
+
+    /// The plugins this plugin depends on.
+    ///
+    /// Required dependencies must be added to the [`App`] before this plugin or in the same
+    /// [`App::add_plugins`] call. Optional dependencies only affect ordering: when present,
+    /// they are built (if added in the same call), finished and cleaned up before this plugin.
+    ///
+    /// ```
+    /// # use bevy_app::*;
+    /// # struct TimePlugin;
+    /// # impl Plugin for TimePlugin { fn build(&self, _: &mut App) {} }
+    /// # struct DiagnosticsPlugin;
+    /// # impl Plugin for DiagnosticsPlugin { fn build(&self, _: &mut App) {} }
+    /// struct PhysicsPlugin;
+    ///
+    /// impl Plugin for PhysicsPlugin {
+    ///     fn build(&self, app: &mut App) {
+    ///         // ...
+    ///     }
+    ///
+    ///     fn dependencies(&self) -> PluginDependencies {
+    ///         PluginDependencies::new()
+    ///             .require::<TimePlugin>()
+    ///             .optional::<DiagnosticsPlugin>()
+    ///     }
+    /// }
+    ///
+    /// App::new().add_plugins((PhysicsPlugin, TimePlugin));
+    /// ```
+    fn dependencies(&self) -> PluginDependencies {
+        PluginDependencies::default()
+    }
+
+    /// Exposes the settings of this plugin to reflection, so they can be overridden by an
+    /// `AppConfig` before the plugin is built.
+    ///
+    /// Plugins that derive [`Reflect`](bevy_reflect::Reflect) can simply return `Some(self)`,
+    /// and register their type in [`Plugin::register_settings`].
+    #[cfg(feature = "bevy_reflect")]
+    fn settings_mut(&mut self) -> Option<&mut dyn bevy_reflect::PartialReflect> {
+        None
+    }
+
+    /// Registers the type of the settings exposed by [`Plugin::settings_mut`], and the types
+    /// of its fields, so that an `AppConfig` can deserialize overrides for them.
+    ///
+    /// This is called before the overrides are applied, so before the plugin is built.
+    #[cfg(feature = "bevy_reflect")]
+    fn register_settings(&self, _registry: &mut bevy_reflect::TypeRegistry) {}
 }
 
 impl_downcast!(Plugin);
@@ -99,6 +156,120 @@ impl<T: Fn(&mut App) + Send + Sync + 'static> Plugin for T {
     }
 }
 
+/// The plugins a [`Plugin`] depends on, as returned by [`Plugin::dependencies`].
+///
+/// Dependencies are identified by type, so plugins added with different type parameters
+/// are different dependencies.
+#[derive(Debug, Default, Clone)]
+pub struct PluginDependencies {
+    dependencies: Vec<PluginDependency>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PluginDependency {
+    type_id: TypeId,
+    name: &'static str,
+    required: bool,
+}
+
+impl PluginDependencies {
+    /// Creates an empty set of dependencies.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a dependency on `P` that must be present in the [`App`].
+    pub fn require<P: Plugin>(mut self) -> Self {
+        self.dependencies.push(PluginDependency {
+            type_id: TypeId::of::<P>(),
+            name: core::any::type_name::<P>(),
+            required: true,
+        });
+        self
+    }
+
+    /// Adds a dependency on `P` that is only used for ordering when `P` is present in the [`App`].
+    pub fn optional<P: Plugin>(mut self) -> Self {
+        self.dependencies.push(PluginDependency {
+            type_id: TypeId::of::<P>(),
+            name: core::any::type_name::<P>(),
+            required: false,
+        });
+        self
+    }
+
+    /// Returns `true` if no dependencies were declared.
+    pub fn is_empty(&self) -> bool {
+        self.dependencies.is_empty()
+    }
+
+    /// Returns the type names of the required dependencies that aren't among `present`.
+    pub(crate) fn missing<'a>(
+        &'a self,
+        present: &'a [TypeId],
+    ) -> impl Iterator<Item = &'static str> + 'a {
+        self.dependencies
+            .iter()
+            .filter(|dependency| dependency.required && !present.contains(&dependency.type_id))
+            .map(|dependency| dependency.name)
+    }
+}
+
+/// Stably reorders `items` so that every plugin comes after the plugins it depends on.
+///
+/// Plugins that are part of a dependency cycle keep their relative order.
+pub(crate) fn sort_by_dependencies<T>(items: &mut Vec<T>, plugin: impl Fn(&T) -> &dyn Plugin) {
+    #[derive(Clone, Copy, PartialEq, Eq)]
+    enum Visit {
+        Pending,
+        InProgress,
+        Done,
+    }
+
+    fn visit(
+        index: usize,
+        type_ids: &[TypeId],
+        dependencies: &[PluginDependencies],
+        visits: &mut [Visit],
+        order: &mut Vec<usize>,
+    ) {
+        if visits[index] != Visit::Pending {
+            return;
+        }
+        visits[index] = Visit::InProgress;
+        for dependency in &dependencies[index].dependencies {
+            for (other, type_id) in type_ids.iter().enumerate() {
+                if *type_id == dependency.type_id {
+                    visit(other, type_ids, dependencies, visits, order);
+                }
+            }
+        }
+        visits[index] = Visit::Done;
+        order.push(index);
+    }
+
+    let dependencies: Vec<_> = items
+        .iter()
+        .map(|item| plugin(item).dependencies())
+        .collect();
+    if dependencies.iter().all(PluginDependencies::is_empty) {
+        return;
+    }
+    let type_ids: Vec<_> = items
+        .iter()
+        .map(|item| plugin(item).as_any().type_id())
+        .collect();
+
+    let mut visits = alloc::vec![Visit::Pending; items.len()];
+    let mut order = Vec::with_capacity(items.len());
+    for index in 0..items.len() {
+        visit(index, &type_ids, &dependencies, &mut visits, &mut order);
+    }
+
+    let mut slots: Vec<_> = items.drain(..).map(Some).collect();
+    items.extend(order.into_iter().filter_map(|index| slots[index].take()));
+}
+
 /// Plugins state in the application
 #[derive(PartialEq, Eq, Debug, Clone, Copy, PartialOrd, Ord)]
 pub enum PluginsState {
@@ -128,13 +299,24 @@ pub trait Plugins<Marker>: sealed::Plugins<Marker> {}
 impl<Marker, T> Plugins<Marker> for T where T: sealed::Plugins<Marker> {}
 
 mod sealed {
-    use alloc::boxed::Box;
+    use alloc::{boxed::Box, string::String, vec::Vec};
     use variadics_please::all_tuples;
 
-    use crate::{App, AppError, Plugin, PluginGroup};
+    use crate::{App, Plugin, PluginGroup};
 
     pub trait Plugins<Marker> {
-        fn add_to_app(self, app: &mut App);
+        /// Collects the plugins, along with the name of the group they come from, if any.
+        fn collect(self, batch: &mut Vec<(Box<dyn Plugin>, Option<String>)>);
+
+        #[track_caller]
+        fn add_to_app(self, app: &mut App)
+        where
+            Self: Sized,
+        {
+            let mut batch = Vec::new();
+            self.collect(&mut batch);
+            app.add_plugin_batch(batch);
+        }
     }
 
     pub struct PluginMarker;
@@ -142,22 +324,14 @@ mod sealed {
     pub struct PluginsTupleMarker;
 
     impl<P: Plugin> Plugins<PluginMarker> for P {
-        #[track_caller]
-        fn add_to_app(self, app: &mut App) {
-            if let Err(AppError::DuplicatePlugin { plugin_name }) =
-                app.add_boxed_plugin(Box::new(self))
-            {
-                panic!(
-                    "Error adding plugin {plugin_name}: : plugin was already added in application"
-                )
-            }
+        fn collect(self, batch: &mut Vec<(Box<dyn Plugin>, Option<String>)>) {
+            batch.push((Box::new(self), None));
         }
     }
 
     impl<P: PluginGroup> Plugins<PluginGroupMarker> for P {
-        #[track_caller]
-        fn add_to_app(self, app: &mut App) {
-            self.build().finish(app);
+        fn collect(self, batch: &mut Vec<(Box<dyn Plugin>, Option<String>)>) {
+            self.build().collect_enabled(batch);
         }
     }
 
@@ -173,11 +347,10 @@ mod sealed {
                     reason = "This is inside a macro, and as such, may not trigger in all cases."
                 )]
                 #[allow(non_snake_case, reason = "`all_tuples!()` generates non-snake-case variable names.")]
-                #[allow(unused_variables, reason = "`app` is unused when implemented for the unit type `()`.")]
-                #[track_caller]
-                fn add_to_app(self, app: &mut App) {
+                #[allow(unused_variables, reason = "`batch` is unused when implemented for the unit type `()`.")]
+                fn collect(self, batch: &mut Vec<(Box<dyn Plugin>, Option<String>)>) {
                     let ($($plugins,)*) = self;
-                    $($plugins.add_to_app(app);)*
+                    $($plugins.collect(batch);)*
                 }
             }
         }
@@ -1,4 +1,4 @@
-use crate::{App, AppError, Plugin};
+use crate::{App, Plugin};
 use alloc::{
     boxed::Box,
     string::{String, ToString},
@@ -509,26 +509,27 @@ impl PluginGroupBuilder {
     }
 
     /// Consumes the [`PluginGroupBuilder`] and [builds](Plugin::build) the contained [`Plugin`]s
-    /// in the order specified.
+    /// in the order specified, moving plugins after the [dependencies](Plugin::dependencies)
+    /// they declare.
     ///
     /// # Panics
     ///
-    /// Panics if one of the plugin in the group was already added to the application.
+    /// Panics if one of the plugin in the group was already added to the application, or if
+    /// a required dependency of one of the plugins is missing.
     #[track_caller]
-    pub fn finish(mut self, app: &mut App) {
+    pub fn finish(self, app: &mut App) {
+        let mut batch = Vec::new();
+        self.collect_enabled(&mut batch);
+        app.add_plugin_batch(batch);
+    }
+
+    /// Moves the enabled plugins of the group into `batch`, in order.
+    pub(crate) fn collect_enabled(mut self, batch: &mut Vec<(Box<dyn Plugin>, Option<String>)>) {
         for ty in &self.order {
             if let Some(entry) = self.plugins.remove(ty) {
                 if entry.enabled {
                     debug!("added plugin: {}", entry.plugin.name());
-                    if let Err(AppError::DuplicatePlugin { plugin_name }) =
-                        app.add_boxed_plugin(entry.plugin)
-                    {
-                        panic!(
-                            "Error adding plugin {} in group {}: plugin was already added in application",
-                            plugin_name,
-                            self.group_name
-                        );
-                    }
+                    batch.push((entry.plugin, Some(self.group_name.clone())));
                 }
             }
         }
@@ -1,5 +1,12 @@
-use crate::{App, AppLabel, InternedAppLabel, Plugin, Plugins, PluginsState};
-use alloc::{boxed::Box, string::String, vec::Vec};
+use crate::{
+    plugin::sort_by_dependencies, App, AppError, AppLabel, InternedAppLabel, Plugin, Plugins,
+    PluginsState,
+};
+use alloc::{
+    boxed::Box,
+    string::{String, ToString},
+    vec::Vec,
+};
 use bevy_ecs::{
     event::EventRegistry,
     prelude::*,
@@ -372,6 +379,31 @@ impl SubApp {
             .collect()
     }
 
+    /// Checks that the required dependencies of the plugins added from `first_index` onwards
+    /// in the plugin registry are present.
+    pub(crate) fn check_plugin_dependencies(&self, first_index: usize) -> Result<(), AppError> {
+        let present: Vec<_> = self
+            .plugin_registry
+            .iter()
+            .map(|plugin| (**plugin).as_any().type_id())
+            .collect();
+
+        let missing: Vec<_> = self.plugin_registry[first_index..]
+            .iter()
+            .filter_map(|plugin| {
+                let dependencies = plugin.dependencies();
+                let missing: Vec<_> = dependencies.missing(&present).collect();
+                (!missing.is_empty()).then(|| (plugin.name().to_string(), missing))
+            })
+            .collect();
+
+        if missing.is_empty() {
+            Ok(())
+        } else {
+            Err(AppError::MissingPluginDependencies { missing })
+        }
+    }
+
     /// Returns `true` if there is no plugin in the middle of being built.
     pub(crate) fn is_building_plugins(&self) -> bool {
         self.plugin_build_depth > 0
@@ -399,8 +431,19 @@ impl SubApp {
         }
     }
 
-    /// Runs [`Plugin::finish`] for each plugin.
+    /// Sorts the plugins by their [dependencies](Plugin::dependencies) before they finish.
+    ///
+    /// No plugins can be added once they have finished, so the order is computed by the first
+    /// call and kept for [`cleanup`](Self::cleanup).
+    pub(crate) fn sort_plugin_registry(&mut self) {
+        if self.plugins_state < PluginsState::Finished {
+            sort_by_dependencies(&mut self.plugin_registry, |plugin| &**plugin);
+        }
+    }
+
+    /// Runs [`Plugin::finish`] for each plugin, after the plugins it depends on.
     pub fn finish(&mut self) {
+        self.sort_plugin_registry();
         let plugins = core::mem::take(&mut self.plugin_registry);
         self.run_as_app(|app| {
             for plugin in &plugins {
@@ -411,8 +454,9 @@ impl SubApp {
         self.plugins_state = PluginsState::Finished;
     }
 
-    /// Runs [`Plugin::cleanup`] for each plugin.
+    /// Runs [`Plugin::cleanup`] for each plugin, after the plugins it depends on.
     pub fn cleanup(&mut self) {
+        self.sort_plugin_registry();
         let plugins = core::mem::take(&mut self.plugin_registry);
         self.run_as_app(|app| {
             for plugin in &plugins {
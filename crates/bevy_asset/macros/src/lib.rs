@@ -11,8 +11,10 @@ pub(crate) fn bevy_asset_path() -> Path {
 }
 
 const DEPENDENCY_ATTRIBUTE: &str = "dependency";
+const ASSET_ATTRIBUTE: &str = "asset";
+const SIZE_IN_BYTES_ATTRIBUTE: &str = "size_in_bytes";
 
-#[proc_macro_derive(Asset, attributes(dependency))]
+#[proc_macro_derive(Asset, attributes(dependency, asset))]
 pub fn derive_asset(input: TokenStream) -> TokenStream {
     let ast = parse_macro_input!(input as DeriveInput);
     let bevy_asset_path: Path = bevy_asset_path();
@@ -24,8 +26,36 @@ pub fn derive_asset(input: TokenStream) -> TokenStream {
         Err(err) => return err.into_compile_error().into(),
     };
 
+    let mut size_in_bytes = None;
+    for attr in ast
+        .attrs
+        .iter()
+        .filter(|a| a.path().is_ident(ASSET_ATTRIBUTE))
+    {
+        let result = attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident(SIZE_IN_BYTES_ATTRIBUTE) {
+                size_in_bytes = Some(meta.value()?.parse::<Path>()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported asset attribute"))
+            }
+        });
+        if let Err(err) = result {
+            return err.into_compile_error().into();
+        }
+    }
+    let size_in_bytes = size_in_bytes.map(|size_in_bytes| {
+        quote! {
+            fn size_in_bytes(&self) -> usize {
+                #size_in_bytes(self)
+            }
+        }
+    });
+
     TokenStream::from(quote! {
-        impl #impl_generics #bevy_asset_path::Asset for #struct_name #type_generics #where_clause { }
+        impl #impl_generics #bevy_asset_path::Asset for #struct_name #type_generics #where_clause {
+            #size_in_bytes
+        }
         #dependency_visitor
     })
 }
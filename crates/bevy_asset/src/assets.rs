@@ -3,13 +3,13 @@ use crate::{
     self as bevy_asset, Asset, AssetEvent, AssetHandleProvider, AssetId, AssetServer, Handle,
     UntypedHandle,
 };
-use alloc::{sync::Arc, vec::Vec};
+use alloc::{collections::BTreeMap, sync::Arc, vec::Vec};
 use bevy_ecs::{
     prelude::EventWriter,
     resource::Resource,
     system::{Res, ResMut, SystemChangeTick},
 };
-use bevy_platform_support::collections::HashMap;
+use bevy_platform_support::collections::{HashMap, HashSet};
 use bevy_reflect::{Reflect, TypePath};
 use core::{any::TypeId, iter::Enumerate, marker::PhantomData, sync::atomic::AtomicU32};
 use crossbeam_channel::{Receiver, Sender};
@@ -283,6 +283,11 @@ impl<A: Asset> DenseAssetStorage<A> {
 /// at compile time.
 ///
 /// This tracks (and queues) [`AssetEvent`] events whenever changes to the collection occur.
+///
+/// If a [memory budget](Assets::set_memory_budget) is set, assets loaded by the [`AssetServer`] are kept in the collection
+/// after their last strong [`Handle`] is dropped, so that loading them again doesn't reload them. Whenever the
+/// [memory usage](Assets::memory_usage) of the collection exceeds the budget, these unused assets are removed, starting with
+/// the one that has been unused for the longest.
 #[derive(Resource)]
 pub struct Assets<A: Asset> {
     dense_storage: DenseAssetStorage<A>,
@@ -292,6 +297,17 @@ pub struct Assets<A: Asset> {
     /// Assets managed by the `Assets` struct with live strong `Handle`s
     /// originating from `get_strong_handle`.
     duplicate_handles: HashMap<AssetId<A>, u16>,
+    /// The size of each asset, as of when it was inserted or last accessed mutably.
+    sizes: HashMap<AssetId<A>, usize>,
+    /// The sum of `sizes`.
+    memory_usage: usize,
+    /// Assets accessed mutably since their size was recorded, whose size may have changed.
+    resized: HashSet<AssetId<A>>,
+    memory_budget: Option<usize>,
+    /// Unused assets kept because of the memory budget, by the order in which they became unused.
+    cached: BTreeMap<u64, AssetId<A>>,
+    cached_order: HashMap<AssetId<A>, u64>,
+    next_cached_order: u64,
 }
 
 impl<A: Asset> Default for Assets<A> {
@@ -305,6 +321,13 @@ impl<A: Asset> Default for Assets<A> {
             hash_map: Default::default(),
             queued_events: Default::default(),
             duplicate_handles: Default::default(),
+            sizes: Default::default(),
+            memory_usage: 0,
+            resized: Default::default(),
+            memory_budget: None,
+            cached: Default::default(),
+            cached_order: Default::default(),
+            next_cached_order: 0,
         }
     }
 }
@@ -356,6 +379,7 @@ impl<A: Asset> Assets<A> {
     }
 
     pub(crate) fn insert_with_uuid(&mut self, uuid: Uuid, asset: A) -> Option<A> {
+        self.set_size(uuid.into(), asset.size_in_bytes());
         let result = self.hash_map.insert(uuid, asset);
         if result.is_some() {
             self.queued_events
@@ -371,7 +395,9 @@ impl<A: Asset> Assets<A> {
         index: AssetIndex,
         asset: A,
     ) -> Result<bool, InvalidGenerationError> {
+        let size = asset.size_in_bytes();
         let replaced = self.dense_storage.insert(index, asset)?;
+        self.set_size(index.into(), size);
         if replaced {
             self.queued_events
                 .push(AssetEvent::Modified { id: index.into() });
@@ -433,6 +459,7 @@ impl<A: Asset> Assets<A> {
         };
         if result.is_some() {
             self.queued_events.push(AssetEvent::Modified { id });
+            self.resized.insert(id);
         }
         result
     }
@@ -453,10 +480,14 @@ impl<A: Asset> Assets<A> {
     pub fn remove_untracked(&mut self, id: impl Into<AssetId<A>>) -> Option<A> {
         let id: AssetId<A> = id.into();
         self.duplicate_handles.remove(&id);
-        match id {
+        let result = match id {
             AssetId::Index { index, .. } => self.dense_storage.remove_still_alive(index),
             AssetId::Uuid { uuid } => self.hash_map.remove(&uuid),
+        };
+        if result.is_some() {
+            self.remove_size(id);
         }
+        result
     }
 
     /// Removes the [`Asset`] with the given `id`.
@@ -473,6 +504,7 @@ impl<A: Asset> Assets<A> {
             AssetId::Uuid { uuid } => self.hash_map.remove(&uuid).is_some(),
         };
         if existed {
+            self.remove_size(id);
             self.queued_events.push(AssetEvent::Removed { id });
         }
     }
@@ -528,7 +560,72 @@ impl<A: Asset> Assets<A> {
             dense_storage: self.dense_storage.storage.iter_mut().enumerate(),
             hash_map: self.hash_map.iter_mut(),
             queued_events: &mut self.queued_events,
+            resized: &mut self.resized,
+        }
+    }
+
+    /// Returns an estimate of the memory used by the assets in this collection, computed with [`Asset::size_in_bytes`].
+    ///
+    /// The size of each asset is recorded when it is inserted. Only the assets that were accessed mutably since then are
+    /// measured again.
+    pub fn memory_usage(&self) -> usize {
+        self.resized
+            .iter()
+            .fold(self.memory_usage, |memory_usage, id| {
+                let recorded = self.sizes.get(id).copied().unwrap_or(0);
+                let current = self.get(*id).map_or(0, Asset::size_in_bytes);
+                memory_usage - recorded + current
+            })
+    }
+
+    fn set_size(&mut self, id: AssetId<A>, size: usize) {
+        self.resized.remove(&id);
+        let previous = self.sizes.insert(id, size).unwrap_or(0);
+        self.memory_usage = self.memory_usage - previous + size;
+    }
+
+    fn remove_size(&mut self, id: AssetId<A>) {
+        self.resized.remove(&id);
+        self.memory_usage -= self.sizes.remove(&id).unwrap_or(0);
+    }
+
+    /// Records the size of the assets that were accessed mutably since their size was last recorded.
+    fn update_memory_usage(&mut self) {
+        for id in core::mem::take(&mut self.resized) {
+            if let Some(size) = self.get(id).map(Asset::size_in_bytes) {
+                self.set_size(id, size);
+            }
+        }
+    }
+
+    /// Sets the memory budget of this collection, in bytes. If [`None`] (the default), assets are removed as soon as their
+    /// last strong [`Handle`] is dropped.
+    ///
+    /// If set, assets loaded by the [`AssetServer`] are kept after their last strong [`Handle`] is dropped, and can be reused
+    /// by loading the same path again. Whenever the [memory usage](Assets::memory_usage) exceeds the budget, these unused
+    /// assets are removed, starting with the one that has been unused for the longest. Assets that are still in use are
+    /// never removed.
+    pub fn set_memory_budget(&mut self, memory_budget: Option<usize>) {
+        self.memory_budget = memory_budget;
+    }
+
+    /// Returns the memory budget of this collection, set by [`Assets::set_memory_budget`].
+    pub fn memory_budget(&self) -> Option<usize> {
+        self.memory_budget
+    }
+
+    /// Returns `true` if the asset with the given `id` is unused, and only kept because of the [memory budget](Assets::set_memory_budget).
+    pub fn is_cached(&self, id: impl Into<AssetId<A>>) -> bool {
+        self.cached_order.contains_key(&id.into())
+    }
+
+    fn insert_cached(&mut self, id: AssetId<A>) {
+        let order = self.next_cached_order;
+        self.next_cached_order += 1;
+        if let Some(previous) = self.cached_order.insert(id, order) {
+            self.cached.remove(&previous);
         }
+        self.cached.insert(order, id);
     }
 
     /// A system that synchronizes the state of assets in this collection with the [`AssetServer`]. This manages
@@ -546,6 +643,18 @@ impl<A: Asset> Assets<A> {
             if drop_event.asset_server_managed {
                 let untyped_id = id.untyped();
 
+                if assets.memory_budget.is_some() && assets.contains(id) {
+                    match infos.cache_handle_drop(untyped_id) {
+                        Some(true) => {
+                            assets.queued_events.push(AssetEvent::Unused { id });
+                            assets.insert_cached(id);
+                            continue;
+                        }
+                        Some(false) => continue,
+                        None => {}
+                    }
+                }
+
                 // the process_handle_drop call checks whether new handles have been created since the drop event was fired, before removing the asset
                 if !infos.process_handle_drop(untyped_id) {
                     // a new handle has been created, or the asset doesn't exist
@@ -556,6 +665,33 @@ impl<A: Asset> Assets<A> {
             assets.queued_events.push(AssetEvent::Unused { id });
             assets.remove_dropped(id);
         }
+
+        if assets.cached.is_empty() {
+            return;
+        }
+        // drop cached assets that are in use again, or that were removed manually
+        let in_use: Vec<_> = assets
+            .cached_order
+            .keys()
+            .copied()
+            .filter(|id| !infos.is_cached(id.untyped()))
+            .collect();
+        for id in in_use {
+            let order = assets.cached_order.remove(&id).unwrap();
+            assets.cached.remove(&order);
+        }
+
+        let budget = assets.memory_budget.unwrap_or(0);
+        assets.update_memory_usage();
+        while assets.memory_usage > budget {
+            let Some((_, id)) = assets.cached.pop_first() else {
+                break;
+            };
+            assets.cached_order.remove(&id);
+            if infos.evict_cached(id.untyped()) {
+                assets.remove_dropped(id);
+            }
+        }
     }
 
     /// A system that applies accumulated asset change events to the [`Events`] resource.
@@ -594,6 +730,7 @@ impl<A: Asset> Assets<A> {
 /// A mutable iterator over [`Assets`].
 pub struct AssetsMutIterator<'a, A: Asset> {
     queued_events: &'a mut Vec<AssetEvent<A>>,
+    resized: &'a mut HashSet<AssetId<A>>,
     dense_storage: Enumerate<core::slice::IterMut<'a, Entry<A>>>,
     hash_map: bevy_platform_support::collections::hash_map::IterMut<'a, Uuid, A>,
 }
@@ -617,6 +754,7 @@ impl<'a, A: Asset> Iterator for AssetsMutIterator<'a, A> {
                     };
                     self.queued_events.push(AssetEvent::Modified { id });
                     if let Some(value) = value {
+                        self.resized.insert(id);
                         return Some((id, value));
                     }
                 }
@@ -625,6 +763,7 @@ impl<'a, A: Asset> Iterator for AssetsMutIterator<'a, A> {
         if let Some((key, value)) = self.hash_map.next() {
             let id = AssetId::Uuid { uuid: *key };
             self.queued_events.push(AssetEvent::Modified { id });
+            self.resized.insert(id);
             Some((id, value))
         } else {
             None
@@ -641,7 +780,20 @@ pub struct InvalidGenerationError {
 
 #[cfg(test)]
 mod test {
-    use crate::AssetIndex;
+    use crate::{self as bevy_asset, Asset, AssetId, AssetIndex, Assets};
+    use alloc::{vec, vec::Vec};
+    use bevy_reflect::TypePath;
+    use uuid::Uuid;
+
+    #[derive(Asset, TypePath)]
+    #[asset(size_in_bytes = Blob::len)]
+    struct Blob(Vec<u8>);
+
+    impl Blob {
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+    }
 
     #[test]
     fn asset_index_round_trip() {
@@ -652,4 +804,31 @@ mod test {
         let roundtripped = AssetIndex::from_bits(asset_index.to_bits());
         assert_eq!(asset_index, roundtripped);
     }
+
+    #[test]
+    fn memory_usage_tracks_inserts_mutations_and_removals() {
+        let mut assets = Assets::<Blob>::default();
+        let a = assets.add(Blob(vec![0; 10]));
+        let b = assets.add(Blob(vec![0; 20]));
+        let c = AssetId::<Blob>::from(Uuid::from_u128(1));
+        assets.insert(c, Blob(vec![0; 5]));
+        assert_eq!(assets.memory_usage(), 35);
+
+        assets.get_mut(&a).unwrap().0.extend([0; 5]);
+        assert_eq!(assets.memory_usage(), 40);
+        assets.insert(&b, Blob(vec![0; 1]));
+        assert_eq!(assets.memory_usage(), 21);
+        for (_, blob) in assets.iter_mut() {
+            blob.0.clear();
+        }
+        assert_eq!(assets.memory_usage(), 0);
+
+        assets.get_mut(&a).unwrap().0.push(0);
+        assets.remove(&b);
+        assets.remove(c);
+        assert_eq!(assets.memory_usage(), 1);
+        assets.update_memory_usage();
+        assert!(assets.resized.is_empty());
+        assert_eq!(assets.memory_usage, 1);
+    }
 }
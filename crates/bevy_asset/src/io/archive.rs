@@ -0,0 +1,614 @@
+use crate::io::{
+    get_meta_path, AssetReader, AssetReaderError, AssetSourceBuilder, PathStream, Reader, VecReader,
+};
+use alloc::{
+    borrow::ToOwned, boxed::Box, collections::BTreeMap, string::String, sync::Arc, vec, vec::Vec,
+};
+use bevy_platform_support::collections::{HashMap, HashSet};
+use core::{pin::Pin, task::Poll};
+use futures_lite::Stream;
+use std::{
+    fs::File,
+    io::{BufReader, BufWriter, Read, Seek, SeekFrom, Write},
+    path::{Path, PathBuf},
+};
+use tracing::warn;
+
+/// The first bytes of every pak archive.
+pub const PAK_MAGIC: [u8; 8] = *b"BEVYPAK\0";
+
+/// The version of the pak format written by [`PakWriter`].
+pub const PAK_VERSION: u32 = 1;
+
+/// The longest path a pak archive index may contain, in bytes.
+const MAX_PATH_LEN: u32 = 4096;
+
+/// A read-only archive of asset files, in Bevy's pak format.
+///
+/// A pak archive starts with [`PAK_MAGIC`], the format version and an index of the files it contains,
+/// followed by the file contents. Use [`PakWriter`] or [`pack_directory`] to create one.
+///
+/// Only the index is loaded when an archive is opened. Files are read from disk when they are requested.
+#[derive(Clone, Debug)]
+pub struct PakArchive(Arc<PakArchiveInternal>);
+
+#[derive(Debug)]
+struct PakArchiveInternal {
+    data: PakData,
+    files: HashMap<Box<str>, PakEntry>,
+    dirs: HashMap<Box<str>, Vec<PathBuf>>,
+}
+
+#[derive(Debug)]
+enum PakData {
+    File(PathBuf),
+    Memory(Box<[u8]>),
+}
+
+#[derive(Debug, Clone, Copy)]
+struct PakEntry {
+    offset: u64,
+    len: u64,
+}
+
+impl PakArchive {
+    /// Opens the pak archive at `path`, reading its index.
+    pub fn open(path: impl Into<PathBuf>) -> std::io::Result<Self> {
+        let path = path.into();
+        let file = File::open(&path)?;
+        let len = file.metadata()?.len();
+        let files = read_index(&mut BufReader::new(file), len)?;
+        Ok(Self::from_index(PakData::File(path), files))
+    }
+
+    /// Creates an archive from the bytes of a whole pak archive, for example one that was embedded in the executable.
+    pub fn from_bytes(bytes: impl Into<Box<[u8]>>) -> std::io::Result<Self> {
+        let bytes = bytes.into();
+        let files = read_index(&mut &*bytes, bytes.len() as u64)?;
+        Ok(Self::from_index(PakData::Memory(bytes), files))
+    }
+
+    fn from_index(data: PakData, files: HashMap<Box<str>, PakEntry>) -> Self {
+        let mut dirs = HashMap::<Box<str>, HashSet<PathBuf>>::default();
+        for path in files.keys() {
+            let mut child = Path::new(&**path);
+            while let Some(parent) = child.parent() {
+                dirs.entry(normalize(parent))
+                    .or_default()
+                    .insert(child.to_owned());
+                child = parent;
+            }
+        }
+        let dirs = dirs
+            .into_iter()
+            .map(|(dir, children)| {
+                let mut children: Vec<_> = children.into_iter().collect();
+                children.sort();
+                (dir, children)
+            })
+            .collect();
+        Self(Arc::new(PakArchiveInternal { data, files, dirs }))
+    }
+
+    /// Returns `true` if the archive contains a file at `path`.
+    pub fn contains(&self, path: &Path) -> bool {
+        self.0.files.contains_key(&*normalize(path))
+    }
+
+    /// Returns `true` if the archive contains a directory at `path`.
+    pub fn is_directory(&self, path: &Path) -> bool {
+        self.0.dirs.contains_key(&*normalize(path))
+    }
+
+    /// Returns the paths of all files in the archive, including meta files.
+    pub fn paths(&self) -> impl Iterator<Item = &Path> {
+        self.0.files.keys().map(|path| Path::new(&**path))
+    }
+
+    /// Reads the contents of the file at `path`, or returns `None` if the archive doesn't contain it.
+    pub fn read(&self, path: &Path) -> Option<std::io::Result<Vec<u8>>> {
+        let entry = *self.0.files.get(&*normalize(path))?;
+        let len = usize::try_from(entry.len).map_err(invalid_data);
+        Some(len.and_then(|len| match &self.0.data {
+            PakData::File(archive_path) => {
+                let mut file = File::open(archive_path)?;
+                file.seek(SeekFrom::Start(entry.offset))?;
+                let mut bytes = vec![0; len];
+                file.read_exact(&mut bytes)?;
+                Ok(bytes)
+            }
+            PakData::Memory(bytes) => {
+                let offset = entry.offset as usize;
+                Ok(bytes[offset..offset + len].to_vec())
+            }
+        }))
+    }
+
+    fn read_directory(&self, path: &Path) -> Option<&[PathBuf]> {
+        self.0.dirs.get(&*normalize(path)).map(Vec::as_slice)
+    }
+}
+
+/// Converts `path` to the form used in the pak index: its components separated by `/`.
+fn normalize(path: &Path) -> Box<str> {
+    let mut normalized = String::new();
+    for component in path.components() {
+        if !normalized.is_empty() {
+            normalized.push('/');
+        }
+        normalized.push_str(&component.as_os_str().to_string_lossy());
+    }
+    normalized.into()
+}
+
+fn invalid_data(error: impl Into<Box<dyn core::error::Error + Send + Sync>>) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, error)
+}
+
+fn read_u32(reader: &mut impl Read) -> std::io::Result<u32> {
+    let mut bytes = [0; 4];
+    reader.read_exact(&mut bytes)?;
+    Ok(u32::from_le_bytes(bytes))
+}
+
+fn read_u64(reader: &mut impl Read) -> std::io::Result<u64> {
+    let mut bytes = [0; 8];
+    reader.read_exact(&mut bytes)?;
+    Ok(u64::from_le_bytes(bytes))
+}
+
+/// Reads the index of an archive that is `archive_len` bytes long, checking that the index and every
+/// entry fit inside the archive before allocating anything for them.
+fn read_index(
+    reader: &mut impl Read,
+    archive_len: u64,
+) -> std::io::Result<HashMap<Box<str>, PakEntry>> {
+    let mut magic = [0; 8];
+    reader.read_exact(&mut magic)?;
+    if magic != PAK_MAGIC {
+        return Err(invalid_data("not a pak archive"));
+    }
+    let version = read_u32(reader)?;
+    if version != PAK_VERSION {
+        return Err(invalid_data(alloc::format!(
+            "unsupported pak archive version {version}"
+        )));
+    }
+    let count = read_u32(reader)?;
+    let mut index_len = PAK_MAGIC.len() as u64 + 8;
+    let mut files = HashMap::default();
+    for _ in 0..count {
+        let path_len = read_u32(reader)?;
+        if path_len > MAX_PATH_LEN {
+            return Err(invalid_data(alloc::format!(
+                "pak archive path is {path_len} bytes long, the maximum is {MAX_PATH_LEN}"
+            )));
+        }
+        index_len += 4 + u64::from(path_len) + 16;
+        if index_len > archive_len {
+            return Err(invalid_data("pak archive index is out of bounds"));
+        }
+        let mut path = vec![0; path_len as usize];
+        reader.read_exact(&mut path)?;
+        let path = String::from_utf8(path).map_err(invalid_data)?;
+        let offset = read_u64(reader)?;
+        let len = read_u64(reader)?;
+        if offset.saturating_add(len) > archive_len {
+            return Err(invalid_data("pak archive entry is out of bounds"));
+        }
+        files.insert(path.into(), PakEntry { offset, len });
+    }
+    Ok(files)
+}
+
+/// Bundles files into a [`PakArchive`].
+///
+/// ```no_run
+/// # use bevy_asset::io::archive::PakWriter;
+/// let mut writer = PakWriter::new();
+/// writer.add_directory("imported_assets/Default")?;
+/// writer.add_bytes("version.txt", b"1.2.0".to_vec());
+/// writer.write_to_file("assets.pak")?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Default)]
+pub struct PakWriter {
+    entries: BTreeMap<Box<str>, PakSource>,
+}
+
+enum PakSource {
+    Bytes(Vec<u8>),
+    File(PathBuf),
+}
+
+impl PakWriter {
+    /// Creates an empty `PakWriter`.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a file with the given contents at `path`, replacing any file already added there.
+    pub fn add_bytes(&mut self, path: impl AsRef<Path>, bytes: Vec<u8>) -> &mut Self {
+        self.entries
+            .insert(normalize(path.as_ref()), PakSource::Bytes(bytes));
+        self
+    }
+
+    /// Adds the file at `source` on disk at `path`, replacing any file already added there.
+    /// The file is read when the archive is written.
+    pub fn add_file(&mut self, path: impl AsRef<Path>, source: impl Into<PathBuf>) -> &mut Self {
+        self.entries
+            .insert(normalize(path.as_ref()), PakSource::File(source.into()));
+        self
+    }
+
+    /// Adds every file under `directory` on disk, including meta files, at its path relative to `directory`.
+    ///
+    /// Symbolic links to files are followed, but symbolic links to directories are skipped, so links that
+    /// point back up the tree can't make packing loop forever.
+    pub fn add_directory(&mut self, directory: impl AsRef<Path>) -> std::io::Result<&mut Self> {
+        let directory = directory.as_ref();
+        let mut pending = vec![directory.to_owned()];
+        while let Some(dir) = pending.pop() {
+            for entry in std::fs::read_dir(&dir)? {
+                let entry = entry?;
+                let path = entry.path();
+                if path.is_dir() {
+                    if entry.file_type()?.is_symlink() {
+                        warn!(
+                            "Skipping {}, a symbolic link to a directory",
+                            path.display()
+                        );
+                    } else {
+                        pending.push(path);
+                    }
+                } else {
+                    let relative_path = path.strip_prefix(directory).unwrap().to_owned();
+                    self.add_file(relative_path, path);
+                }
+            }
+        }
+        Ok(self)
+    }
+
+    /// Writes the archive to `writer`.
+    pub fn write_to(&self, writer: &mut impl Write) -> std::io::Result<()> {
+        let mut lens = Vec::with_capacity(self.entries.len());
+        for source in self.entries.values() {
+            lens.push(match source {
+                PakSource::Bytes(bytes) => bytes.len() as u64,
+                PakSource::File(path) => std::fs::metadata(path)?.len(),
+            });
+        }
+        let count = u32::try_from(self.entries.len()).map_err(invalid_data)?;
+        let index_len: u64 = self
+            .entries
+            .keys()
+            .map(|path| 4 + path.len() as u64 + 16)
+            .sum();
+
+        writer.write_all(&PAK_MAGIC)?;
+        writer.write_all(&PAK_VERSION.to_le_bytes())?;
+        writer.write_all(&count.to_le_bytes())?;
+        let mut offset = PAK_MAGIC.len() as u64 + 8 + index_len;
+        for (path, len) in self.entries.keys().zip(&lens) {
+            let path_len = u32::try_from(path.len()).map_err(invalid_data)?;
+            writer.write_all(&path_len.to_le_bytes())?;
+            writer.write_all(path.as_bytes())?;
+            writer.write_all(&offset.to_le_bytes())?;
+            writer.write_all(&len.to_le_bytes())?;
+            offset += len;
+        }
+        for (source, len) in self.entries.values().zip(lens) {
+            match source {
+                PakSource::Bytes(bytes) => writer.write_all(bytes)?,
+                PakSource::File(path) => {
+                    let copied = std::io::copy(&mut File::open(path)?.take(len), writer)?;
+                    if copied != len {
+                        return Err(invalid_data(alloc::format!(
+                            "{} changed while it was being packed",
+                            path.display()
+                        )));
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes the archive to a new file at `path`.
+    pub fn write_to_file(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let mut writer = BufWriter::new(File::create(path)?);
+        self.write_to(&mut writer)?;
+        writer.flush()
+    }
+}
+
+/// Packs every file under `directory` into a pak archive at `output`.
+///
+/// This is typically used to bundle the processed assets in `imported_assets/Default` for shipping.
+pub fn pack_directory(
+    directory: impl AsRef<Path>,
+    output: impl AsRef<Path>,
+) -> std::io::Result<()> {
+    PakWriter::new()
+        .add_directory(directory)?
+        .write_to_file(output)
+}
+
+/// Reader implementation for loading assets from one or more mounted [`PakArchive`]s.
+///
+/// Each archive is mounted with a priority. When several archives contain the same file, it is read from
+/// the archive with the highest priority, which lets patches and mods override the files of the base game.
+/// Among archives with the same priority, the one mounted last wins. Directories are merged across archives.
+///
+/// ```no_run
+/// # use bevy_app::App;
+/// # use bevy_asset::io::{archive::{ArchiveAssetReader, PakArchive}, AssetSourceBuilder};
+/// # use bevy_asset::{AssetApp, AssetPlugin};
+/// let reader = ArchiveAssetReader::new()
+///     .with_archive(PakArchive::open("assets.pak")?, 0)
+///     .with_archive(PakArchive::open("patch_1.pak")?, 1);
+/// App::new()
+///     .register_asset_source("pak", AssetSourceBuilder::archive(reader))
+///     .add_plugins(AssetPlugin::default());
+/// # Ok::<(), std::io::Error>(())
+/// ```
+#[derive(Clone, Default, Debug)]
+pub struct ArchiveAssetReader {
+    archives: Vec<(i32, PakArchive)>,
+}
+
+impl ArchiveAssetReader {
+    /// Creates an `ArchiveAssetReader` without any archives.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Mounts `archive` with the given `priority`. Files in archives with a higher priority override files
+    /// at the same path in archives with a lower priority.
+    pub fn mount(&mut self, archive: PakArchive, priority: i32) {
+        let index = self
+            .archives
+            .iter()
+            .position(|(other, _)| *other <= priority)
+            .unwrap_or(self.archives.len());
+        self.archives.insert(index, (priority, archive));
+    }
+
+    /// Mounts `archive` with the given `priority`. See [`ArchiveAssetReader::mount`].
+    pub fn with_archive(mut self, archive: PakArchive, priority: i32) -> Self {
+        self.mount(archive, priority);
+        self
+    }
+
+    /// Returns the mounted archives, in the order they are searched.
+    pub fn archives(&self) -> impl Iterator<Item = &PakArchive> {
+        self.archives.iter().map(|(_, archive)| archive)
+    }
+
+    fn read_bytes(&self, path: &Path) -> Result<VecReader, AssetReaderError> {
+        match self.archives().find_map(|archive| archive.read(path)) {
+            Some(Ok(bytes)) => Ok(VecReader::new(bytes)),
+            Some(Err(error)) => Err(error.into()),
+            None => Err(AssetReaderError::NotFound(path.to_owned())),
+        }
+    }
+}
+
+struct ArchiveDirReader(Vec<PathBuf>);
+
+impl Stream for ArchiveDirReader {
+    type Item = PathBuf;
+
+    fn poll_next(
+        self: Pin<&mut Self>,
+        _cx: &mut core::task::Context<'_>,
+    ) -> Poll<Option<Self::Item>> {
+        Poll::Ready(self.get_mut().0.pop())
+    }
+}
+
+impl AssetReader for ArchiveAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        self.read_bytes(path)
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        self.read_bytes(&get_meta_path(path))
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        let mut found = false;
+        let mut paths = HashSet::<&PathBuf>::default();
+        for children in self
+            .archives()
+            .filter_map(|archive| archive.read_directory(path))
+        {
+            found = true;
+            // filter out meta files as they are not considered assets
+            paths.extend(children.iter().filter(|child| {
+                !child
+                    .extension()
+                    .is_some_and(|ext| ext.eq_ignore_ascii_case("meta"))
+            }));
+        }
+        if !found {
+            return Err(AssetReaderError::NotFound(path.to_owned()));
+        }
+        let mut paths: Vec<PathBuf> = paths.into_iter().cloned().collect();
+        paths.sort();
+        paths.reverse();
+        let stream: Box<PathStream> = Box::new(ArchiveDirReader(paths));
+        Ok(stream)
+    }
+
+    async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        if self.archives().any(|archive| archive.is_directory(path)) {
+            Ok(true)
+        } else if self.archives().any(|archive| archive.contains(path)) {
+            Ok(false)
+        } else {
+            Err(AssetReaderError::NotFound(path.to_owned()))
+        }
+    }
+}
+
+impl AssetSourceBuilder {
+    /// Returns a builder for a source that reads assets from the archives mounted in `reader`.
+    ///
+    /// The archives are used both as the unprocessed and the [processed](crate::AssetMode::Processed) reader, so
+    /// archives packed from `imported_assets` can be loaded without running the asset processor.
+    pub fn archive(reader: ArchiveAssetReader) -> Self {
+        let processed_reader = reader.clone();
+        Self::default()
+            .with_reader(move || Box::new(reader.clone()))
+            .with_processed_reader(move || Box::new(processed_reader.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        pack_directory, ArchiveAssetReader, PakArchive, PakWriter, PAK_MAGIC, PAK_VERSION,
+    };
+    use crate::io::{AssetReader, AssetReaderError};
+    use alloc::{string::String, vec, vec::Vec};
+    use futures_lite::{future::block_on, AsyncReadExt, StreamExt};
+    use std::path::{Path, PathBuf};
+
+    fn archive(files: &[(&str, &str)]) -> PakArchive {
+        let mut writer = PakWriter::new();
+        for (path, text) in files {
+            writer.add_bytes(path, text.as_bytes().to_vec());
+        }
+        let mut bytes = Vec::new();
+        writer.write_to(&mut bytes).unwrap();
+        PakArchive::from_bytes(bytes).unwrap()
+    }
+
+    fn read(reader: &ArchiveAssetReader, path: &str) -> Result<String, AssetReaderError> {
+        block_on(async {
+            let mut reader = reader.read(Path::new(path)).await?;
+            let mut text = String::new();
+            reader.read_to_string(&mut text).await.unwrap();
+            Ok(text)
+        })
+    }
+
+    #[test]
+    fn higher_priority_archives_override_files() {
+        let base = archive(&[
+            ("textures/a.png", "base a"),
+            ("textures/b.png", "base b"),
+            ("textures/b.png.meta", "meta"),
+        ]);
+        let patch = archive(&[("textures/b.png", "patch b"), ("mods/c.png", "mod c")]);
+        // The patch is mounted first, but has a higher priority.
+        let reader = ArchiveAssetReader::new()
+            .with_archive(patch, 1)
+            .with_archive(base, 0);
+
+        assert_eq!(read(&reader, "textures/a.png").unwrap(), "base a");
+        assert_eq!(read(&reader, "textures/b.png").unwrap(), "patch b");
+        assert!(matches!(
+            read(&reader, "textures/missing.png"),
+            Err(AssetReaderError::NotFound(_))
+        ));
+
+        let root: Vec<PathBuf> = block_on(async {
+            let stream = reader.read_directory(Path::new("")).await.unwrap();
+            stream.collect().await
+        });
+        assert_eq!(root, vec![PathBuf::from("mods"), PathBuf::from("textures")]);
+        let textures: Vec<PathBuf> = block_on(async {
+            let stream = reader.read_directory(Path::new("textures")).await.unwrap();
+            stream.collect().await
+        });
+        assert_eq!(
+            textures,
+            vec![
+                PathBuf::from("textures/a.png"),
+                PathBuf::from("textures/b.png")
+            ]
+        );
+        assert!(block_on(reader.is_directory(Path::new("mods"))).unwrap());
+        assert!(!block_on(reader.is_directory(Path::new("mods/c.png"))).unwrap());
+    }
+
+    #[test]
+    fn packs_directory_into_archive_file() {
+        let dir =
+            std::env::temp_dir().join(alloc::format!("bevy_asset_pak_test_{}", std::process::id()));
+        let assets = dir.join("imported_assets/Default");
+        std::fs::create_dir_all(assets.join("models")).unwrap();
+        std::fs::write(assets.join("models/ship.gltf"), "ship").unwrap();
+        std::fs::write(assets.join("models/ship.gltf.meta"), "(meta)").unwrap();
+        let pak = dir.join("assets.pak");
+        pack_directory(&assets, &pak).unwrap();
+
+        let reader = ArchiveAssetReader::new().with_archive(PakArchive::open(&pak).unwrap(), 0);
+        assert_eq!(read(&reader, "models/ship.gltf").unwrap(), "ship");
+        let mut meta = String::new();
+        block_on(async {
+            let mut reader = reader
+                .read_meta(Path::new("models/ship.gltf"))
+                .await
+                .unwrap();
+            reader.read_to_string(&mut meta).await.unwrap();
+        });
+        assert_eq!(meta, "(meta)");
+
+        std::fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn rejects_index_paths_that_do_not_fit() {
+        let header = |path_len: u32| {
+            let mut bytes = PAK_MAGIC.to_vec();
+            bytes.extend(PAK_VERSION.to_le_bytes());
+            bytes.extend(1u32.to_le_bytes());
+            bytes.extend(path_len.to_le_bytes());
+            bytes
+        };
+        // A path longer than the maximum is rejected before allocating it.
+        let error = PakArchive::from_bytes(header(u32::MAX)).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+        // So is a path that is longer than the rest of the archive.
+        let mut bytes = header(100);
+        bytes.extend(b"short");
+        let error = PakArchive::from_bytes(bytes).unwrap_err();
+        assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn skips_symlinked_directories_when_packing() {
+        let dir = std::env::temp_dir().join(alloc::format!(
+            "bevy_asset_pak_symlink_test_{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(dir.join("textures")).unwrap();
+        std::fs::write(dir.join("textures/a.png"), "a").unwrap();
+        std::os::unix::fs::symlink(&dir, dir.join("textures/loop")).unwrap();
+
+        let mut writer = PakWriter::new();
+        writer.add_directory(&dir).unwrap();
+        let mut bytes = Vec::new();
+        writer.write_to(&mut bytes).unwrap();
+        let archive = PakArchive::from_bytes(bytes).unwrap();
+        assert_eq!(
+            archive.paths().collect::<Vec<_>>(),
+            [Path::new("textures/a.png")]
+        );
+
+        std::fs::remove_dir_all(dir).ok();
+    }
+}
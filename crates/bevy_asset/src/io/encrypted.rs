@@ -0,0 +1,600 @@
+use crate::io::{
+    get_meta_path, AssetReader, AssetReaderError, AssetSourceBuilder, AssetWriter,
+    AssetWriterError, ErasedAssetReader, ErasedAssetWriter, PathStream, Reader, VecReader, Writer,
+};
+use alloc::{boxed::Box, sync::Arc, vec::Vec};
+use core::{
+    pin::Pin,
+    task::{ready, Context, Poll},
+};
+use futures_io::AsyncWrite;
+use parking_lot::Mutex;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Encrypts and decrypts the bytes of assets (and asset metadata) for an [`EncryptedAssetReader`] and an
+/// [`EncryptedAssetWriter`].
+///
+/// `path` is the path of the file being read or written, which can be used to select a key per asset.
+pub trait AssetCipher: Send + Sync + 'static {
+    /// Encrypts the `bytes` of the file at `path`.
+    fn encrypt(&self, path: &Path, bytes: &[u8]) -> Vec<u8>;
+
+    /// Decrypts the `bytes` of the file at `path`, which were encrypted with [`AssetCipher::encrypt`].
+    fn decrypt(&self, path: &Path, bytes: &[u8]) -> Result<Vec<u8>, AssetCipherError>;
+}
+
+/// An error that occurs when decrypting an asset.
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum AssetCipherError {
+    /// The file is not in the format produced by the cipher.
+    #[error("the file is not an encrypted asset")]
+    InvalidFormat,
+    /// The file could not be authenticated, because the key is wrong or the file was modified.
+    #[error("the encrypted asset could not be authenticated")]
+    AuthenticationFailed,
+}
+
+/// Provides the key used by a [`KeyedAssetCipher`] for the file at a given path.
+///
+/// This is implemented for `[u8; 32]`, which uses the same key for every file, and for closures.
+pub trait AssetKeyProvider: Send + Sync + 'static {
+    /// Returns the key of the file at `path`.
+    fn key(&self, path: &Path) -> [u8; 32];
+}
+
+impl AssetKeyProvider for [u8; 32] {
+    fn key(&self, _path: &Path) -> [u8; 32] {
+        *self
+    }
+}
+
+impl<F: Fn(&Path) -> [u8; 32] + Send + Sync + 'static> AssetKeyProvider for F {
+    fn key(&self, path: &Path) -> [u8; 32] {
+        self(path)
+    }
+}
+
+/// An [`AssetCipher`] built on BLAKE3, with 256-bit keys given by an [`AssetKeyProvider`].
+///
+/// Files are authenticated with a keyed hash of their contents, which is also used to derive the keystream
+/// they are encrypted with. Encryption is deterministic: the same file encrypted with the same key produces
+/// the same bytes, which keeps processed assets reproducible.
+///
+/// This protects shipped assets from casual extraction and modification. The key has to ship with the game,
+/// so it can't protect assets from a determined attacker.
+pub struct KeyedAssetCipher {
+    keys: Box<dyn AssetKeyProvider>,
+}
+
+impl KeyedAssetCipher {
+    /// The first bytes of every file encrypted by a [`KeyedAssetCipher`].
+    pub const MAGIC: [u8; 4] = *b"BENC";
+
+    const TAG_LEN: usize = blake3::OUT_LEN;
+    const HEADER_LEN: usize = Self::MAGIC.len() + Self::TAG_LEN;
+
+    /// Creates a new [`KeyedAssetCipher`] that uses the given key provider.
+    pub fn new(keys: impl AssetKeyProvider) -> Self {
+        Self {
+            keys: Box::new(keys),
+        }
+    }
+
+    fn derive_keys(&self, path: &Path) -> ([u8; 32], [u8; 32]) {
+        let key = self.keys.key(path);
+        (
+            blake3::derive_key("bevy_asset KeyedAssetCipher authentication", &key),
+            blake3::derive_key("bevy_asset KeyedAssetCipher encryption", &key),
+        )
+    }
+
+    fn apply_keystream(encryption_key: &[u8; 32], tag: &[u8], bytes: &mut [u8]) {
+        let mut keystream = blake3::Hasher::new_keyed(encryption_key)
+            .update(tag)
+            .finalize_xof();
+        let mut block = [0; 64];
+        for chunk in bytes.chunks_mut(block.len()) {
+            keystream.fill(&mut block[..chunk.len()]);
+            for (byte, key) in chunk.iter_mut().zip(block) {
+                *byte ^= key;
+            }
+        }
+    }
+}
+
+impl AssetCipher for KeyedAssetCipher {
+    fn encrypt(&self, path: &Path, bytes: &[u8]) -> Vec<u8> {
+        let (authentication_key, encryption_key) = self.derive_keys(path);
+        let tag = blake3::keyed_hash(&authentication_key, bytes);
+        let mut encrypted = Vec::with_capacity(Self::HEADER_LEN + bytes.len());
+        encrypted.extend_from_slice(&Self::MAGIC);
+        encrypted.extend_from_slice(tag.as_bytes());
+        encrypted.extend_from_slice(bytes);
+        Self::apply_keystream(
+            &encryption_key,
+            tag.as_bytes(),
+            &mut encrypted[Self::HEADER_LEN..],
+        );
+        encrypted
+    }
+
+    fn decrypt(&self, path: &Path, bytes: &[u8]) -> Result<Vec<u8>, AssetCipherError> {
+        if bytes.len() < Self::HEADER_LEN || bytes[..Self::MAGIC.len()] != Self::MAGIC {
+            return Err(AssetCipherError::InvalidFormat);
+        }
+        let (authentication_key, encryption_key) = self.derive_keys(path);
+        let tag = &bytes[Self::MAGIC.len()..Self::HEADER_LEN];
+        let mut decrypted = bytes[Self::HEADER_LEN..].to_vec();
+        Self::apply_keystream(&encryption_key, tag, &mut decrypted);
+        // `blake3::Hash` compares in constant time.
+        let expected = blake3::Hash::from_bytes(tag.try_into().unwrap());
+        if blake3::keyed_hash(&authentication_key, &decrypted) != expected {
+            return Err(AssetCipherError::AuthenticationFailed);
+        }
+        Ok(decrypted)
+    }
+}
+
+/// An [`AssetReader`] that decrypts the assets (and asset metadata) of another reader with an [`AssetCipher`].
+///
+/// Use an [`EncryptedAssetWriter`] with the same cipher to write the assets, such as the processed assets
+/// written by the [`AssetProcessor`](crate::processor::AssetProcessor).
+/// See [`AssetSourceBuilder::with_processed_encryption`].
+pub struct EncryptedAssetReader {
+    reader: Box<dyn ErasedAssetReader>,
+    cipher: Arc<dyn AssetCipher>,
+}
+
+impl EncryptedAssetReader {
+    /// Creates a new [`EncryptedAssetReader`] that decrypts the files of `reader` with `cipher`.
+    pub fn new(reader: Box<dyn ErasedAssetReader>, cipher: Arc<dyn AssetCipher>) -> Self {
+        Self { reader, cipher }
+    }
+
+    async fn decrypt(
+        &self,
+        path: &Path,
+        reader: &mut dyn Reader,
+    ) -> Result<VecReader, AssetReaderError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let bytes = self.cipher.decrypt(path, &bytes).map_err(|err| {
+            AssetReaderError::Io(Arc::new(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                err,
+            )))
+        })?;
+        Ok(VecReader::new(bytes))
+    }
+}
+
+impl AssetReader for EncryptedAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        let mut reader = self.reader.read(path).await?;
+        self.decrypt(path, &mut *reader).await
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        let mut reader = self.reader.read_meta(path).await?;
+        self.decrypt(&get_meta_path(path), &mut *reader).await
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        self.reader.read_directory(path).await
+    }
+
+    async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        self.reader.is_directory(path).await
+    }
+}
+
+/// An [`AssetWriter`] that encrypts the assets (and asset metadata) written to another writer with an [`AssetCipher`].
+///
+/// A file is encrypted as a whole, so it is only written to the inner writer when its [`Writer`] is flushed.
+/// Writing to it after it has been flushed fails.
+///
+/// Files are encrypted for their path, so renaming a file re-encrypts it for its new path. This reads the file back,
+/// so renames fail unless a reader of the inner writer's files is set with [`EncryptedAssetWriter::with_reader`].
+pub struct EncryptedAssetWriter {
+    writer: Box<dyn ErasedAssetWriter>,
+    reader: Option<Box<dyn ErasedAssetReader>>,
+    cipher: Arc<dyn AssetCipher>,
+}
+
+impl EncryptedAssetWriter {
+    /// Creates a new [`EncryptedAssetWriter`] that encrypts the files written to `writer` with `cipher`.
+    pub fn new(writer: Box<dyn ErasedAssetWriter>, cipher: Arc<dyn AssetCipher>) -> Self {
+        Self {
+            writer,
+            reader: None,
+            cipher,
+        }
+    }
+
+    /// Sets the reader of the (encrypted) files written by the inner writer, used to re-encrypt files when they are
+    /// renamed.
+    pub fn with_reader(mut self, reader: Box<dyn ErasedAssetReader>) -> Self {
+        self.reader = Some(reader);
+        self
+    }
+
+    /// Decrypts `bytes`, which were encrypted for `old_path`, and encrypts them for `new_path`.
+    fn reencrypt(
+        &self,
+        bytes: &[u8],
+        old_path: &Path,
+        new_path: &Path,
+    ) -> Result<Vec<u8>, AssetWriterError> {
+        let bytes = self
+            .cipher
+            .decrypt(old_path, bytes)
+            .map_err(|err| std::io::Error::new(std::io::ErrorKind::InvalidData, err))?;
+        Ok(self.cipher.encrypt(new_path, &bytes))
+    }
+
+    fn reader(&self) -> Result<&dyn ErasedAssetReader, AssetWriterError> {
+        self.reader.as_deref().ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::Unsupported,
+                "an encrypted asset can't be renamed without a reader to re-encrypt it",
+            )
+            .into()
+        })
+    }
+}
+
+fn reader_error(err: AssetReaderError) -> AssetWriterError {
+    match err {
+        AssetReaderError::NotFound(path) => std::io::Error::new(
+            std::io::ErrorKind::NotFound,
+            AssetReaderError::NotFound(path),
+        )
+        .into(),
+        err => std::io::Error::other(err).into(),
+    }
+}
+
+impl AssetWriter for EncryptedAssetWriter {
+    async fn write<'a>(&'a self, path: &'a Path) -> Result<Box<Writer>, AssetWriterError> {
+        let writer = self.writer.write(path).await?;
+        Ok(Box::new(EncryptingWriter::new(
+            writer,
+            self.cipher.clone(),
+            path.to_path_buf(),
+        )))
+    }
+
+    async fn write_meta<'a>(&'a self, path: &'a Path) -> Result<Box<Writer>, AssetWriterError> {
+        let writer = self.writer.write_meta(path).await?;
+        Ok(Box::new(EncryptingWriter::new(
+            writer,
+            self.cipher.clone(),
+            get_meta_path(path),
+        )))
+    }
+
+    async fn remove<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        self.writer.remove(path).await
+    }
+
+    async fn remove_meta<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        self.writer.remove_meta(path).await
+    }
+
+    async fn rename<'a>(
+        &'a self,
+        old_path: &'a Path,
+        new_path: &'a Path,
+    ) -> Result<(), AssetWriterError> {
+        let mut bytes = Vec::new();
+        self.reader()?
+            .read(old_path)
+            .await
+            .map_err(reader_error)?
+            .read_to_end(&mut bytes)
+            .await?;
+        let bytes = self.reencrypt(&bytes, old_path, new_path)?;
+        self.writer.write_bytes(new_path, &bytes).await?;
+        self.writer.remove(old_path).await
+    }
+
+    async fn rename_meta<'a>(
+        &'a self,
+        old_path: &'a Path,
+        new_path: &'a Path,
+    ) -> Result<(), AssetWriterError> {
+        let bytes = self
+            .reader()?
+            .read_meta_bytes(old_path)
+            .await
+            .map_err(reader_error)?;
+        let bytes = self.reencrypt(&bytes, &get_meta_path(old_path), &get_meta_path(new_path))?;
+        self.writer.write_meta_bytes(new_path, &bytes).await?;
+        self.writer.remove_meta(old_path).await
+    }
+
+    async fn create_directory<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        self.writer.create_directory(path).await
+    }
+
+    async fn remove_directory<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        self.writer.remove_directory(path).await
+    }
+
+    async fn remove_empty_directory<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        self.writer.remove_empty_directory(path).await
+    }
+
+    async fn remove_assets_in_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<(), AssetWriterError> {
+        self.writer.remove_assets_in_directory(path).await
+    }
+}
+
+/// Buffers the bytes of a file, and writes them encrypted to the inner [`Writer`] when flushed.
+struct EncryptingWriter {
+    writer: Box<Writer>,
+    cipher: Arc<dyn AssetCipher>,
+    path: PathBuf,
+    buffer: Vec<u8>,
+    /// The encrypted file, and the number of its bytes written so far.
+    encrypted: Option<(Vec<u8>, usize)>,
+}
+
+impl EncryptingWriter {
+    fn new(writer: Box<Writer>, cipher: Arc<dyn AssetCipher>, path: PathBuf) -> Self {
+        Self {
+            writer,
+            cipher,
+            path,
+            buffer: Vec::new(),
+            encrypted: None,
+        }
+    }
+}
+
+impl AsyncWrite for EncryptingWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let this = self.get_mut();
+        if this.encrypted.is_some() {
+            return Poll::Ready(Err(std::io::Error::other(
+                "an encrypted asset can't be written to after it has been flushed",
+            )));
+        }
+        this.buffer.extend_from_slice(buf);
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        let this = self.get_mut();
+        let (encrypted, written) = this.encrypted.get_or_insert_with(|| {
+            let buffer = core::mem::take(&mut this.buffer);
+            (this.cipher.encrypt(&this.path, &buffer), 0)
+        });
+        while *written < encrypted.len() {
+            match ready!(Pin::new(&mut this.writer).poll_write(cx, &encrypted[*written..])) {
+                Ok(0) => return Poll::Ready(Err(std::io::ErrorKind::WriteZero.into())),
+                Ok(len) => *written += len,
+                Err(err) => return Poll::Ready(Err(err)),
+            }
+        }
+        Pin::new(&mut this.writer).poll_flush(cx)
+    }
+
+    fn poll_close(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        ready!(self.as_mut().poll_flush(cx))?;
+        Pin::new(&mut self.writer).poll_close(cx)
+    }
+}
+
+impl AssetSourceBuilder {
+    /// Encrypts the processed assets of this source with `cipher`: the processed writer used by the
+    /// [`AssetProcessor`](crate::processor::AssetProcessor) encrypts them, and the processed reader decrypts them.
+    ///
+    /// This wraps the processed reader and writer set so far, so it must be called after
+    /// [`with_processed_reader`](AssetSourceBuilder::with_processed_reader) and
+    /// [`with_processed_writer`](AssetSourceBuilder::with_processed_writer). A processed reader or writer set
+    /// afterwards is not encrypted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the processed reader has not been set.
+    pub fn with_processed_encryption(mut self, cipher: impl AssetCipher) -> Self {
+        assert!(
+            self.processed_reader.is_some(),
+            "with_processed_encryption must be called after with_processed_reader"
+        );
+        encrypt(
+            &mut self.processed_reader,
+            &mut self.processed_writer,
+            Arc::new(cipher),
+        );
+        self
+    }
+
+    /// Encrypts the unprocessed assets of this source with `cipher`: the reader decrypts them, and the writer encrypts them.
+    ///
+    /// This wraps the reader and writer set so far, so it must be called after
+    /// [`with_reader`](AssetSourceBuilder::with_reader) and [`with_writer`](AssetSourceBuilder::with_writer).
+    /// A reader or writer set afterwards is not encrypted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the reader has not been set.
+    pub fn with_encryption(mut self, cipher: impl AssetCipher) -> Self {
+        assert!(
+            self.reader.is_some(),
+            "with_encryption must be called after with_reader"
+        );
+        encrypt(&mut self.reader, &mut self.writer, Arc::new(cipher));
+        self
+    }
+}
+
+/// Wraps the reader and writer of an [`AssetSourceBuilder`] in an [`EncryptedAssetReader`] and an
+/// [`EncryptedAssetWriter`]. The writer also gets a reader, to re-encrypt renamed files.
+fn encrypt(
+    reader: &mut Option<Box<dyn FnMut() -> Box<dyn ErasedAssetReader> + Send + Sync>>,
+    writer: &mut Option<Box<dyn FnMut(bool) -> Option<Box<dyn ErasedAssetWriter>> + Send + Sync>>,
+    cipher: Arc<dyn AssetCipher>,
+) {
+    let inner_reader = reader.take().map(|reader| Arc::new(Mutex::new(reader)));
+    if let Some(inner_reader) = inner_reader.clone() {
+        let cipher = cipher.clone();
+        *reader = Some(Box::new(move || {
+            Box::new(EncryptedAssetReader::new(
+                (*inner_reader.lock())(),
+                cipher.clone(),
+            ))
+        }));
+    }
+    if let Some(mut inner_writer) = writer.take() {
+        *writer = Some(Box::new(move |create_root| {
+            inner_writer(create_root).map(|writer| {
+                let mut writer = EncryptedAssetWriter::new(writer, cipher.clone());
+                if let Some(inner_reader) = &inner_reader {
+                    writer = writer.with_reader((*inner_reader.lock())());
+                }
+                Box::new(writer) as Box<dyn ErasedAssetWriter>
+            })
+        }));
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::{
+        AssetCipher, AssetCipherError, EncryptedAssetReader, EncryptedAssetWriter, KeyedAssetCipher,
+    };
+    use crate::io::{
+        file::{FileAssetReader, FileAssetWriter},
+        AssetReader, AssetReaderError, AssetSource, AssetWriter,
+    };
+    use alloc::{boxed::Box, format, sync::Arc, vec::Vec};
+    use futures_lite::{future::block_on, AsyncReadExt, AsyncWriteExt};
+    use std::path::Path;
+
+    #[test]
+    fn cipher_round_trip() {
+        let cipher = KeyedAssetCipher::new([7; 32]);
+        let path = Path::new("a.txt");
+        let encrypted = cipher.encrypt(path, b"hello world");
+        assert_ne!(&encrypted[KeyedAssetCipher::MAGIC.len()..], b"hello world");
+        assert_eq!(cipher.decrypt(path, &encrypted).unwrap(), b"hello world");
+
+        let other_key = KeyedAssetCipher::new([8; 32]);
+        assert_eq!(
+            other_key.decrypt(path, &encrypted),
+            Err(AssetCipherError::AuthenticationFailed)
+        );
+        let mut modified = encrypted.clone();
+        *modified.last_mut().unwrap() ^= 1;
+        assert_eq!(
+            cipher.decrypt(path, &modified),
+            Err(AssetCipherError::AuthenticationFailed)
+        );
+        assert_eq!(
+            cipher.decrypt(path, b"hello world"),
+            Err(AssetCipherError::InvalidFormat)
+        );
+    }
+
+    #[test]
+    fn encrypted_writer_and_reader_round_trip() {
+        let dir =
+            std::env::temp_dir().join(format!("bevy_asset_encrypted_test_{}", std::process::id()));
+        let cipher: Arc<dyn AssetCipher> = Arc::new(KeyedAssetCipher::new(|path: &Path| {
+            *blake3::hash(path.to_string_lossy().as_bytes()).as_bytes()
+        }));
+        let writer =
+            EncryptedAssetWriter::new(Box::new(FileAssetWriter::new(&dir, true)), cipher.clone());
+        let reader = EncryptedAssetReader::new(Box::new(FileAssetReader::new(&dir)), cipher);
+
+        block_on(async {
+            let path = Path::new("text.txt");
+            let mut asset_writer = writer.write(path).await.unwrap();
+            asset_writer.write_all(b"secret ").await.unwrap();
+            asset_writer.write_all(b"asset").await.unwrap();
+            asset_writer.flush().await.unwrap();
+            assert!(asset_writer.write_all(b"more").await.is_err());
+            writer.write_meta_bytes(path, b"meta").await.unwrap();
+
+            let raw = std::fs::read(dir.join(path)).unwrap();
+            assert!(!raw.windows(6).any(|window| window == b"secret"));
+
+            let mut text = Vec::new();
+            let mut asset_reader = reader.read(path).await.unwrap();
+            asset_reader.read_to_end(&mut text).await.unwrap();
+            assert_eq!(text, b"secret asset");
+            assert_eq!(reader.read_meta_bytes(path).await.unwrap(), b"meta");
+
+            std::fs::write(dir.join("plain.txt"), b"plain").unwrap();
+            assert!(matches!(
+                reader.read(Path::new("plain.txt")).await.err(),
+                Some(AssetReaderError::Io(_))
+            ));
+        });
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn renamed_files_are_reencrypted() {
+        let dir = std::env::temp_dir().join(format!(
+            "bevy_asset_encrypted_rename_test_{}",
+            std::process::id()
+        ));
+        let cipher: Arc<dyn AssetCipher> = Arc::new(KeyedAssetCipher::new(|path: &Path| {
+            *blake3::hash(path.to_string_lossy().as_bytes()).as_bytes()
+        }));
+        let writer =
+            EncryptedAssetWriter::new(Box::new(FileAssetWriter::new(&dir, true)), cipher.clone())
+                .with_reader(Box::new(FileAssetReader::new(&dir)));
+        let reader =
+            EncryptedAssetReader::new(Box::new(FileAssetReader::new(&dir)), cipher.clone());
+
+        block_on(async {
+            let (old_path, new_path) = (Path::new("old.txt"), Path::new("new.txt"));
+            writer.write_bytes(old_path, b"asset").await.unwrap();
+            writer.write_meta_bytes(old_path, b"meta").await.unwrap();
+            writer.rename(old_path, new_path).await.unwrap();
+            writer.rename_meta(old_path, new_path).await.unwrap();
+
+            let mut text = Vec::new();
+            let mut asset_reader = reader.read(new_path).await.unwrap();
+            asset_reader.read_to_end(&mut text).await.unwrap();
+            assert_eq!(text, b"asset");
+            assert_eq!(reader.read_meta_bytes(new_path).await.unwrap(), b"meta");
+            assert!(matches!(
+                reader.read(old_path).await.err(),
+                Some(AssetReaderError::NotFound(_))
+            ));
+            assert!(matches!(
+                reader.read_meta_bytes(old_path).await.err(),
+                Some(AssetReaderError::NotFound(_))
+            ));
+
+            // Without a reader, the file can't be re-encrypted, so it is not renamed
+            let writer =
+                EncryptedAssetWriter::new(Box::new(FileAssetWriter::new(&dir, true)), cipher);
+            assert!(writer.rename(new_path, old_path).await.is_err());
+            assert!(reader.read(new_path).await.is_ok());
+        });
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "with_encryption must be called after with_reader")]
+    fn encryption_requires_a_reader() {
+        let _ = AssetSource::build().with_encryption(KeyedAssetCipher::new([7; 32]));
+    }
+}
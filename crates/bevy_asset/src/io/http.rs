@@ -0,0 +1,382 @@
+use crate::io::{
+    get_meta_path, AssetReader, AssetReaderError, AssetSourceBuilder, EmptyPathStream, PathStream,
+    Reader, VecReader,
+};
+use alloc::{borrow::ToOwned, boxed::Box, string::String, sync::Arc, vec::Vec};
+use core::time::Duration;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use serde::{Deserialize, Serialize};
+use std::{
+    io::Read,
+    path::{Path, PathBuf},
+};
+use tracing::{error, warn};
+
+/// The characters percent-encoded in each segment of an asset's URL: everything except the
+/// unreserved characters of RFC 3986.
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'.')
+    .remove(b'_')
+    .remove(b'~');
+
+/// Reader implementation for loading assets over `http://` and `https://` on native platforms.
+///
+/// Asset paths are appended to the base URL, so with a base URL of `https://cdn.example.com/assets`
+/// the path `textures/player.png` is fetched from `https://cdn.example.com/assets/textures/player.png`.
+/// Loading over `https://` requires the `https` cargo feature.
+///
+/// If a [cache directory](HttpAssetReader::with_cache_directory) is set, downloaded assets are stored
+/// there along with their `ETag` and `Last-Modified` response headers. Later requests for the same asset
+/// are revalidated using `If-None-Match` / `If-Modified-Since`, and a `304 Not Modified` response is
+/// served from the cache. If the server can't be reached, the cached copy is used instead.
+///
+/// ```no_run
+/// # use bevy_app::App;
+/// # use bevy_asset::io::{http::HttpAssetReader, AssetSourceBuilder};
+/// # use bevy_asset::AssetApp;
+/// # use core::time::Duration;
+/// let reader = HttpAssetReader::new("https://cdn.example.com/assets")
+///     .with_cache_directory("cache/cdn")
+///     .with_timeout(Duration::from_secs(30));
+/// App::new().register_asset_source("cdn", AssetSourceBuilder::http(reader));
+/// ```
+#[derive(Clone)]
+pub struct HttpAssetReader {
+    base_url: String,
+    cache_directory: Option<PathBuf>,
+    connect_timeout: Duration,
+    timeout: Option<Duration>,
+    agent: ureq::Agent,
+}
+
+/// The response headers stored next to a cached asset, used to revalidate it.
+#[derive(Serialize, Deserialize, Default)]
+struct CacheEntry {
+    etag: Option<String>,
+    last_modified: Option<String>,
+}
+
+impl HttpAssetReader {
+    /// The default time allowed for connecting to the server.
+    pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+    /// Creates a new `HttpAssetReader`. The URL provided will be used as the base to build URLs to query for assets.
+    pub fn new(base_url: impl Into<String>) -> Self {
+        let mut base_url = base_url.into();
+        while base_url.ends_with('/') {
+            base_url.pop();
+        }
+        let connect_timeout = Self::DEFAULT_CONNECT_TIMEOUT;
+        Self {
+            base_url,
+            cache_directory: None,
+            connect_timeout,
+            timeout: None,
+            agent: build_agent(connect_timeout, None),
+        }
+    }
+
+    /// Caches downloaded assets in `path`, and revalidates them with the server instead of downloading them again.
+    pub fn with_cache_directory(mut self, path: impl Into<PathBuf>) -> Self {
+        self.cache_directory = Some(path.into());
+        self
+    }
+
+    /// Sets the time allowed for connecting to the server. Defaults to [`Self::DEFAULT_CONNECT_TIMEOUT`].
+    pub fn with_connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
+        self.agent = build_agent(self.connect_timeout, self.timeout);
+        self
+    }
+
+    /// Sets the time allowed for a whole request, including downloading the response. Unlimited by default.
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self.agent = build_agent(self.connect_timeout, self.timeout);
+        self
+    }
+
+    /// Returns the base URL that asset paths are appended to.
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Returns the URL the asset at `path` is fetched from.
+    ///
+    /// Each component of the path is percent-encoded, so names containing spaces, `#`, `?` or
+    /// non-ASCII characters are fetched as-is.
+    pub fn url(&self, path: &Path) -> String {
+        let mut url = self.base_url.clone();
+        for component in path.components() {
+            url.push('/');
+            url.extend(utf8_percent_encode(
+                &component.as_os_str().to_string_lossy(),
+                PATH_SEGMENT,
+            ));
+        }
+        url
+    }
+
+    async fn fetch_bytes(&self, path: &Path) -> Result<VecReader, AssetReaderError> {
+        let path = path.to_owned();
+        let url = self.url(&path);
+        let agent = self.agent.clone();
+        let cache_paths = self.cache_directory.as_ref().map(|directory| {
+            let name = blake3::hash(url.as_bytes()).to_hex();
+            (
+                directory.join(name.as_str()),
+                directory.join(alloc::format!("{name}.ron")),
+            )
+        });
+        blocking::unblock(move || {
+            fetch_blocking(
+                &agent,
+                &url,
+                cache_paths.as_ref().map(|(a, b)| (&**a, &**b)),
+            )
+            .map(VecReader::new)
+            .map_err(|error| match error {
+                FetchError::NotFound => AssetReaderError::NotFound(path),
+                FetchError::Status(status) => AssetReaderError::HttpError(status),
+                FetchError::Io(error) => AssetReaderError::Io(Arc::new(error)),
+            })
+        })
+        .await
+    }
+}
+
+fn build_agent(connect_timeout: Duration, timeout: Option<Duration>) -> ureq::Agent {
+    let mut builder = ureq::AgentBuilder::new().timeout_connect(connect_timeout);
+    if let Some(timeout) = timeout {
+        builder = builder.timeout(timeout);
+    }
+    builder.build()
+}
+
+enum FetchError {
+    NotFound,
+    Status(u16),
+    Io(std::io::Error),
+}
+
+fn fetch_blocking(
+    agent: &ureq::Agent,
+    url: &str,
+    cache_paths: Option<(&Path, &Path)>,
+) -> Result<Vec<u8>, FetchError> {
+    let cached = cache_paths.and_then(|(data_path, entry_path)| {
+        let entry = std::fs::read_to_string(entry_path).ok()?;
+        let entry: CacheEntry = ron::from_str(&entry).ok()?;
+        data_path.exists().then_some(entry)
+    });
+
+    let mut request = agent.get(url);
+    if let Some(entry) = &cached {
+        if let Some(etag) = &entry.etag {
+            request = request.set("If-None-Match", etag);
+        }
+        if let Some(last_modified) = &entry.last_modified {
+            request = request.set("If-Modified-Since", last_modified);
+        }
+    }
+
+    let response = match request.call() {
+        Ok(response) => response,
+        Err(ureq::Error::Status(404, _)) => return Err(FetchError::NotFound),
+        Err(ureq::Error::Status(status, _)) => return Err(FetchError::Status(status)),
+        Err(ureq::Error::Transport(transport)) => {
+            return match (cached, cache_paths) {
+                (Some(_), Some((data_path, _))) => {
+                    warn!("Failed to reach {url}, using the cached copy instead: {transport}");
+                    std::fs::read(data_path).map_err(FetchError::Io)
+                }
+                _ => Err(FetchError::Io(std::io::Error::other(transport))),
+            };
+        }
+    };
+
+    match (response.status(), cache_paths) {
+        (304, Some((data_path, _))) if cached.is_some() => {
+            std::fs::read(data_path).map_err(FetchError::Io)
+        }
+        (200, _) => {
+            let entry = CacheEntry {
+                etag: response.header("ETag").map(ToOwned::to_owned),
+                last_modified: response.header("Last-Modified").map(ToOwned::to_owned),
+            };
+            let mut bytes = Vec::new();
+            response
+                .into_reader()
+                .read_to_end(&mut bytes)
+                .map_err(FetchError::Io)?;
+            if let Some((data_path, entry_path)) = cache_paths {
+                if let Err(error) = write_cache(data_path, entry_path, &bytes, &entry) {
+                    warn!("Failed to cache {url}: {error}");
+                }
+            }
+            Ok(bytes)
+        }
+        (status, _) => Err(FetchError::Status(status)),
+    }
+}
+
+fn write_cache(
+    data_path: &Path,
+    entry_path: &Path,
+    bytes: &[u8],
+    entry: &CacheEntry,
+) -> std::io::Result<()> {
+    if let Some(parent) = data_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    // Remove the old entry first, so a partially written cache is never revalidated.
+    let _ = std::fs::remove_file(entry_path);
+    std::fs::write(data_path, bytes)?;
+    if entry.etag.is_some() || entry.last_modified.is_some() {
+        let entry = ron::to_string(entry).map_err(std::io::Error::other)?;
+        std::fs::write(entry_path, entry)?;
+    }
+    Ok(())
+}
+
+impl AssetReader for HttpAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        self.fetch_bytes(path).await
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        self.fetch_bytes(&get_meta_path(path)).await
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        _path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        let stream: Box<PathStream> = Box::new(EmptyPathStream);
+        error!("Reading directories is not supported with the HttpAssetReader");
+        Ok(stream)
+    }
+
+    async fn is_directory<'a>(&'a self, _path: &'a Path) -> Result<bool, AssetReaderError> {
+        error!("Reading directories is not supported with the HttpAssetReader");
+        Ok(false)
+    }
+}
+
+impl AssetSourceBuilder {
+    /// Returns a builder for a source that reads assets over HTTP using the given [`HttpAssetReader`].
+    pub fn http(reader: HttpAssetReader) -> Self {
+        Self::default().with_reader(move || Box::new(reader.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HttpAssetReader;
+    use crate::io::{AssetReader, AssetReaderError};
+    use alloc::{borrow::ToOwned, string::String, sync::Arc, vec::Vec};
+    use futures_lite::{future::block_on, AsyncReadExt};
+    use parking_lot::Mutex;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        path::Path,
+    };
+
+    /// Serves `hello.txt` with an `ETag`, and records the `If-None-Match` header of each request.
+    fn serve(requests: Arc<Mutex<Vec<(String, Option<String>)>>>) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request_line = String::new();
+                reader.read_line(&mut request_line).unwrap();
+                let path = request_line.split(' ').nth(1).unwrap().to_owned();
+                let mut if_none_match = None;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    let line = line.trim_end();
+                    if line.is_empty() {
+                        break;
+                    }
+                    if let Some((name, value)) = line.split_once(':') {
+                        if name.eq_ignore_ascii_case("if-none-match") {
+                            if_none_match = Some(value.trim().to_owned());
+                        }
+                    }
+                }
+                let response = match (path.as_str(), if_none_match.as_deref()) {
+                    ("/assets/hello.txt", Some("\"v1\"")) => {
+                        "HTTP/1.1 304 Not Modified\r\nETag: \"v1\"\r\nContent-Length: 0\r\nConnection: close\r\n\r\n"
+                    }
+                    ("/assets/hello.txt", _) => {
+                        "HTTP/1.1 200 OK\r\nETag: \"v1\"\r\nContent-Length: 5\r\nConnection: close\r\n\r\nhello"
+                    }
+                    _ => "HTTP/1.1 404 Not Found\r\nContent-Length: 0\r\nConnection: close\r\n\r\n",
+                };
+                requests.lock().push((path, if_none_match));
+                stream.write_all(response.as_bytes()).unwrap();
+            }
+        });
+        alloc::format!("http://{address}/assets/")
+    }
+
+    fn read_to_string(reader: &HttpAssetReader, path: &str) -> Result<String, AssetReaderError> {
+        block_on(async {
+            let mut reader = reader.read(Path::new(path)).await?;
+            let mut text = String::new();
+            reader.read_to_string(&mut text).await.unwrap();
+            Ok(text)
+        })
+    }
+
+    #[test]
+    fn fetches_and_revalidates_cached_assets() {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let base_url = serve(requests.clone());
+        let cache_directory = std::env::temp_dir().join(alloc::format!(
+            "bevy_asset_http_test_{}",
+            std::process::id()
+        ));
+        let reader = HttpAssetReader::new(base_url).with_cache_directory(&cache_directory);
+
+        assert_eq!(read_to_string(&reader, "hello.txt").unwrap(), "hello");
+        // The second read is revalidated, and served from the cache.
+        assert_eq!(read_to_string(&reader, "hello.txt").unwrap(), "hello");
+        assert!(matches!(
+            read_to_string(&reader, "missing.txt"),
+            Err(AssetReaderError::NotFound(path)) if path == Path::new("missing.txt")
+        ));
+
+        assert_eq!(
+            *requests.lock(),
+            [
+                ("/assets/hello.txt".into(), None),
+                ("/assets/hello.txt".into(), Some("\"v1\"".into())),
+                ("/assets/missing.txt".into(), None),
+            ]
+        );
+        std::fs::remove_dir_all(cache_directory).ok();
+    }
+
+    #[test]
+    fn percent_encodes_path_segments() {
+        let reader = HttpAssetReader::new("https://cdn.example.com/assets/");
+        assert_eq!(
+            reader.url(Path::new("textures/my player #1.png")),
+            "https://cdn.example.com/assets/textures/my%20player%20%231.png"
+        );
+        assert_eq!(
+            reader.url(Path::new("100%/caf\u{e9}?.txt")),
+            "https://cdn.example.com/assets/100%25/caf%C3%A9%3F.txt"
+        );
+        assert_eq!(
+            reader.url(Path::new("sounds/jump-1_final~.ogg")),
+            "https://cdn.example.com/assets/sounds/jump-1_final~.ogg"
+        );
+    }
+}
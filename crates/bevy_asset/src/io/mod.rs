@@ -7,10 +7,14 @@ compile_error!(
 
 #[cfg(target_os = "android")]
 pub mod android;
+pub mod archive;
 pub mod embedded;
+pub mod encrypted;
 #[cfg(not(target_arch = "wasm32"))]
 pub mod file;
 pub mod gated;
+#[cfg(all(feature = "http", not(target_arch = "wasm32")))]
+pub mod http;
 pub mod memory;
 pub mod processor_gated;
 #[cfg(target_arch = "wasm32")]
@@ -771,11 +775,19 @@ pub(crate) fn get_meta_path(path: &Path) -> PathBuf {
     meta_path
 }
 
-#[cfg(any(target_arch = "wasm32", target_os = "android"))]
+#[cfg(any(
+    target_arch = "wasm32",
+    target_os = "android",
+    all(feature = "http", not(target_arch = "wasm32"))
+))]
 /// A [`PathBuf`] [`Stream`] implementation that immediately returns nothing.
 struct EmptyPathStream;
 
-#[cfg(any(target_arch = "wasm32", target_os = "android"))]
+#[cfg(any(
+    target_arch = "wasm32",
+    target_os = "android",
+    all(feature = "http", not(target_arch = "wasm32"))
+))]
 impl Stream for EmptyPathStream {
     type Item = PathBuf;
 
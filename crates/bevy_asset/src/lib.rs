@@ -176,6 +176,7 @@ mod event;
 mod folder;
 mod handle;
 mod id;
+mod load_group;
 mod loader;
 mod loader_builders;
 mod path;
@@ -191,6 +192,7 @@ pub use folder::*;
 pub use futures_lite::{AsyncReadExt, AsyncWriteExt};
 pub use handle::*;
 pub use id::*;
+pub use load_group::*;
 pub use loader::*;
 pub use loader_builders::{
     Deferred, DynamicTyped, Immediate, NestedLoader, StaticTyped, UnknownTyped,
@@ -222,6 +224,7 @@ use bevy_ecs::{
 use bevy_platform_support::collections::HashSet;
 use bevy_reflect::{FromReflect, GetTypeRegistration, Reflect, TypePath};
 use core::any::TypeId;
+use serde::{Deserialize, Serialize};
 use tracing::error;
 
 #[cfg(all(feature = "file_watcher", not(feature = "multi_threaded")))]
@@ -254,6 +257,18 @@ pub struct AssetPlugin {
     pub mode: AssetMode,
     /// How/If asset meta files should be checked.
     pub meta_check: AssetMetaCheck,
+    /// If set, limits the number of asset loads that run at the same time. Further loads are queued and started in
+    /// [`LoadPriority`] order, so that important loads aren't stuck behind less important ones.
+    /// Loads are cancelled if all of their handles are dropped before they finish.
+    ///
+    /// This can be changed at runtime with [`AssetServer::set_max_concurrent_loads`].
+    pub max_concurrent_loads: Option<usize>,
+    /// If set, the [`AssetProcessor`] stores processed assets in a [`ProcessorCache`](processor::ProcessorCache) in this
+    /// directory (relative to the project root), and reuses them instead of processing unchanged assets again.
+    /// The directory can be shared across checkouts and machines.
+    ///
+    /// This is only used in [`AssetMode::Processed`].
+    pub processor_cache_path: Option<String>,
 }
 
 /// Controls whether or not assets are pre-processed before being loaded.
@@ -307,6 +322,8 @@ impl Default for AssetPlugin {
             processed_file_path: Self::DEFAULT_PROCESSED_FILE_PATH.to_string(),
             watch_for_changes_override: None,
             meta_check: AssetMetaCheck::default(),
+            max_concurrent_loads: None,
+            processor_cache_path: None,
         }
     }
 }
@@ -354,12 +371,18 @@ impl Plugin for AssetPlugin {
                     {
                         let mut builders = app.world_mut().resource_mut::<AssetSourceBuilders>();
                         let processor = AssetProcessor::new(&mut builders);
+                        if let Some(cache_path) = &self.processor_cache_path {
+                            #[cfg(not(target_arch = "wasm32"))]
+                            let cache_path = io::file::get_base_path().join(cache_path);
+                            processor.set_cache(Some(processor::ProcessorCache::new(cache_path)));
+                        }
                         let mut sources = builders.build_sources(false, watch);
                         sources.gate_on_processor(processor.data.clone());
                         // the main asset server shares loaders with the processor asset server
                         app.insert_resource(AssetServer::new_with_loaders(
                             sources,
                             processor.server().data.loaders.clone(),
+                            processor.server().data.meta_migrations.clone(),
                             AssetServerMode::Processed,
                             AssetMetaCheck::Always,
                             watch,
@@ -380,11 +403,16 @@ impl Plugin for AssetPlugin {
                     }
                 }
             }
+            app.world()
+                .resource::<AssetServer>()
+                .set_max_concurrent_loads(self.max_concurrent_loads);
         }
         app.insert_resource(embedded)
             .init_asset::<LoadedFolder>()
             .init_asset::<LoadedUntypedAsset>()
             .init_asset::<()>()
+            .init_asset::<LoadGroupManifest>()
+            .register_asset_loader(LoadGroupManifestLoader)
             .add_event::<UntypedAssetLoadFailedEvent>()
             .configure_sets(PreUpdate, TrackAssets.after(handle_internal_asset_events))
             // `handle_internal_asset_events` requires the use of `&mut World`,
@@ -392,6 +420,10 @@ impl Plugin for AssetPlugin {
             // This is virtually never a real problem: asset loading is async and so anything that interacts directly with it
             // needs to be robust to stochastic delays anyways.
             .add_systems(PreUpdate, handle_internal_asset_events.ambiguous_with_all())
+            .add_systems(
+                PreUpdate,
+                track_load_groups.after(handle_internal_asset_events),
+            )
             .register_type::<AssetPath>();
     }
 }
@@ -403,12 +435,38 @@ impl Plugin for AssetPlugin {
 ///
 /// [`TypePath`] is largely used for diagnostic purposes, and should almost always be implemented by deriving [`Reflect`] on your type.
 /// [`VisitAssetDependencies`] is used to track asset dependencies, and an implementation is automatically generated when deriving [`Asset`].
+///
+/// [`Asset::size_in_bytes`] is used for [memory accounting](Assets::memory_usage). When deriving [`Asset`], it can be overridden
+/// with a function that takes `&Self`:
+///
+/// ```
+/// # use bevy_asset::Asset;
+/// # use bevy_reflect::TypePath;
+/// #[derive(Asset, TypePath)]
+/// #[asset(size_in_bytes = Heightmap::estimate_size)]
+/// struct Heightmap {
+///     heights: Vec<f32>,
+/// }
+///
+/// impl Heightmap {
+///     fn estimate_size(&self) -> usize {
+///         size_of::<Self>() + self.heights.len() * size_of::<f32>()
+///     }
+/// }
+/// ```
 #[diagnostic::on_unimplemented(
     message = "`{Self}` is not an `Asset`",
     label = "invalid `Asset`",
     note = "consider annotating `{Self}` with `#[derive(Asset)]`"
 )]
-pub trait Asset: VisitAssetDependencies + TypePath + Send + Sync + 'static {}
+pub trait Asset: VisitAssetDependencies + TypePath + Send + Sync + 'static {
+    /// Returns an estimate of the memory used by this asset, including its heap allocations.
+    ///
+    /// Defaults to the size of the asset type itself, which doesn't account for heap allocations.
+    fn size_in_bytes(&self) -> usize {
+        size_of_val(self)
+    }
+}
 
 /// A trait for components that can be used as asset identifiers, e.g. handle wrappers.
 pub trait AsAssetId: Component {
@@ -508,6 +566,28 @@ pub trait AssetApp {
     /// Preregisters a loader for the given extensions, that will block asset loads until a real loader
     /// is registered.
     fn preregister_asset_loader<L: AssetLoader>(&mut self, extensions: &[&str]) -> &mut Self;
+    /// Registers a migration of the settings of the `L` [`AssetLoader`] from `from_version` to `from_version + 1`
+    /// in the [`App`]'s [`AssetServer`]. See [`MetaMigrations`](meta::MetaMigrations).
+    fn register_asset_loader_migration<L, From, To>(
+        &mut self,
+        from_version: u32,
+        migrate: impl Fn(From) -> To + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        L: AssetLoader,
+        From: for<'a> Deserialize<'a>,
+        To: Serialize;
+    /// Registers a migration of the settings of the `P` [`Process`]or from `from_version` to `from_version + 1`
+    /// in the [`App`]'s [`AssetServer`]. See [`MetaMigrations`](meta::MetaMigrations).
+    fn register_asset_processor_migration<P, From, To>(
+        &mut self,
+        from_version: u32,
+        migrate: impl Fn(From) -> To + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        P: Process,
+        From: for<'a> Deserialize<'a>,
+        To: Serialize;
 }
 
 impl AssetApp for App {
@@ -611,6 +691,38 @@ impl AssetApp for App {
             .preregister_loader::<L>(extensions);
         self
     }
+
+    fn register_asset_loader_migration<L, From, To>(
+        &mut self,
+        from_version: u32,
+        migrate: impl Fn(From) -> To + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        L: AssetLoader,
+        From: for<'a> Deserialize<'a>,
+        To: Serialize,
+    {
+        self.world()
+            .resource::<AssetServer>()
+            .register_loader_meta_migration::<L, From, To>(from_version, migrate);
+        self
+    }
+
+    fn register_asset_processor_migration<P, From, To>(
+        &mut self,
+        from_version: u32,
+        migrate: impl Fn(From) -> To + Send + Sync + 'static,
+    ) -> &mut Self
+    where
+        P: Process,
+        From: for<'a> Deserialize<'a>,
+        To: Serialize,
+    {
+        self.world()
+            .resource::<AssetServer>()
+            .register_processor_meta_migration::<P, From, To>(from_version, migrate);
+        self
+    }
 }
 
 /// A system set that holds all "track asset" operations.
@@ -636,7 +748,8 @@ mod tests {
         },
         loader::{AssetLoader, LoadContext},
         Asset, AssetApp, AssetEvent, AssetId, AssetLoadError, AssetLoadFailedEvent, AssetPath,
-        AssetPlugin, AssetServer, Assets,
+        AssetPlugin, AssetServer, Assets, LoadGroup, LoadGroupFailed, LoadGroupLoaded,
+        LoadGroupManifest, LoadGroupProgress, LoadGroupState, LoadPriority, LoadedUntypedAsset,
     };
     use alloc::{
         boxed::Box,
@@ -1436,6 +1549,53 @@ mod tests {
         );
     }
 
+    #[test]
+    fn managed_assets_report_state_and_handles() {
+        // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
+        #[cfg(not(feature = "multi_threaded"))]
+        panic!("This test requires the \"multi_threaded\" feature, otherwise it will deadlock.\ncargo test --package bevy_asset --features multi_threaded");
+
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("dep.cool.ron"), SIMPLE_TEXT);
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader);
+
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let handle: Handle<CoolText> = asset_server.load("dep.cool.ron");
+        let second_handle = handle.clone();
+        let id = handle.id().untyped();
+        let managed_asset = |asset_server: &AssetServer| {
+            asset_server
+                .managed_assets()
+                .into_iter()
+                .find(|info| info.id == id)
+                .unwrap()
+        };
+
+        let info = managed_asset(&asset_server);
+        assert_eq!(info.path, Some(AssetPath::from("dep.cool.ron")));
+        assert!(info.load_state.is_loading());
+
+        gate_opener.open("dep.cool.ron");
+        run_app_until(&mut app, |world| {
+            world
+                .resource::<AssetServer>()
+                .is_loaded_with_dependencies(&handle)
+                .then_some(())
+        });
+
+        let info = managed_asset(&asset_server);
+        assert!(info.load_state.is_loaded());
+        assert!(info.recursive_dependency_load_state.is_loaded());
+        assert_eq!(info.strong_handle_count, 2);
+
+        drop(second_handle);
+        assert_eq!(managed_asset(&asset_server).strong_handle_count, 1);
+    }
+
     #[test]
     fn manual_asset_management() {
         // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
@@ -1756,6 +1916,616 @@ mod tests {
         });
     }
 
+    #[test]
+    fn queued_loads_start_by_priority_and_cancel_when_dropped() {
+        let dir = Dir::default();
+        let paths = ["a.cool.ron", "b.cool.ron", "c.cool.ron", "d.cool.ron"];
+        for path in paths {
+            let text = path.split('.').next().unwrap();
+            dir.insert_asset_text(
+                Path::new(path),
+                &format!(
+                    "(text: \"{text}\", dependencies: [], embedded_dependencies: [], sub_texts: [])"
+                ),
+            );
+        }
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_resource::<StoredEvents>()
+            .register_asset_loader(CoolTextLoader)
+            .add_systems(Update, store_asset_events);
+        for path in paths {
+            gate_opener.open(path);
+        }
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        // Don't start any loads until all of them are queued.
+        asset_server.set_max_concurrent_loads(Some(0));
+
+        let a: Handle<CoolText> = asset_server.load("a.cool.ron");
+        let b: Handle<CoolText> = asset_server
+            .load_builder()
+            .with_priority(LoadPriority::LOW)
+            .load("b.cool.ron");
+        let c: Handle<CoolText> = asset_server
+            .load_builder()
+            .with_priority(LoadPriority::HIGH)
+            .load("c.cool.ron");
+        let d: Handle<CoolText> = asset_server.load("d.cool.ron");
+        let d_id = d.id();
+        run_app_until(&mut app, |_| {
+            (asset_server.data.load_queue.waiting_len() == 4).then_some(())
+        });
+        drop(d);
+        asset_server.set_max_concurrent_loads(Some(1));
+
+        run_app_until(&mut app, |world| {
+            let loaded: Vec<_> = world
+                .resource::<StoredEvents>()
+                .0
+                .iter()
+                .filter_map(|event| match event {
+                    AssetEvent::LoadedWithDependencies { id } => Some(*id),
+                    _ => None,
+                })
+                .collect();
+            (loaded.len() == 3).then(|| assert_eq!(loaded, [c.id(), a.id(), b.id()]))
+        });
+        assert!(asset_server.get_load_state(d_id).is_none());
+        assert!(get::<CoolText>(app.world(), d_id).is_none());
+    }
+
+    #[test]
+    fn dropping_handles_cancels_loads_in_progress() {
+        // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
+        #[cfg(not(feature = "multi_threaded"))]
+        panic!("This test requires the \"multi_threaded\" feature, otherwise it will deadlock.\ncargo test --package bevy_asset --features multi_threaded");
+
+        let dir = Dir::default();
+        for path in ["a.cool.ron", "b.cool.ron"] {
+            let text = path.split('.').next().unwrap();
+            dir.insert_asset_text(
+                Path::new(path),
+                &format!(
+                    "(text: \"{text}\", dependencies: [], embedded_dependencies: [], sub_texts: [])"
+                ),
+            );
+        }
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .register_asset_loader(CoolTextLoader);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        // Don't start any loads until both of them are queued.
+        asset_server.set_max_concurrent_loads(Some(0));
+
+        let a: Handle<CoolText> = asset_server
+            .load_builder()
+            .with_priority(LoadPriority::HIGH)
+            .load("a.cool.ron");
+        let a_id = a.id();
+        // Untyped loads are queued too.
+        let b = asset_server.load_untyped("b.cool.ron");
+        gate_opener.open("b.cool.ron");
+        run_app_until(&mut app, |_| {
+            (asset_server.data.load_queue.waiting_len() == 2).then_some(())
+        });
+        // `a` takes the only slot, and blocks on its gate while loading.
+        asset_server.set_max_concurrent_loads(Some(1));
+
+        // The load of `a` doesn't keep it alive, so dropping its handle cancels the load.
+        drop(a);
+        run_app_until(&mut app, |_| {
+            asset_server.get_load_state(a_id).is_none().then_some(())
+        });
+        gate_opener.open("a.cool.ron");
+
+        run_app_until(&mut app, |world| {
+            world
+                .resource::<Assets<LoadedUntypedAsset>>()
+                .get(&b)
+                .map(|_| ())
+        });
+        assert!(get::<CoolText>(app.world(), a_id).is_none());
+    }
+
+    #[test]
+    fn unused_assets_are_cached_and_evicted_under_memory_budget() {
+        let dir = Dir::default();
+        let paths = ["a.cool.ron", "b.cool.ron", "c.cool.ron"];
+        for path in paths {
+            let text = path.split('.').next().unwrap();
+            dir.insert_asset_text(
+                Path::new(path),
+                &format!(
+                    "(text: \"{text}\", dependencies: [], embedded_dependencies: [], sub_texts: [])"
+                ),
+            );
+        }
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_resource::<StoredEvents>()
+            .register_asset_loader(CoolTextLoader)
+            .add_systems(Update, store_asset_events);
+        for path in paths {
+            gate_opener.open(path);
+        }
+        let size = size_of::<CoolText>();
+        app.world_mut()
+            .resource_mut::<Assets<CoolText>>()
+            .set_memory_budget(Some(3 * size));
+
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let a: Handle<CoolText> = asset_server.load("a.cool.ron");
+        let b: Handle<CoolText> = asset_server.load("b.cool.ron");
+        let c: Handle<CoolText> = asset_server.load("c.cool.ron");
+        let (a_id, b_id) = (a.id(), b.id());
+        run_app_until(&mut app, |world| {
+            let assets = world.resource::<Assets<CoolText>>();
+            (assets.len() == 3).then_some(())
+        });
+
+        // Unused assets are kept while they fit in the budget.
+        drop(a);
+        app.update();
+        drop(b);
+        app.update();
+        let assets = app.world().resource::<Assets<CoolText>>();
+        assert_eq!(assets.memory_usage(), 3 * size);
+        assert!(assets.is_cached(a_id));
+        assert!(assets.is_cached(b_id));
+        assert!(!assets.is_cached(&c));
+
+        // Lowering the budget evicts the asset that has been unused for the longest first.
+        app.world_mut()
+            .resource_mut::<Assets<CoolText>>()
+            .set_memory_budget(Some(2 * size));
+        app.update();
+        assert!(get::<CoolText>(app.world(), a_id).is_none());
+        assert!(asset_server.get_load_state(a_id).is_none());
+        assert!(get::<CoolText>(app.world(), b_id).is_some());
+
+        // Loading a cached asset again reuses it without reloading it.
+        let b: Handle<CoolText> = asset_server.load("b.cool.ron");
+        assert_eq!(b.id(), b_id);
+        assert!(asset_server.is_loaded_with_dependencies(&b));
+        app.update();
+        assert!(!app.world().resource::<Assets<CoolText>>().is_cached(&b));
+
+        // Assets that are in use are never evicted.
+        app.world_mut()
+            .resource_mut::<Assets<CoolText>>()
+            .set_memory_budget(Some(0));
+        app.update();
+        assert!(get::<CoolText>(app.world(), b.id()).is_some());
+        assert!(get::<CoolText>(app.world(), c.id()).is_some());
+        let loads = app
+            .world()
+            .resource::<StoredEvents>()
+            .0
+            .iter()
+            .filter(
+                |event| matches!(event, AssetEvent::LoadedWithDependencies { id } if *id == b_id),
+            )
+            .count();
+        assert_eq!(loads, 1);
+    }
+
+    #[test]
+    fn load_group_tracks_recursive_progress() {
+        // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
+        #[cfg(not(feature = "multi_threaded"))]
+        panic!("This test requires the \"multi_threaded\" feature, otherwise it will deadlock.\ncargo test --package bevy_asset --features multi_threaded");
+
+        let dir = Dir::default();
+        dir.insert_asset_text(
+            Path::new("a.cool.ron"),
+            r#"(text: "a", dependencies: ["b.cool.ron"], embedded_dependencies: [], sub_texts: [])"#,
+        );
+        dir.insert_asset_text(
+            Path::new("b.cool.ron"),
+            r#"(text: "b", dependencies: [], embedded_dependencies: [], sub_texts: [])"#,
+        );
+        dir.insert_asset_text(
+            Path::new("c.cool.ron"),
+            r#"(text: "c", dependencies: [], embedded_dependencies: [], sub_texts: [])"#,
+        );
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .register_asset_loader(CoolTextLoader);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let group = app
+            .world_mut()
+            .spawn(asset_server.load_group(["a.cool.ron", "c.cool.ron"]))
+            .observe(|_: Trigger<LoadGroupLoaded>, mut commands: Commands| {
+                commands.insert_resource(LoadGroupDone);
+            })
+            .id();
+
+        gate_opener.open("a.cool.ron");
+        gate_opener.open("c.cool.ron");
+        // `a` is waiting on its dependency, which is discovered and counted once `a` has loaded.
+        run_app_until(&mut app, |world| {
+            let progress = world.get::<LoadGroup>(group).unwrap().progress();
+            (progress.loaded == 2 && progress.total == 3).then_some(())
+        });
+        let load_group = app.world().get::<LoadGroup>(group).unwrap();
+        assert_eq!(load_group.state(), LoadGroupState::Loading);
+        assert!(!app.world().contains_resource::<LoadGroupDone>());
+
+        gate_opener.open("b.cool.ron");
+        run_app_until(&mut app, |world| {
+            world.contains_resource::<LoadGroupDone>().then_some(())
+        });
+        let load_group = app.world().get::<LoadGroup>(group).unwrap();
+        assert!(load_group.is_loaded());
+        assert_eq!(
+            load_group.progress(),
+            LoadGroupProgress {
+                loaded: 3,
+                failed: 0,
+                total: 3,
+            }
+        );
+        assert_eq!(load_group.progress().fraction(), 1.0);
+    }
+
+    #[test]
+    fn load_group_manifest_reports_failures() {
+        let dir = Dir::default();
+        dir.insert_asset_text(
+            Path::new("level.load_group.ron"),
+            r#"(paths: ["a.cool.ron", "missing.cool.ron"])"#,
+        );
+        dir.insert_asset_text(
+            Path::new("a.cool.ron"),
+            r#"(text: "a", dependencies: [], embedded_dependencies: [], sub_texts: [])"#,
+        );
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .register_asset_loader(CoolTextLoader);
+        for path in ["level.load_group.ron", "a.cool.ron", "missing.cool.ron"] {
+            gate_opener.open(path);
+        }
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let group = app
+            .world_mut()
+            .spawn(asset_server.load_group_manifest("level.load_group.ron"))
+            .observe(
+                |trigger: Trigger<LoadGroupFailed>, mut commands: Commands| {
+                    assert_eq!(trigger.errors.len(), 1);
+                    commands.insert_resource(LoadGroupDone);
+                },
+            )
+            .id();
+
+        run_app_until(&mut app, |world| {
+            world.contains_resource::<LoadGroupDone>().then_some(())
+        });
+        let load_group = app.world().get::<LoadGroup>(group).unwrap();
+        assert_eq!(load_group.state(), LoadGroupState::Failed);
+        let progress = load_group.progress();
+        // The manifest, `a` and the missing asset.
+        assert_eq!(
+            progress,
+            LoadGroupProgress {
+                loaded: 2,
+                failed: 1,
+                total: 3,
+            }
+        );
+        let handle = load_group.handles()[0].clone().typed::<LoadGroupManifest>();
+        let manifest = app
+            .world()
+            .resource::<Assets<LoadGroupManifest>>()
+            .get(&handle)
+            .unwrap();
+        assert_eq!(manifest.paths[1], AssetPath::from("missing.cool.ron"));
+    }
+
+    #[derive(Resource)]
+    struct LoadGroupDone;
+
+    #[derive(Asset, TypePath)]
+    struct SettingsText(String);
+
+    #[derive(Deserialize)]
+    struct SettingsTextSettingsV0 {
+        exclaim: bool,
+    }
+
+    #[derive(Serialize, Deserialize)]
+    struct SettingsTextSettingsV1 {
+        suffix: String,
+    }
+
+    #[derive(Serialize, Deserialize, Default)]
+    struct SettingsTextSettings {
+        suffix: String,
+        repeat: usize,
+    }
+
+    struct SettingsTextLoader;
+
+    impl AssetLoader for SettingsTextLoader {
+        type Asset = SettingsText;
+        type Settings = SettingsTextSettings;
+        type Error = std::io::Error;
+        const SETTINGS_VERSION: u32 = 2;
+
+        async fn load(
+            &self,
+            reader: &mut dyn Reader,
+            settings: &Self::Settings,
+            _load_context: &mut LoadContext<'_>,
+        ) -> Result<Self::Asset, Self::Error> {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let text = String::from_utf8_lossy(&bytes);
+            Ok(SettingsText(format!(
+                "{text}{}",
+                settings.suffix.repeat(settings.repeat)
+            )))
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["txt"]
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn meta_settings_are_migrated() {
+        use crate::io::file::{FileAssetReader, FileAssetWriter};
+
+        let dir = std::env::temp_dir().join(format!(
+            "bevy_asset_meta_migration_test_{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        let meta = |version: &str, settings: &str| {
+            format!(
+                r#"(meta_format_version: "1.0", {version} asset: Load(loader: "{}", settings: {settings}))"#,
+                core::any::type_name::<SettingsTextLoader>()
+            )
+        };
+        std::fs::write(dir.join("a.txt"), "a").unwrap();
+        std::fs::write(dir.join("a.txt.meta"), meta("", "(exclaim: true)")).unwrap();
+        std::fs::write(dir.join("b.txt"), "b").unwrap();
+        std::fs::write(
+            dir.join("b.txt.meta"),
+            meta("settings_version: 1,", r#"(suffix: "?")"#),
+        )
+        .unwrap();
+        std::fs::write(dir.join("c.txt"), "c").unwrap();
+        std::fs::write(
+            dir.join("c.txt.meta"),
+            meta("settings_version: 3,", r#"(suffix: "?", repeat: 1)"#),
+        )
+        .unwrap();
+
+        let mut app = App::new();
+        let (reader_dir, writer_dir) = (dir.clone(), dir.clone());
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(FileAssetReader::new(&reader_dir)))
+                .with_writer(move |_| Some(Box::new(FileAssetWriter::new(&writer_dir, false)))),
+        )
+        .add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+        .init_asset::<SettingsText>()
+        .register_asset_loader(SettingsTextLoader)
+        .register_asset_loader_migration::<SettingsTextLoader, _, _>(
+            0,
+            |old: SettingsTextSettingsV0| SettingsTextSettingsV1 {
+                suffix: if old.exclaim { "!" } else { "" }.to_string(),
+            },
+        )
+        .register_asset_loader_migration::<SettingsTextLoader, _, _>(
+            1,
+            |old: SettingsTextSettingsV1| SettingsTextSettings {
+                suffix: old.suffix,
+                repeat: 1,
+            },
+        );
+
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let a: Handle<SettingsText> = asset_server.load("a.txt");
+        let b: Handle<SettingsText> = asset_server.load("b.txt");
+        let c: Handle<SettingsText> = asset_server.load("c.txt");
+        run_app_until(&mut app, |world| {
+            let a = get(world, a.id())?;
+            let b = get(world, b.id())?;
+            assert_eq!(a.0, "a!");
+            assert_eq!(b.0, "b?");
+            asset_server.load_state(&c).is_failed().then_some(())
+        });
+
+        // `c.txt` can't be migrated, see `migrate_meta_files_rejects_unmigratable_settings`
+        std::fs::remove_file(dir.join("c.txt.meta")).unwrap();
+        let mut migrated =
+            bevy_tasks::block_on(asset_server.migrate_meta_files(AssetSourceId::Default)).unwrap();
+        migrated.sort_by_key(ToString::to_string);
+        assert_eq!(
+            migrated,
+            [AssetPath::from("a.txt"), AssetPath::from("b.txt")]
+        );
+        let a_meta = std::fs::read_to_string(dir.join("a.txt.meta")).unwrap();
+        assert!(a_meta.contains("settings_version: 2"));
+        assert!(a_meta.contains(r#"suffix: "!""#));
+        assert!(a_meta.contains("repeat: 1"));
+        assert!(
+            bevy_tasks::block_on(asset_server.migrate_meta_files(AssetSourceId::Default))
+                .unwrap()
+                .is_empty()
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn migrate_meta_files_rejects_unmigratable_settings() {
+        use crate::{
+            io::file::{FileAssetReader, FileAssetWriter},
+            meta::MetaMigrationError,
+            DeserializeMetaError, MigrateMetaFilesError,
+        };
+
+        let dir = std::env::temp_dir().join(format!(
+            "bevy_asset_meta_migration_error_test_{}",
+            std::process::id()
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("a.txt"), "a").unwrap();
+
+        let mut app = App::new();
+        let (reader_dir, writer_dir) = (dir.clone(), dir.clone());
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(FileAssetReader::new(&reader_dir)))
+                .with_writer(move |_| Some(Box::new(FileAssetWriter::new(&writer_dir, false)))),
+        )
+        .add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+        .init_asset::<SettingsText>()
+        .register_asset_loader(SettingsTextLoader)
+        // The migration from version 0 is missing
+        .register_asset_loader_migration::<SettingsTextLoader, _, _>(
+            1,
+            |old: SettingsTextSettingsV1| SettingsTextSettings {
+                suffix: old.suffix,
+                repeat: 1,
+            },
+        );
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let loader_name = core::any::type_name::<SettingsTextLoader>();
+
+        let migrate = |meta: &str| {
+            std::fs::write(dir.join("a.txt.meta"), meta).unwrap();
+            let error =
+                bevy_tasks::block_on(asset_server.migrate_meta_files(AssetSourceId::Default))
+                    .unwrap_err();
+            // The meta file is not rewritten
+            assert_eq!(
+                std::fs::read_to_string(dir.join("a.txt.meta")).unwrap(),
+                meta
+            );
+            match error {
+                MigrateMetaFilesError::DeserializeMeta {
+                    path,
+                    error: DeserializeMetaError::Migration(error),
+                } => {
+                    assert_eq!(path, AssetPath::from("a.txt"));
+                    error
+                }
+                error => panic!("unexpected error: {error}"),
+            }
+        };
+
+        let gap = format!(
+            r#"(meta_format_version: "1.0", asset: Load(loader: "{loader_name}", settings: (exclaim: true)))"#
+        );
+        assert_eq!(
+            migrate(&gap),
+            MetaMigrationError::MissingMigration {
+                name: loader_name.to_string(),
+                version: 0,
+            }
+        );
+
+        let newer = format!(
+            r#"(meta_format_version: "1.0", settings_version: 3, asset: Load(loader: "{loader_name}", settings: (suffix: "?", repeat: 1)))"#
+        );
+        assert_eq!(
+            migrate(&newer),
+            MetaMigrationError::UnsupportedVersion {
+                name: loader_name.to_string(),
+                version: 3,
+                current_version: 2,
+            }
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn dependency_graph_reports_leaked_assets() {
+        let dir = Dir::default();
+        dir.insert_asset_text(
+            Path::new("a.cool.ron"),
+            r#"(text: "a", dependencies: ["b.cool.ron"], embedded_dependencies: [], sub_texts: [])"#,
+        );
+        dir.insert_asset_text(
+            Path::new("b.cool.ron"),
+            r#"(text: "b", dependencies: [], embedded_dependencies: [], sub_texts: [])"#,
+        );
+        dir.insert_asset_text(
+            Path::new("c.cool.ron"),
+            r#"(text: "c", dependencies: [], embedded_dependencies: [], sub_texts: [])"#,
+        );
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .register_asset_loader(CoolTextLoader);
+        for path in ["a.cool.ron", "b.cool.ron", "c.cool.ron"] {
+            gate_opener.open(path);
+        }
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let a: Handle<CoolText> = asset_server.load("a.cool.ron");
+        let c: Handle<CoolText> = asset_server.load("c.cool.ron");
+        run_app_until(&mut app, |_| {
+            (asset_server.is_loaded_with_dependencies(&a)
+                && asset_server.is_loaded_with_dependencies(&c))
+            .then_some(())
+        });
+
+        let b_id = asset_server
+            .get_handle::<CoolText>("b.cool.ron")
+            .unwrap()
+            .id()
+            .untyped();
+        let graph = asset_server.dependency_graph();
+        let a_node = graph.get(&a).unwrap();
+        assert_eq!(a_node.asset.dependencies, [b_id]);
+        let b_node = graph.get(b_id).unwrap();
+        assert_eq!(b_node.dependents, [a.id().untyped()]);
+        assert_eq!(b_node.asset.strong_handle_count, 1);
+        assert_eq!(b_node.external_handles(), 0);
+        assert!(graph.leak_report([a.id(), c.id()]).is_empty());
+        assert!(graph.to_dot().contains("a0 -> a1;"));
+        let json: serde_json::Value = serde_json::from_str(&graph.to_json()).unwrap();
+        assert_eq!(
+            json["assets"][0],
+            serde_json::json!({
+                "index": 0,
+                "path": "a.cool.ron",
+                "strong_handles": 1,
+                "load_state": "Loaded",
+                "cached": false,
+                "dependencies": [1],
+            })
+        );
+
+        // A handle to `b` outlives `a`, which was the only reason to keep it.
+        let stale = asset_server.get_handle::<CoolText>("b.cool.ron").unwrap();
+        drop(a);
+        run_app_until(&mut app, |world| {
+            (!world
+                .resource::<Assets<CoolText>>()
+                .contains(a_node.asset.id.typed::<CoolText>()))
+            .then_some(())
+        });
+        let report = asset_server.dependency_graph().leak_report([c.id()]);
+        assert_eq!(report.leaked.len(), 1);
+        assert_eq!(report.leaked[0].asset.id, stale.id().untyped());
+        assert_eq!(report.leaked[0].external_handles(), 1);
+        assert!(report.to_string().contains("b.cool.ron"));
+    }
+
     #[test]
     fn ignore_system_ambiguities_on_assets() {
         let mut app = App::new();
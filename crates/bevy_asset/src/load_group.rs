@@ -0,0 +1,293 @@
+use crate as bevy_asset;
+use crate::{
+    io::Reader, Asset, AssetLoadError, AssetLoader, AssetPath, AssetServer, Handle, LoadContext,
+    LoadState, LoadedUntypedAsset, ParseAssetPathError, RecursiveDependencyLoadState,
+    UntypedAssetId, UntypedHandle,
+};
+use alloc::{string::String, sync::Arc, vec::Vec};
+use bevy_ecs::prelude::*;
+use bevy_platform_support::collections::HashSet;
+use bevy_reflect::TypePath;
+use core::any::TypeId;
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// A group of assets that are loaded together, such as everything a level needs before it can start.
+///
+/// Spawn a [`LoadGroup`] on an entity to track the aggregated [progress](LoadGroup::progress) of its assets, including
+/// their recursive dependencies. When every asset in the group has finished loading, [`LoadGroupLoaded`] is triggered
+/// on the entity, or [`LoadGroupFailed`] if any of them (or any of their dependencies) failed to load.
+///
+/// ```
+/// # use bevy_asset::{AssetServer, LoadGroup, LoadGroupLoaded};
+/// # use bevy_ecs::prelude::*;
+/// fn start_loading(mut commands: Commands, asset_server: Res<AssetServer>) {
+///     commands
+///         .spawn(asset_server.load_group(["models/level.gltf", "textures/sky.png"]))
+///         .observe(|trigger: Trigger<LoadGroupLoaded>| {
+///             // all assets of the level are ready
+///         });
+/// }
+///
+/// fn update_loading_screen(groups: Query<&LoadGroup>) {
+///     for group in &groups {
+///         let progress = group.progress();
+///         // display progress.fraction()
+///     }
+/// }
+/// ```
+#[derive(Component, Default)]
+pub struct LoadGroup {
+    handles: Vec<UntypedHandle>,
+    /// Every asset of the group, including the dependencies discovered while loading.
+    tracked: HashSet<UntypedAssetId>,
+    progress: LoadGroupProgress,
+    state: LoadGroupState,
+}
+
+impl LoadGroup {
+    /// Creates an empty [`LoadGroup`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds the asset of the given `handle` to the group.
+    pub fn with_handle(mut self, handle: impl Into<UntypedHandle>) -> Self {
+        self.add(handle);
+        self
+    }
+
+    /// Adds the asset of the given `handle` to the group. If the group has already finished loading,
+    /// it will be loading again until this asset has loaded.
+    pub fn add(&mut self, handle: impl Into<UntypedHandle>) {
+        self.handles.push(handle.into());
+        self.state = LoadGroupState::Loading;
+    }
+
+    /// Returns the handles of the assets in this group.
+    pub fn handles(&self) -> &[UntypedHandle] {
+        &self.handles
+    }
+
+    /// Returns the loading progress of this group, as of the last asset update.
+    pub fn progress(&self) -> LoadGroupProgress {
+        self.progress
+    }
+
+    /// Returns the [`LoadGroupState`] of this group.
+    pub fn state(&self) -> LoadGroupState {
+        self.state
+    }
+
+    /// Returns `true` if every asset in this group has loaded, along with its recursive dependencies.
+    pub fn is_loaded(&self) -> bool {
+        self.state == LoadGroupState::Loaded
+    }
+
+    /// Updates the progress of this group. Returns the errors of the failed assets if it just finished loading.
+    fn update(&mut self, asset_server: &AssetServer) -> Option<Vec<Arc<AssetLoadError>>> {
+        if self.state != LoadGroupState::Loading {
+            return None;
+        }
+        let infos = asset_server.data.infos.read();
+        let mut finished = true;
+        let mut errors: Vec<Arc<AssetLoadError>> = Vec::new();
+        for handle in &self.handles {
+            let id = handle.id();
+            self.tracked.insert(id);
+            match infos.get(id).map(|info| &info.rec_dep_load_state) {
+                Some(RecursiveDependencyLoadState::Loaded) => {}
+                Some(RecursiveDependencyLoadState::Failed(error)) => {
+                    if !errors.iter().any(|e| Arc::ptr_eq(e, error)) {
+                        errors.push(error.clone());
+                    }
+                }
+                _ => finished = false,
+            }
+        }
+
+        // Dependencies are only known once their dependent has loaded, so walk the dependency tree to discover them.
+        let mut pending: Vec<UntypedAssetId> = self.tracked.iter().copied().collect();
+        while let Some(id) = pending.pop() {
+            let Some(info) = infos.get(id) else {
+                continue;
+            };
+            for dependency in info.dependencies() {
+                if self.tracked.insert(*dependency) {
+                    pending.push(*dependency);
+                }
+            }
+        }
+
+        let mut progress = LoadGroupProgress::default();
+        for id in &self.tracked {
+            let load_state = infos.get(*id).map(|info| &info.load_state);
+            // A `LoadedUntypedAsset` wraps the asset of an untyped load. It stands in for that asset until it has
+            // loaded, after which the asset itself is counted as its dependency.
+            if id.type_id() == TypeId::of::<LoadedUntypedAsset>()
+                && matches!(load_state, Some(LoadState::Loaded))
+            {
+                continue;
+            }
+            progress.total += 1;
+            match load_state {
+                Some(LoadState::Loaded) => progress.loaded += 1,
+                Some(LoadState::Failed(_)) => progress.failed += 1,
+                _ => {}
+            }
+        }
+        self.progress = progress;
+
+        if !finished {
+            return None;
+        }
+        self.state = if errors.is_empty() {
+            LoadGroupState::Loaded
+        } else {
+            LoadGroupState::Failed
+        };
+        Some(errors)
+    }
+}
+
+/// The state of a [`LoadGroup`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum LoadGroupState {
+    /// Some assets of the group are still loading.
+    #[default]
+    Loading,
+    /// Every asset of the group has loaded, along with its recursive dependencies.
+    Loaded,
+    /// Every asset of the group has finished loading, but some of them or their dependencies failed to load.
+    Failed,
+}
+
+/// The aggregated loading progress of a [`LoadGroup`].
+///
+/// Dependencies are counted once they are discovered, so `total` can grow while the group is loading.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct LoadGroupProgress {
+    /// The number of assets that have loaded.
+    pub loaded: usize,
+    /// The number of assets that have failed to load.
+    pub failed: usize,
+    /// The number of assets in the group, including the dependencies discovered so far.
+    pub total: usize,
+}
+
+impl LoadGroupProgress {
+    /// Returns the fraction of assets that have finished loading (successfully or not), between `0.0` and `1.0`.
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            return 1.0;
+        }
+        (self.loaded + self.failed) as f32 / self.total as f32
+    }
+}
+
+/// Triggered on the entity of a [`LoadGroup`] when all of its assets have loaded, along with their recursive dependencies.
+#[derive(Event, Clone, Debug)]
+pub struct LoadGroupLoaded;
+
+/// Triggered on the entity of a [`LoadGroup`] when all of its assets have finished loading, but some of them or their
+/// dependencies failed to load.
+#[derive(Event, Clone, Debug)]
+pub struct LoadGroupFailed {
+    /// The errors of the assets that failed to load.
+    pub errors: Vec<Arc<AssetLoadError>>,
+}
+
+/// A system that updates the progress of every [`LoadGroup`] and triggers [`LoadGroupLoaded`] or [`LoadGroupFailed`]
+/// when a group finishes loading.
+pub fn track_load_groups(
+    mut commands: Commands,
+    asset_server: Res<AssetServer>,
+    mut groups: Query<(Entity, &mut LoadGroup)>,
+) {
+    for (entity, mut group) in &mut groups {
+        if group.state != LoadGroupState::Loading {
+            continue;
+        }
+        match group.update(&asset_server) {
+            Some(errors) if errors.is_empty() => commands.trigger_targets(LoadGroupLoaded, entity),
+            Some(errors) => commands.trigger_targets(LoadGroupFailed { errors }, entity),
+            None => {}
+        }
+    }
+}
+
+/// A manifest listing the assets of a [`LoadGroup`], loaded from a `.load_group.ron` file with
+/// [`AssetServer::load_group_manifest`].
+///
+/// The file contains the asset paths to load:
+///
+/// ```ron
+/// (
+///     paths: [
+///         "models/level.gltf",
+///         "textures/sky.png",
+///     ],
+/// )
+/// ```
+#[derive(Asset, TypePath)]
+pub struct LoadGroupManifest {
+    /// The paths listed in the manifest.
+    pub paths: Vec<AssetPath<'static>>,
+    /// The handles of the listed assets, in the same order as `paths`.
+    #[dependency]
+    pub handles: Vec<Handle<LoadedUntypedAsset>>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct LoadGroupManifestRon {
+    paths: Vec<String>,
+}
+
+/// Loads [`LoadGroupManifest`] assets from `.load_group.ron` files.
+#[derive(Default)]
+pub struct LoadGroupManifestLoader;
+
+/// An error that occurs when loading a [`LoadGroupManifest`].
+#[derive(Error, Debug)]
+pub enum LoadGroupManifestLoaderError {
+    /// The manifest could not be read.
+    #[error("An IO error occurred while reading the manifest: {0}")]
+    Io(#[from] std::io::Error),
+    /// The manifest is not valid RON, or does not match the expected format.
+    #[error("Could not parse the manifest: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+    /// One of the paths listed in the manifest is not a valid [`AssetPath`].
+    #[error("The manifest contains an invalid asset path: {0}")]
+    InvalidPath(#[from] ParseAssetPathError),
+}
+
+impl AssetLoader for LoadGroupManifestLoader {
+    type Asset = LoadGroupManifest;
+    type Settings = ();
+    type Error = LoadGroupManifestLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let manifest: LoadGroupManifestRon = ron::de::from_bytes(&bytes)?;
+        let paths = manifest
+            .paths
+            .iter()
+            .map(|path| AssetPath::try_parse(path).map(AssetPath::into_owned))
+            .collect::<Result<Vec<_>, _>>()?;
+        let handles = paths
+            .iter()
+            .map(|path| load_context.loader().with_unknown_type().load(path))
+            .collect();
+        Ok(LoadGroupManifest { paths, handles })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["load_group.ron"]
+    }
+}
@@ -1,10 +1,12 @@
 use crate::{
     io::{AssetReaderError, MissingAssetSourceError, MissingProcessedAssetReaderError, Reader},
     loader_builders::{Deferred, NestedLoader, StaticTyped},
-    meta::{AssetHash, AssetMeta, AssetMetaDyn, ProcessedInfoMinimal, Settings},
+    meta::{
+        AssetHash, AssetMeta, AssetMetaDyn, MetaMigrationError, ProcessedInfoMinimal, Settings,
+    },
     path::AssetPath,
-    Asset, AssetLoadError, AssetServer, AssetServerMode, Assets, Handle, UntypedAssetId,
-    UntypedHandle,
+    Asset, AssetLoadError, AssetServer, AssetServerMode, Assets, Handle, LoadPriority,
+    UntypedAssetId, UntypedHandle,
 };
 use alloc::{
     boxed::Box,
@@ -35,6 +37,9 @@ pub trait AssetLoader: Send + Sync + 'static {
     type Settings: Settings + Default + Serialize + for<'a> Deserialize<'a>;
     /// The type of [error](`std::error::Error`) which could be encountered by this loader.
     type Error: Into<Box<dyn core::error::Error + Send + Sync + 'static>>;
+    /// The version of [`AssetLoader::Settings`]. Bump it when a change to the settings breaks existing meta files,
+    /// and register a migration from the previous version in [`MetaMigrations`](crate::meta::MetaMigrations).
+    const SETTINGS_VERSION: u32 = 0;
     /// Asynchronously loads [`AssetLoader::Asset`] (and any other labeled assets) from the bytes provided by [`Reader`].
     fn load(
         &self,
@@ -69,6 +74,8 @@ pub trait ErasedAssetLoader: Send + Sync + 'static {
     fn deserialize_meta(&self, meta: &[u8]) -> Result<Box<dyn AssetMetaDyn>, DeserializeMetaError>;
     /// Returns the default meta value for the [`AssetLoader`] (erased as [`Box<dyn AssetMetaDyn>`]).
     fn default_meta(&self) -> Box<dyn AssetMetaDyn>;
+    /// Returns the [`AssetLoader::SETTINGS_VERSION`] of the [`AssetLoader`].
+    fn settings_version(&self) -> u32;
     /// Returns the type name of the [`AssetLoader`].
     fn type_name(&self) -> &'static str;
     /// Returns the [`TypeId`] of the [`AssetLoader`].
@@ -122,6 +129,10 @@ where
         }))
     }
 
+    fn settings_version(&self) -> u32 {
+        L::SETTINGS_VERSION
+    }
+
     fn type_name(&self) -> &'static str {
         core::any::type_name::<L>()
     }
@@ -309,6 +320,8 @@ pub enum DeserializeMetaError {
     DeserializeSettings(#[from] SpannedError),
     #[error("Failed to deserialize minimal asset meta: {0:?}")]
     DeserializeMinimal(SpannedError),
+    #[error("Failed to migrate asset meta: {0}")]
+    Migration(#[from] MetaMigrationError),
 }
 
 /// A context that provides access to assets in [`AssetLoader`]s, tracks dependencies, and collects asset load state.
@@ -318,6 +331,8 @@ pub struct LoadContext<'a> {
     pub(crate) asset_server: &'a AssetServer,
     pub(crate) should_load_dependencies: bool,
     populate_hashes: bool,
+    /// The priority of the load, inherited by the loads of its dependencies.
+    pub(crate) priority: LoadPriority,
     asset_path: AssetPath<'static>,
     pub(crate) dependencies: HashSet<UntypedAssetId>,
     /// Direct dependencies used by this loader.
@@ -332,12 +347,14 @@ impl<'a> LoadContext<'a> {
         asset_path: AssetPath<'static>,
         should_load_dependencies: bool,
         populate_hashes: bool,
+        priority: LoadPriority,
     ) -> Self {
         Self {
             asset_server,
             asset_path,
             populate_hashes,
             should_load_dependencies,
+            priority,
             dependencies: HashSet::default(),
             loader_dependencies: HashMap::default(),
             labeled_assets: HashMap::default(),
@@ -379,6 +396,7 @@ impl<'a> LoadContext<'a> {
             self.asset_path.clone(),
             self.should_load_dependencies,
             self.populate_hashes,
+            self.priority,
         )
     }
 
@@ -535,6 +553,7 @@ impl<'a> LoadContext<'a> {
                 reader,
                 false,
                 self.populate_hashes,
+                self.priority,
             )
             .await
             .map_err(|error| LoadDirectError {
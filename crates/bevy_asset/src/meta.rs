@@ -1,5 +1,7 @@
 use alloc::{
+    borrow::Cow,
     boxed::Box,
+    collections::BTreeMap,
     string::{String, ToString},
     vec::Vec,
 };
@@ -8,9 +10,11 @@ use crate::{
     self as bevy_asset, loader::AssetLoader, processor::Process, Asset, AssetPath,
     DeserializeMetaError, VisitAssetDependencies,
 };
+use bevy_platform_support::collections::HashMap;
 use downcast_rs::{impl_downcast, Downcast};
-use ron::ser::PrettyConfig;
+use ron::{error::SpannedError, ser::PrettyConfig};
 use serde::{Deserialize, Serialize};
+use thiserror::Error;
 use tracing::error;
 
 pub const META_FORMAT_VERSION: &str = "1.0";
@@ -25,6 +29,11 @@ pub struct AssetMeta<L: AssetLoader, P: Process> {
     /// The version of the meta format being used. This will change whenever a breaking change is made to
     /// the meta format.
     pub meta_format_version: String,
+    /// The version of the settings in `asset`. This is the [`AssetLoader::SETTINGS_VERSION`] or
+    /// [`Process::SETTINGS_VERSION`] the settings were written with, and is used to [migrate](MetaMigrations) them when
+    /// it is out of date.
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub settings_version: u32,
     /// Information produced by the [`AssetProcessor`] _after_ processing this asset.
     /// This will only exist alongside processed versions of assets. You should not manually set it in your asset source files.
     ///
@@ -37,8 +46,14 @@ pub struct AssetMeta<L: AssetLoader, P: Process> {
 
 impl<L: AssetLoader, P: Process> AssetMeta<L, P> {
     pub fn new(asset: AssetAction<L::Settings, P::Settings>) -> Self {
+        let settings_version = match &asset {
+            AssetAction::Load { .. } => L::SETTINGS_VERSION,
+            AssetAction::Process { .. } => P::SETTINGS_VERSION,
+            AssetAction::Ignore => 0,
+        };
         Self {
             meta_format_version: META_FORMAT_VERSION.to_string(),
+            settings_version,
             processed_info: None,
             asset,
         }
@@ -101,6 +116,8 @@ pub struct ProcessDependencyInfo {
 // using a type registry.
 #[derive(Serialize, Deserialize)]
 pub struct AssetMetaMinimal {
+    #[serde(default)]
+    pub settings_version: u32,
     pub asset: AssetActionMinimal,
 }
 
@@ -260,3 +277,233 @@ pub(crate) fn get_full_asset_hash(
     }
     *hasher.finalize().as_bytes()
 }
+
+fn is_zero(value: &u32) -> bool {
+    *value == 0
+}
+
+/// A function that migrates serialized asset meta from one settings version to the next.
+type MetaMigrationFn = Box<dyn Fn(&[u8]) -> Result<Vec<u8>, MetaMigrationError> + Send + Sync>;
+
+/// Migrations of the [`AssetLoader`] and [`Process`] settings stored in asset meta files.
+///
+/// When the settings type of a loader or processor changes in a way that breaks existing `.meta` files, bump its
+/// `SETTINGS_VERSION` and register a migration from the previous version. Meta files with older settings versions are
+/// migrated when they are read, and can be rewritten on disk with
+/// [`AssetServer::migrate_meta_files`](crate::AssetServer::migrate_meta_files).
+///
+/// ```
+/// # use bevy_asset::{meta::MetaMigrations, AssetLoader};
+/// # use serde::{Deserialize, Serialize};
+/// #[derive(Deserialize)]
+/// struct ImageSettingsV0 {
+///     srgb: bool,
+/// }
+///
+/// #[derive(Serialize, Deserialize, Default)]
+/// enum ColorSpace {
+///     #[default]
+///     Srgb,
+///     Linear,
+/// }
+///
+/// #[derive(Serialize, Deserialize, Default)]
+/// struct ImageSettings {
+///     color_space: ColorSpace,
+/// }
+///
+/// // `ImageLoader::SETTINGS_VERSION` is 1
+/// fn register<ImageLoader: AssetLoader<Settings = ImageSettings>>(migrations: &mut MetaMigrations) {
+///     migrations.add_loader_migration::<ImageLoader, _, _>(0, |old: ImageSettingsV0| ImageSettings {
+///         color_space: if old.srgb { ColorSpace::Srgb } else { ColorSpace::Linear },
+///     });
+/// }
+/// ```
+#[derive(Default)]
+pub struct MetaMigrations {
+    loaders: HashMap<&'static str, BTreeMap<u32, MetaMigrationFn>>,
+    processors: HashMap<&'static str, BTreeMap<u32, MetaMigrationFn>>,
+    /// The current settings version of each registered [`Process`]or. Processors are registered with the
+    /// [`AssetProcessor`](crate::processor::AssetProcessor), which the [`AssetServer`](crate::AssetServer) can't see.
+    processor_versions: HashMap<&'static str, u32>,
+}
+
+impl MetaMigrations {
+    /// Registers a migration of the settings of the `L` [`AssetLoader`] from `from_version` to `from_version + 1`.
+    ///
+    /// `From` is the settings type of `from_version`, and `To` the settings type of the next version.
+    pub fn add_loader_migration<L, From, To>(
+        &mut self,
+        from_version: u32,
+        migrate: impl Fn(From) -> To + Send + Sync + 'static,
+    ) where
+        L: AssetLoader,
+        From: for<'a> Deserialize<'a>,
+        To: Serialize,
+    {
+        self.loaders
+            .entry(core::any::type_name::<L>())
+            .or_default()
+            .insert(
+                from_version,
+                Box::new(move |bytes| {
+                    migrate_meta_bytes::<From, (), To, ()>(bytes, |asset| match asset {
+                        AssetAction::Load { loader, settings } => AssetAction::Load {
+                            loader,
+                            settings: migrate(settings),
+                        },
+                        AssetAction::Process { processor, .. } => AssetAction::Process {
+                            processor,
+                            settings: (),
+                        },
+                        AssetAction::Ignore => AssetAction::Ignore,
+                    })
+                }),
+            );
+    }
+
+    /// Registers a migration of the settings of the `P` [`Process`]or from `from_version` to `from_version + 1`.
+    ///
+    /// `From` is the settings type of `from_version`, and `To` the settings type of the next version.
+    pub fn add_processor_migration<P, From, To>(
+        &mut self,
+        from_version: u32,
+        migrate: impl Fn(From) -> To + Send + Sync + 'static,
+    ) where
+        P: Process,
+        From: for<'a> Deserialize<'a>,
+        To: Serialize,
+    {
+        self.processors
+            .entry(core::any::type_name::<P>())
+            .or_default()
+            .insert(
+                from_version,
+                Box::new(move |bytes| {
+                    migrate_meta_bytes::<(), From, (), To>(bytes, |asset| match asset {
+                        AssetAction::Load { loader, .. } => AssetAction::Load {
+                            loader,
+                            settings: (),
+                        },
+                        AssetAction::Process {
+                            processor,
+                            settings,
+                        } => AssetAction::Process {
+                            processor,
+                            settings: migrate(settings),
+                        },
+                        AssetAction::Ignore => AssetAction::Ignore,
+                    })
+                }),
+            );
+    }
+
+    /// Records the current settings version of the `P` [`Process`]or.
+    pub(crate) fn register_processor_version<P: Process>(&mut self) {
+        self.processor_versions
+            .insert(core::any::type_name::<P>(), P::SETTINGS_VERSION);
+    }
+
+    /// Returns the current settings version of the [`Process`]or with the given type name, if it is registered.
+    pub(crate) fn processor_version(&self, processor_type_name: &str) -> Option<u32> {
+        self.processor_versions.get(processor_type_name).copied()
+    }
+
+    /// Migrates the serialized meta of an asset with the given `action`, from the given settings version to
+    /// `to_version`.
+    ///
+    /// Returns the meta unchanged if it is already at `to_version`.
+    pub(crate) fn migrate<'a>(
+        &self,
+        action: &AssetActionMinimal,
+        from_version: u32,
+        to_version: u32,
+        meta: &'a [u8],
+    ) -> Result<Cow<'a, [u8]>, MetaMigrationError> {
+        let (name, migrations) = match action {
+            AssetActionMinimal::Load { loader } => (loader, self.loaders.get(loader.as_str())),
+            AssetActionMinimal::Process { processor } => {
+                (processor, self.processors.get(processor.as_str()))
+            }
+            AssetActionMinimal::Ignore => return Ok(Cow::Borrowed(meta)),
+        };
+        if from_version > to_version {
+            return Err(MetaMigrationError::UnsupportedVersion {
+                name: name.clone(),
+                version: from_version,
+                current_version: to_version,
+            });
+        }
+        let mut meta = Cow::Borrowed(meta);
+        for version in from_version..to_version {
+            let migration = migrations
+                .and_then(|migrations| migrations.get(&version))
+                .ok_or_else(|| MetaMigrationError::MissingMigration {
+                    name: name.clone(),
+                    version,
+                })?;
+            meta = Cow::Owned(migration(&meta)?);
+        }
+        Ok(meta)
+    }
+}
+
+/// The fields of [`AssetMeta`], with arbitrary settings types.
+#[derive(Serialize, Deserialize)]
+struct MigratingAssetMeta<LoaderSettings, ProcessSettings> {
+    meta_format_version: String,
+    #[serde(default, skip_serializing_if = "is_zero")]
+    settings_version: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    processed_info: Option<ProcessedInfo>,
+    asset: AssetAction<LoaderSettings, ProcessSettings>,
+}
+
+fn migrate_meta_bytes<FromLoader, FromProcess, ToLoader, ToProcess>(
+    bytes: &[u8],
+    migrate: impl FnOnce(AssetAction<FromLoader, FromProcess>) -> AssetAction<ToLoader, ToProcess>,
+) -> Result<Vec<u8>, MetaMigrationError>
+where
+    FromLoader: for<'a> Deserialize<'a>,
+    FromProcess: for<'a> Deserialize<'a>,
+    ToLoader: Serialize,
+    ToProcess: Serialize,
+{
+    let meta: MigratingAssetMeta<FromLoader, FromProcess> = ron::de::from_bytes(bytes)?;
+    let meta = MigratingAssetMeta {
+        meta_format_version: meta.meta_format_version,
+        settings_version: meta.settings_version + 1,
+        processed_info: meta.processed_info,
+        asset: migrate(meta.asset),
+    };
+    Ok(ron::ser::to_string_pretty(&meta, PrettyConfig::default())?.into_bytes())
+}
+
+/// An error that occurs while migrating asset meta with [`MetaMigrations`].
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum MetaMigrationError {
+    /// The meta, or its settings, could not be deserialized in the format of the version being migrated from.
+    #[error("Failed to deserialize asset meta for migration: {0}")]
+    Deserialize(#[from] SpannedError),
+    /// The migrated meta could not be serialized.
+    #[error("Failed to serialize migrated asset meta: {0}")]
+    Serialize(#[from] ron::Error),
+    /// The settings are out of date, but no migration is registered from their version.
+    #[error("No migration is registered for the settings of {name} from version {version}")]
+    MissingMigration {
+        /// The type name of the loader or processor the settings belong to.
+        name: String,
+        /// The version of the settings that has no migration.
+        version: u32,
+    },
+    /// The settings were written by a newer version of the loader or processor than the registered one.
+    #[error("The settings of {name} have version {version}, which is newer than the current version {current_version}")]
+    UnsupportedVersion {
+        /// The type name of the loader or processor the settings belong to.
+        name: String,
+        /// The version of the settings in the meta.
+        version: u32,
+        /// The settings version of the registered loader or processor.
+        current_version: u32,
+    },
+}
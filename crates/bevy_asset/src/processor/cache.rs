@@ -0,0 +1,345 @@
+use crate::{
+    io::Writer,
+    meta::{AssetHash, ProcessDependencyInfo, META_FORMAT_VERSION},
+};
+use alloc::{borrow::ToOwned, boxed::Box, format, string::String, vec::Vec};
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_io::AsyncWrite;
+use serde::{Deserialize, Serialize};
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+use tracing::warn;
+
+/// A content-addressed cache of processed assets, stored in a directory.
+///
+/// Processed assets are keyed by the hash of their source asset and meta, and the [version](super::Process::version)
+/// of their processor. Before processing an asset, the [`AssetProcessor`](super::AssetProcessor) looks it up in the cache,
+/// and reuses the cached output if the process dependencies it was produced with haven't changed.
+///
+/// The cache doesn't depend on the asset paths or the processor transaction log, so it can be shared across branches
+/// and checkouts, or across machines by pointing it at a shared directory. It is only ever added to, so it can be cleared
+/// by deleting the directory.
+#[derive(Clone, Debug)]
+pub struct ProcessorCache {
+    root: PathBuf,
+}
+
+/// A processed asset read from a [`ProcessorCache`].
+pub(crate) struct CachedAsset {
+    pub(crate) asset: Vec<u8>,
+    pub(crate) meta: Vec<u8>,
+    pub(crate) process_dependencies: Vec<ProcessDependencyInfo>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct CacheEntry {
+    /// The hash of the processed asset bytes, used to detect corrupted entries.
+    asset_hash: AssetHash,
+    meta: String,
+    process_dependencies: Vec<ProcessDependencyInfo>,
+}
+
+impl ProcessorCache {
+    /// Creates a new [`ProcessorCache`] that stores processed assets in the directory at `root`.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// The directory processed assets are stored in.
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Returns the key of the asset with the given source `hash`, processed by a processor with the given `version`.
+    pub(crate) fn key(hash: AssetHash, version: u32) -> AssetHash {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(META_FORMAT_VERSION.as_bytes());
+        hasher.update(&hash);
+        hasher.update(&version.to_le_bytes());
+        *hasher.finalize().as_bytes()
+    }
+
+    fn paths(&self, key: &AssetHash) -> (PathBuf, PathBuf) {
+        let hex = blake3::Hash::from_bytes(*key).to_hex();
+        let directory = self.root.join(&hex[..2]);
+        (
+            directory.join(hex.as_str()),
+            directory.join(format!("{hex}.ron")),
+        )
+    }
+
+    /// Returns the processed asset with the given `key`, if it is in the cache and its bytes match the hash they were
+    /// stored with.
+    pub(crate) fn get(&self, key: &AssetHash) -> Option<CachedAsset> {
+        let (asset_path, entry_path) = self.paths(key);
+        // the entry is written last, so the asset is complete if it exists
+        let entry = std::fs::read(entry_path).ok()?;
+        let entry: CacheEntry = ron::de::from_bytes(&entry).ok()?;
+        let asset = std::fs::read(&asset_path).ok()?;
+        if *blake3::hash(&asset).as_bytes() != entry.asset_hash {
+            warn!(
+                "Ignoring corrupted processed asset {} in the processor cache",
+                asset_path.display()
+            );
+            return None;
+        }
+        Some(CachedAsset {
+            asset,
+            meta: entry.meta.into_bytes(),
+            process_dependencies: entry.process_dependencies,
+        })
+    }
+
+    /// Stores a processed asset with the given `key`.
+    pub(crate) fn insert(
+        &self,
+        key: &AssetHash,
+        asset: &[u8],
+        meta: &[u8],
+        process_dependencies: &[ProcessDependencyInfo],
+    ) -> io::Result<()> {
+        let meta = String::from_utf8(meta.to_vec())
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        let entry = ron::ser::to_string(&CacheEntry {
+            asset_hash: *blake3::hash(asset).as_bytes(),
+            meta,
+            process_dependencies: process_dependencies.to_vec(),
+        })
+        .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?;
+        let (asset_path, entry_path) = self.paths(key);
+        std::fs::create_dir_all(asset_path.parent().unwrap())?;
+        write_atomic(&asset_path, asset)?;
+        write_atomic(&entry_path, entry.as_bytes())
+    }
+}
+
+/// Writes `bytes` to a temporary file next to `path` and renames it, so that readers never see a partial file.
+fn write_atomic(path: &Path, bytes: &[u8]) -> io::Result<()> {
+    let mut temp_path = path.as_os_str().to_owned();
+    temp_path.push(format!(".{}.tmp", std::process::id()));
+    std::fs::write(&temp_path, bytes)?;
+    std::fs::rename(&temp_path, path)
+}
+
+/// A [`Writer`] that records the bytes written to another writer, so they can be stored in a [`ProcessorCache`].
+pub(crate) struct RecordingWriter {
+    writer: Box<Writer>,
+    /// The recorded bytes, or [`None`] if recording is disabled.
+    bytes: Option<Vec<u8>>,
+}
+
+impl RecordingWriter {
+    pub(crate) fn new(writer: Box<Writer>, record: bool) -> Self {
+        Self {
+            writer,
+            bytes: record.then(Vec::new),
+        }
+    }
+
+    pub(crate) fn into_bytes(self) -> Option<Vec<u8>> {
+        self.bytes
+    }
+}
+
+impl AsyncWrite for RecordingWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        let this = self.get_mut();
+        let result = Pin::new(&mut this.writer).poll_write(cx, buf);
+        if let (Poll::Ready(Ok(len)), Some(bytes)) = (&result, &mut this.bytes) {
+            bytes.extend_from_slice(&buf[..*len]);
+        }
+        result
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().writer).poll_flush(cx)
+    }
+
+    fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.get_mut().writer).poll_close(cx)
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod tests {
+    use super::ProcessorCache;
+    use crate::meta::ProcessDependencyInfo;
+    use alloc::{format, vec};
+
+    #[test]
+    fn cached_assets_are_keyed_by_hash_and_version() {
+        let root = std::env::temp_dir().join(format!(
+            "bevy_asset_processor_cache_test_{}",
+            std::process::id()
+        ));
+        let cache = ProcessorCache::new(&root);
+        let key = ProcessorCache::key([1; 32], 0);
+        assert_ne!(key, ProcessorCache::key([1; 32], 1));
+        assert_ne!(key, ProcessorCache::key([2; 32], 0));
+        assert!(cache.get(&key).is_none());
+
+        let dependencies = vec![ProcessDependencyInfo {
+            full_hash: [3; 32],
+            path: "a.txt".into(),
+        }];
+        cache
+            .insert(&key, b"processed", b"(meta)", &dependencies)
+            .unwrap();
+        let cached = cache.get(&key).unwrap();
+        assert_eq!(cached.asset, b"processed");
+        assert_eq!(cached.meta, b"(meta)");
+        assert_eq!(cached.process_dependencies.len(), 1);
+        assert_eq!(cached.process_dependencies[0].full_hash, [3; 32]);
+        assert!(cache.get(&ProcessorCache::key([1; 32], 1)).is_none());
+
+        // corrupted bytes are not served
+        let (asset_path, _) = cache.paths(&key);
+        std::fs::write(asset_path, b"processes").unwrap();
+        assert!(cache.get(&key).is_none());
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[cfg(feature = "multi_threaded")]
+    mod processor {
+        use crate::{
+            self as bevy_asset,
+            io::{
+                file::{FileAssetReader, FileAssetWriter},
+                AssetSourceBuilder, AssetSourceBuilders, AssetSourceId, Reader, Writer,
+            },
+            meta::AssetMeta,
+            processor::{AssetProcessor, Process, ProcessContext, ProcessError, ProcessorCache},
+            Asset, AssetLoader, LoadContext,
+        };
+        use alloc::{boxed::Box, format, sync::Arc};
+        use bevy_reflect::TypePath;
+        use bevy_tasks::IoTaskPool;
+        use core::sync::atomic::{AtomicUsize, Ordering};
+        use futures_lite::AsyncWriteExt;
+        use std::path::Path;
+
+        #[derive(Asset, TypePath)]
+        struct Text;
+
+        struct TextLoader;
+
+        impl AssetLoader for TextLoader {
+            type Asset = Text;
+            type Settings = ();
+            type Error = std::io::Error;
+
+            async fn load(
+                &self,
+                _reader: &mut dyn Reader,
+                _settings: &(),
+                _load_context: &mut LoadContext<'_>,
+            ) -> Result<Text, Self::Error> {
+                Ok(Text)
+            }
+
+            fn extensions(&self) -> &[&str] {
+                &["txt"]
+            }
+        }
+
+        /// Uppercases text assets, counting how many assets it processed.
+        struct UppercaseProcess(Arc<AtomicUsize>);
+
+        impl Process for UppercaseProcess {
+            type Settings = ();
+            type OutputLoader = TextLoader;
+
+            async fn process(
+                &self,
+                context: &mut ProcessContext<'_>,
+                _meta: AssetMeta<(), Self>,
+                writer: &mut Writer,
+            ) -> Result<(), ProcessError> {
+                self.0.fetch_add(1, Ordering::SeqCst);
+                writer
+                    .write_all(&context.asset_bytes().to_ascii_uppercase())
+                    .await
+                    .map_err(|err| ProcessError::AssetWriterError {
+                        path: context.path().clone(),
+                        err: err.into(),
+                    })
+            }
+        }
+
+        /// Processes the assets in `root/source` into `root/{processed}`, logging to `root/{processed}.log`.
+        fn run_processor(
+            root: &Path,
+            processed: &str,
+            cache: &ProcessorCache,
+            processed_count: &Arc<AtomicUsize>,
+        ) {
+            let log = root.join(format!("{processed}.log"));
+            let (source, processed) = (root.join("source"), root.join(processed));
+            let mut builders = AssetSourceBuilders::default();
+            builders.insert(
+                AssetSourceId::Default,
+                AssetSourceBuilder::default()
+                    .with_reader({
+                        let source = source.clone();
+                        move || Box::new(FileAssetReader::new(&source))
+                    })
+                    .with_writer(move |create_root| {
+                        Some(Box::new(FileAssetWriter::new(&source, create_root)))
+                    })
+                    .with_processed_reader({
+                        let processed = processed.clone();
+                        move || Box::new(FileAssetReader::new(&processed))
+                    })
+                    .with_processed_writer(move |create_root| {
+                        Some(Box::new(FileAssetWriter::new(&processed, create_root)))
+                    }),
+            );
+            let processor = AssetProcessor::new(&mut builders);
+            processor.server().register_loader(TextLoader);
+            processor.register_processor(UppercaseProcess(processed_count.clone()));
+            processor.set_default_processor::<UppercaseProcess>("txt");
+            processor.set_cache(Some(cache.clone()));
+            processor.set_log_path(log);
+            processor.process_assets();
+        }
+
+        #[test]
+        fn processor_restores_assets_from_the_cache() {
+            IoTaskPool::get_or_init(Default::default);
+            let root = std::env::temp_dir().join(format!(
+                "bevy_asset_processor_cache_restore_test_{}",
+                std::process::id()
+            ));
+            let source = root.join("source");
+            std::fs::create_dir_all(&source).unwrap();
+            std::fs::write(source.join("a.txt"), "hello").unwrap();
+            let cache = ProcessorCache::new(root.join("cache"));
+            let processed_count = Arc::new(AtomicUsize::new(0));
+
+            // Each run writes to a fresh processed folder, like a new checkout sharing the cache.
+            run_processor(&root, "processed_1", &cache, &processed_count);
+            assert_eq!(processed_count.load(Ordering::SeqCst), 1);
+            run_processor(&root, "processed_2", &cache, &processed_count);
+            assert_eq!(processed_count.load(Ordering::SeqCst), 1);
+
+            for processed in ["processed_1", "processed_2"] {
+                let processed = root.join(processed);
+                assert_eq!(std::fs::read(processed.join("a.txt")).unwrap(), b"HELLO");
+                let meta = std::fs::read_to_string(processed.join("a.txt.meta")).unwrap();
+                assert!(meta.contains("TextLoader"));
+            }
+
+            std::fs::remove_dir_all(&root).unwrap();
+        }
+    }
+}
@@ -7,7 +7,7 @@ use alloc::{
 use async_fs::File;
 use bevy_platform_support::collections::HashSet;
 use futures_lite::{AsyncReadExt, AsyncWriteExt};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use thiserror::Error;
 use tracing::error;
 
@@ -76,7 +76,8 @@ const ENTRY_END: &str = "End ";
 const UNRECOVERABLE_ERROR: &str = "UnrecoverableError";
 
 impl ProcessorTransactionLog {
-    fn full_log_path() -> PathBuf {
+    /// The default path of the log file, `imported_assets/log`.
+    pub(crate) fn default_path() -> PathBuf {
         #[cfg(not(target_arch = "wasm32"))]
         let base_path = crate::io::file::get_base_path();
         #[cfg(target_arch = "wasm32")]
@@ -84,9 +85,8 @@ impl ProcessorTransactionLog {
         base_path.join(LOG_PATH)
     }
     /// Create a new, fresh log file. This will delete the previous log file if it exists.
-    pub(crate) async fn new() -> Result<Self, futures_io::Error> {
-        let path = Self::full_log_path();
-        match async_fs::remove_file(&path).await {
+    pub(crate) async fn new(path: &Path) -> Result<Self, futures_io::Error> {
+        match async_fs::remove_file(path).await {
             Ok(_) => { /* successfully removed file */ }
             Err(err) => {
                 // if the log file is not found, we assume we are starting in a fresh (or good) state
@@ -105,9 +105,9 @@ impl ProcessorTransactionLog {
         })
     }
 
-    pub(crate) async fn read() -> Result<Vec<LogEntry>, ReadLogError> {
+    pub(crate) async fn read(path: &Path) -> Result<Vec<LogEntry>, ReadLogError> {
         let mut log_lines = Vec::new();
-        let mut file = match File::open(Self::full_log_path()).await {
+        let mut file = match File::open(path).await {
             Ok(file) => file,
             Err(err) => {
                 if err.kind() == futures_io::ErrorKind::NotFound {
@@ -137,10 +137,10 @@ impl ProcessorTransactionLog {
         Ok(log_lines)
     }
 
-    pub(crate) async fn validate() -> Result<(), ValidateLogError> {
+    pub(crate) async fn validate(path: &Path) -> Result<(), ValidateLogError> {
         let mut transactions: HashSet<AssetPath<'static>> = Default::default();
         let mut errors: Vec<LogEntryError> = Vec::new();
-        let entries = Self::read().await?;
+        let entries = Self::read(path).await?;
         for entry in entries {
             match entry {
                 LogEntry::BeginProcessing(path) => {
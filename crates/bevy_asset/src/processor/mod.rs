@@ -37,12 +37,16 @@
 //!
 //! In most cases, [`LoadTransformAndSave`] should be sufficient.
 
+mod cache;
 mod log;
 mod process;
 
+pub use cache::ProcessorCache;
 pub use log::*;
 pub use process::*;
 
+use cache::RecordingWriter;
+
 use crate::{
     io::{
         AssetReaderError, AssetSource, AssetSourceBuilders, AssetSourceEvent, AssetSourceId,
@@ -99,9 +103,11 @@ pub struct AssetProcessor {
 pub struct AssetProcessorData {
     pub(crate) asset_infos: async_lock::RwLock<ProcessorAssetInfos>,
     log: async_lock::RwLock<Option<ProcessorTransactionLog>>,
+    log_path: RwLock<PathBuf>,
     processors: RwLock<HashMap<&'static str, Arc<dyn ErasedProcessor>>>,
     /// Default processors for file extensions
     default_processors: RwLock<HashMap<Box<str>, &'static str>>,
+    cache: RwLock<Option<ProcessorCache>>,
     state: async_lock::RwLock<ProcessorState>,
     sources: AssetSources,
     initialized_sender: async_broadcast::Sender<()>,
@@ -167,6 +173,24 @@ impl AssetProcessor {
         &self.data.sources
     }
 
+    /// Sets the [`ProcessorCache`] that processed assets are stored in and reused from.
+    /// If [`None`] (the default), processed assets are not cached.
+    pub fn set_cache(&self, cache: Option<ProcessorCache>) {
+        *self.data.cache.write() = cache;
+    }
+
+    /// Returns the [`ProcessorCache`] set with [`AssetProcessor::set_cache`].
+    pub fn cache(&self) -> Option<ProcessorCache> {
+        self.data.cache.read().clone()
+    }
+
+    /// Sets the path of the [`ProcessorTransactionLog`] file. Defaults to `imported_assets/log`.
+    ///
+    /// This must be set before the processor starts.
+    pub fn set_log_path(&self, path: impl Into<PathBuf>) {
+        *self.data.log_path.write() = path.into();
+    }
+
     /// Logs an unrecoverable error. On the next run of the processor, all assets will be regenerated. This should only be used as a last resort.
     /// Every call to this should be considered with scrutiny and ideally replaced with something more granular.
     async fn log_unrecoverable(&self) {
@@ -525,6 +549,11 @@ impl AssetProcessor {
 
     /// Register a new asset processor.
     pub fn register_processor<P: Process>(&self, processor: P) {
+        self.server
+            .data
+            .meta_migrations
+            .write()
+            .register_processor_version::<P>();
         let mut process_plans = self.data.processors.write();
         #[cfg(feature = "trace")]
         let processor = InstrumentedAssetProcessor(processor);
@@ -763,17 +792,31 @@ impl AssetProcessor {
                 let minimal: AssetMetaMinimal = ron::de::from_bytes(&meta_bytes).map_err(|e| {
                     ProcessError::DeserializeMetaError(DeserializeMetaError::DeserializeMinimal(e))
                 })?;
-                let (meta, processor) = match minimal.asset {
+                let migrate = |settings_version| {
+                    server
+                        .data
+                        .meta_migrations
+                        .read()
+                        .migrate(
+                            &minimal.asset,
+                            minimal.settings_version,
+                            settings_version,
+                            &meta_bytes,
+                        )
+                        .map_err(DeserializeMetaError::from)
+                };
+                let (meta, processor) = match &minimal.asset {
                     AssetActionMinimal::Load { loader } => {
-                        let loader = server.get_asset_loader_with_type_name(&loader).await?;
-                        let meta = loader.deserialize_meta(&meta_bytes)?;
+                        let loader = server.get_asset_loader_with_type_name(loader).await?;
+                        let meta = loader.deserialize_meta(&migrate(loader.settings_version())?)?;
                         (meta, None)
                     }
                     AssetActionMinimal::Process { processor } => {
                         let processor = self
-                            .get_processor(&processor)
-                            .ok_or_else(|| ProcessError::MissingProcessor(processor))?;
-                        let meta = processor.deserialize_meta(&meta_bytes)?;
+                            .get_processor(processor)
+                            .ok_or_else(|| ProcessError::MissingProcessor(processor.clone()))?;
+                        let meta =
+                            processor.deserialize_meta(&migrate(processor.settings_version())?)?;
                         (meta, Some(processor))
                     }
                     AssetActionMinimal::Ignore => {
@@ -874,12 +917,31 @@ impl AssetProcessor {
         // TODO: this class of failure can be recovered via re-processing + smarter log validation that allows for duplicate transactions in the event of failures
         self.log_begin_processing(asset_path).await;
         if let Some(processor) = processor {
-            let mut writer = processed_writer.write(path).await.map_err(writer_err)?;
+            let cache = self.cache();
+            let cache_key = ProcessorCache::key(new_hash, processor.version());
+            if let Some(cache) = &cache {
+                if self
+                    .restore_from_cache(
+                        cache,
+                        &cache_key,
+                        asset_path,
+                        &mut new_processed_info,
+                        processed_writer,
+                    )
+                    .await?
+                {
+                    self.log_end_processing(asset_path).await;
+                    return Ok(ProcessResult::Processed(new_processed_info));
+                }
+            }
+
+            let writer = processed_writer.write(path).await.map_err(writer_err)?;
+            let mut writer = RecordingWriter::new(writer, cache.is_some());
             let mut processed_meta = {
                 let mut context =
                     ProcessContext::new(self, asset_path, &asset_bytes, &mut new_processed_info);
                 processor
-                    .process(&mut context, source_meta, &mut *writer)
+                    .process(&mut context, source_meta, &mut writer)
                     .await?
             };
 
@@ -890,6 +952,9 @@ impl AssetProcessor {
                     path: asset_path.clone(),
                     err: AssetWriterError::Io(e),
                 })?;
+            let processed_bytes = writer.into_bytes();
+            // the cached meta is stored without its processed info, which depends on the process dependencies
+            let cached_meta_bytes = cache.is_some().then(|| processed_meta.serialize());
 
             let full_hash = get_full_asset_hash(
                 new_hash,
@@ -905,6 +970,19 @@ impl AssetProcessor {
                 .write_meta_bytes(path, &meta_bytes)
                 .await
                 .map_err(writer_err)?;
+
+            if let (Some(cache), Some(processed_bytes), Some(cached_meta_bytes)) =
+                (cache, processed_bytes, cached_meta_bytes)
+            {
+                if let Err(err) = cache.insert(
+                    &cache_key,
+                    &processed_bytes,
+                    &cached_meta_bytes,
+                    &new_processed_info.process_dependencies,
+                ) {
+                    warn!("Failed to store processed asset {asset_path} in the processor cache: {err}");
+                }
+            }
         } else {
             processed_writer
                 .write_bytes(path, &asset_bytes)
@@ -922,8 +1000,68 @@ impl AssetProcessor {
         Ok(ProcessResult::Processed(new_processed_info))
     }
 
+    /// Writes the processed asset stored in the `cache` with the given `key`, if it exists and its process dependencies
+    /// haven't changed. Returns `true` if the asset was restored from the cache.
+    async fn restore_from_cache(
+        &self,
+        cache: &ProcessorCache,
+        key: &AssetHash,
+        asset_path: &AssetPath<'static>,
+        processed_info: &mut ProcessedInfo,
+        processed_writer: &dyn ErasedAssetWriter,
+    ) -> Result<bool, ProcessError> {
+        let Some(cached) = cache.get(key) else {
+            return Ok(false);
+        };
+        for dependency in &cached.process_dependencies {
+            self.data
+                .wait_until_processed(dependency.path.clone())
+                .await;
+            let infos = self.data.asset_infos.read().await;
+            let live_hash = infos
+                .get(&dependency.path)
+                .and_then(|i| i.processed_info.as_ref())
+                .map(|i| i.full_hash);
+            if live_hash != Some(dependency.full_hash) {
+                return Ok(false);
+            }
+        }
+        let Ok(AssetMetaMinimal {
+            asset: AssetActionMinimal::Load { loader },
+            ..
+        }) = ron::de::from_bytes(&cached.meta)
+        else {
+            return Ok(false);
+        };
+        let loader = self.server.get_asset_loader_with_type_name(&loader).await?;
+        let mut meta = loader.deserialize_meta(&cached.meta)?;
+
+        processed_info.full_hash = get_full_asset_hash(
+            processed_info.hash,
+            cached.process_dependencies.iter().map(|i| i.full_hash),
+        );
+        processed_info.process_dependencies = cached.process_dependencies;
+        *meta.processed_info_mut() = Some(processed_info.clone());
+
+        let writer_err = |err| ProcessError::AssetWriterError {
+            path: asset_path.clone(),
+            err,
+        };
+        processed_writer
+            .write_bytes(asset_path.path(), &cached.asset)
+            .await
+            .map_err(writer_err)?;
+        processed_writer
+            .write_meta_bytes(asset_path.path(), &meta.serialize())
+            .await
+            .map_err(writer_err)?;
+        debug!("Restored {} from the processor cache", asset_path);
+        Ok(true)
+    }
+
     async fn validate_transaction_log_and_recover(&self) {
-        if let Err(err) = ProcessorTransactionLog::validate().await {
+        let log_path = self.data.log_path.read().clone();
+        if let Err(err) = ProcessorTransactionLog::validate(&log_path).await {
             let state_is_valid = match err {
                 ValidateLogError::ReadLogError(err) => {
                     error!("Failed to read processor log file. Processed assets cannot be validated so they must be re-generated {err}");
@@ -1001,7 +1139,7 @@ impl AssetProcessor {
             }
         }
         let mut log = self.data.log.write().await;
-        *log = match ProcessorTransactionLog::new().await {
+        *log = match ProcessorTransactionLog::new(&log_path).await {
             Ok(log) => Some(log),
             Err(err) => panic!("Failed to initialize asset processor log. This cannot be recovered. Try restarting. If that doesn't work, try deleting processed asset folder. {}", err),
         };
@@ -1026,9 +1164,11 @@ impl AssetProcessorData {
             initialized_receiver,
             state: async_lock::RwLock::new(ProcessorState::Initializing),
             log: Default::default(),
+            log_path: RwLock::new(ProcessorTransactionLog::default_path()),
             processors: Default::default(),
             asset_infos: Default::default(),
             default_processors: Default::default(),
+            cache: Default::default(),
         }
     }
 
@@ -1094,6 +1234,7 @@ struct InstrumentedAssetProcessor<T>(T);
 impl<T: Process> Process for InstrumentedAssetProcessor<T> {
     type Settings = T::Settings;
     type OutputLoader = T::OutputLoader;
+    const SETTINGS_VERSION: u32 = T::SETTINGS_VERSION;
 
     fn process(
         &self,
@@ -1106,6 +1247,7 @@ impl<T: Process> Process for InstrumentedAssetProcessor<T> {
         // Change the processor type for the `AssetMeta`, which works because we share the `Settings` type.
         let meta = AssetMeta {
             meta_format_version: meta.meta_format_version,
+            settings_version: meta.settings_version,
             processed_info: meta.processed_info,
             asset: meta.asset,
         };
@@ -1116,6 +1258,10 @@ impl<T: Process> Process for InstrumentedAssetProcessor<T> {
         );
         self.0.process(context, meta, writer).instrument(span)
     }
+
+    fn version(&self) -> u32 {
+        self.0.version()
+    }
 }
 
 /// The (successful) result of processing an asset
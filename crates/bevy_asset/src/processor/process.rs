@@ -7,7 +7,7 @@ use crate::{
     processor::AssetProcessor,
     saver::{AssetSaver, SavedAsset},
     transformer::{AssetTransformer, IdentityAssetTransformer, TransformedAsset},
-    AssetLoadError, AssetLoader, AssetPath, DeserializeMetaError, ErasedLoadedAsset,
+    AssetLoadError, AssetLoader, AssetPath, DeserializeMetaError, ErasedLoadedAsset, LoadPriority,
     MissingAssetLoaderForExtensionError, MissingAssetLoaderForTypeNameError,
 };
 use alloc::{
@@ -30,6 +30,9 @@ pub trait Process: Send + Sync + Sized + 'static {
     type Settings: Settings + Default + Serialize + for<'a> Deserialize<'a>;
     /// The [`AssetLoader`] that will be used to load the final processed asset.
     type OutputLoader: AssetLoader;
+    /// The version of [`Process::Settings`]. Bump it when a change to the settings breaks existing meta files,
+    /// and register a migration from the previous version in [`MetaMigrations`](crate::meta::MetaMigrations).
+    const SETTINGS_VERSION: u32 = 0;
     /// Processes the asset stored on `context` in some way using the settings stored on `meta`. The results are written to `writer`. The
     /// final written processed asset is loadable using [`Process::OutputLoader`]. This load will use the returned [`AssetLoader::Settings`].
     fn process(
@@ -40,6 +43,12 @@ pub trait Process: Send + Sync + Sized + 'static {
     ) -> impl ConditionalSendFuture<
         Output = Result<<Self::OutputLoader as AssetLoader>::Settings, ProcessError>,
     >;
+
+    /// The version of this processor. Bump it when a change to the processor changes its output for the same input,
+    /// so that outputs stored in a [`ProcessorCache`](super::ProcessorCache) by previous versions aren't reused.
+    fn version(&self) -> u32 {
+        0
+    }
 }
 
 /// A flexible [`Process`] implementation that loads the source [`Asset`] using the `L` [`AssetLoader`], then transforms
@@ -221,6 +230,10 @@ pub trait ErasedProcessor: Send + Sync {
     fn deserialize_meta(&self, meta: &[u8]) -> Result<Box<dyn AssetMetaDyn>, DeserializeMetaError>;
     /// Returns the default type-erased [`AssetMeta`] for the underlying [`Process`] impl.
     fn default_meta(&self) -> Box<dyn AssetMetaDyn>;
+    /// Type-erased variant of [`Process::version`].
+    fn version(&self) -> u32;
+    /// Returns the [`Process::SETTINGS_VERSION`] of the underlying [`Process`] impl.
+    fn settings_version(&self) -> u32;
 }
 
 impl<P: Process> ErasedProcessor for P {
@@ -255,6 +268,14 @@ impl<P: Process> ErasedProcessor for P {
             settings: P::Settings::default(),
         }))
     }
+
+    fn version(&self) -> u32 {
+        <P as Process>::version(self)
+    }
+
+    fn settings_version(&self) -> u32 {
+        P::SETTINGS_VERSION
+    }
 }
 
 /// Provides scoped data access to the [`AssetProcessor`].
@@ -311,7 +332,15 @@ impl<'a> ProcessContext<'a> {
         let loader = server.get_asset_loader_with_type_name(loader_name).await?;
         let mut reader = SliceReader::new(self.asset_bytes);
         let loaded_asset = server
-            .load_with_meta_loader_and_reader(self.path, &meta, &*loader, &mut reader, false, true)
+            .load_with_meta_loader_and_reader(
+                self.path,
+                &meta,
+                &*loader,
+                &mut reader,
+                false,
+                true,
+                LoadPriority::NORMAL,
+            )
             .await?;
         for (path, full_hash) in &loaded_asset.loader_dependencies {
             self.new_processed_info
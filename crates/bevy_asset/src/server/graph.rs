@@ -0,0 +1,277 @@
+use crate::{LoadState, ManagedAssetInfo, UntypedAssetId};
+use alloc::{
+    format,
+    string::{String, ToString},
+    vec::Vec,
+};
+use bevy_platform_support::collections::{HashMap, HashSet};
+use core::fmt::{self, Write};
+use serde::Serialize;
+
+/// A snapshot of the assets tracked by the [`AssetServer`](crate::AssetServer) and their dependencies, returned by
+/// [`AssetServer::dependency_graph`](crate::AssetServer::dependency_graph).
+///
+/// The graph can be exported with [`AssetDependencyGraph::to_dot`] or [`AssetDependencyGraph::to_json`], and checked for
+/// assets that are kept alive unexpectedly with [`AssetDependencyGraph::leak_report`].
+#[derive(Clone, Debug, Default)]
+pub struct AssetDependencyGraph {
+    nodes: HashMap<UntypedAssetId, AssetGraphNode>,
+}
+
+/// An asset in an [`AssetDependencyGraph`].
+#[derive(Clone, Debug)]
+pub struct AssetGraphNode {
+    /// The state of the asset, including its strong handle count and dependencies.
+    ///
+    /// The strong handle count includes the handles held by its dependents.
+    pub asset: ManagedAssetInfo,
+    /// The assets that depend on this asset.
+    pub dependents: Vec<UntypedAssetId>,
+}
+
+impl AssetGraphNode {
+    /// Returns the number of strong handles to this asset that aren't accounted for by its dependents.
+    ///
+    /// This assumes that each dependent holds a single handle to the asset.
+    pub fn external_handles(&self) -> usize {
+        self.asset
+            .strong_handle_count
+            .saturating_sub(self.dependents.len())
+    }
+
+    fn label(&self) -> String {
+        match &self.asset.path {
+            Some(path) => path.to_string(),
+            None => format!("{:?}", self.asset.id),
+        }
+    }
+}
+
+impl AssetDependencyGraph {
+    pub(crate) fn new(assets: impl IntoIterator<Item = ManagedAssetInfo>) -> Self {
+        let mut nodes: HashMap<_, _> = assets
+            .into_iter()
+            .map(|asset| {
+                let node = AssetGraphNode {
+                    asset,
+                    dependents: Vec::new(),
+                };
+                (node.asset.id, node)
+            })
+            .collect();
+        let edges: Vec<_> = nodes
+            .values()
+            .flat_map(|node| {
+                node.asset
+                    .dependencies
+                    .iter()
+                    .map(|dependency| (node.asset.id, *dependency))
+            })
+            .collect();
+        for (dependent, dependency) in edges {
+            if let Some(node) = nodes.get_mut(&dependency) {
+                node.dependents.push(dependent);
+            }
+        }
+        Self { nodes }
+    }
+
+    /// Returns the node of the asset with the given `id`, if it is tracked by the [`AssetServer`](crate::AssetServer).
+    pub fn get(&self, id: impl Into<UntypedAssetId>) -> Option<&AssetGraphNode> {
+        self.nodes.get(&id.into())
+    }
+
+    /// Iterates over the nodes of the graph, in no particular order.
+    pub fn nodes(&self) -> impl Iterator<Item = &AssetGraphNode> {
+        self.nodes.values()
+    }
+
+    /// Returns the number of assets in the graph.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns `true` if the graph contains no assets.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the ids of the given `roots` and every asset they depend on, recursively.
+    pub fn reachable_from(
+        &self,
+        roots: impl IntoIterator<Item = impl Into<UntypedAssetId>>,
+    ) -> HashSet<UntypedAssetId> {
+        let mut reachable = HashSet::default();
+        let mut pending: Vec<UntypedAssetId> = roots.into_iter().map(Into::into).collect();
+        while let Some(id) = pending.pop() {
+            if !reachable.insert(id) {
+                continue;
+            }
+            if let Some(node) = self.nodes.get(&id) {
+                pending.extend(node.asset.dependencies.iter().copied());
+            }
+        }
+        reachable
+    }
+
+    /// Returns a [`LeakReport`] of the assets that are alive, but not reachable from the given `roots`.
+    ///
+    /// `roots` should be the assets that are expected to be alive, such as the assets of the current level. Any other asset
+    /// with a strong handle is kept alive by a handle that should have been dropped.
+    pub fn leak_report(
+        &self,
+        roots: impl IntoIterator<Item = impl Into<UntypedAssetId>>,
+    ) -> LeakReport {
+        let reachable = self.reachable_from(roots);
+        let mut leaked: Vec<_> = self
+            .nodes
+            .values()
+            .filter(|node| {
+                node.asset.strong_handle_count > 0 && !reachable.contains(&node.asset.id)
+            })
+            .cloned()
+            .collect();
+        leaked.sort_by_cached_key(AssetGraphNode::label);
+        LeakReport { leaked }
+    }
+
+    /// Returns the nodes sorted by label, and the index of every node in that order.
+    fn sorted(&self) -> (Vec<&AssetGraphNode>, HashMap<UntypedAssetId, usize>) {
+        let mut nodes: Vec<_> = self.nodes.values().collect();
+        nodes.sort_by_cached_key(|node| node.label());
+        let indices = nodes
+            .iter()
+            .enumerate()
+            .map(|(index, node)| (node.asset.id, index))
+            .collect();
+        (nodes, indices)
+    }
+
+    /// Exports the graph in the [DOT](https://graphviz.org/doc/info/lang.html) format, with an edge from each asset to
+    /// each of its dependencies.
+    pub fn to_dot(&self) -> String {
+        let (nodes, indices) = self.sorted();
+        let mut dot = String::from("digraph assets {\n");
+        for (index, node) in nodes.iter().enumerate() {
+            let _ = writeln!(
+                dot,
+                "    a{index} [label={}];",
+                quote(&format!(
+                    "{}\nstrong handles: {}",
+                    node.label(),
+                    node.asset.strong_handle_count
+                ))
+            );
+        }
+        for (index, node) in nodes.iter().enumerate() {
+            for dependency in &node.asset.dependencies {
+                if let Some(dependency) = indices.get(dependency) {
+                    let _ = writeln!(dot, "    a{index} -> a{dependency};");
+                }
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Exports the graph as JSON. Assets are identified by their index in the `assets` array.
+    pub fn to_json(&self) -> String {
+        let (nodes, indices) = self.sorted();
+        let graph = JsonGraph {
+            assets: nodes
+                .iter()
+                .enumerate()
+                .map(|(index, node)| JsonAsset {
+                    index,
+                    path: node.asset.path.as_ref().map(ToString::to_string),
+                    strong_handles: node.asset.strong_handle_count,
+                    load_state: load_state_name(&node.asset.load_state),
+                    cached: node.asset.cached,
+                    dependencies: node
+                        .asset
+                        .dependencies
+                        .iter()
+                        .filter_map(|dependency| indices.get(dependency).copied())
+                        .collect(),
+                })
+                .collect(),
+        };
+        serde_json::to_string(&graph).expect("the graph only contains serializable values")
+    }
+}
+
+#[derive(Serialize)]
+struct JsonGraph {
+    assets: Vec<JsonAsset>,
+}
+
+#[derive(Serialize)]
+struct JsonAsset {
+    index: usize,
+    path: Option<String>,
+    strong_handles: usize,
+    load_state: &'static str,
+    cached: bool,
+    dependencies: Vec<usize>,
+}
+
+/// The assets that are alive but not reachable from the expected roots, returned by [`AssetDependencyGraph::leak_report`].
+#[derive(Clone, Debug, Default)]
+pub struct LeakReport {
+    /// The leaked assets, sorted by path.
+    pub leaked: Vec<AssetGraphNode>,
+}
+
+impl LeakReport {
+    /// Returns `true` if no asset was leaked.
+    pub fn is_empty(&self) -> bool {
+        self.leaked.is_empty()
+    }
+}
+
+impl fmt::Display for LeakReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.leaked.is_empty() {
+            return writeln!(f, "No leaked assets");
+        }
+        writeln!(f, "{} leaked assets:", self.leaked.len())?;
+        for node in &self.leaked {
+            writeln!(
+                f,
+                "  {} ({} strong handles, {} held outside of dependents)",
+                node.label(),
+                node.asset.strong_handle_count,
+                node.external_handles()
+            )?;
+        }
+        Ok(())
+    }
+}
+
+fn load_state_name(load_state: &LoadState) -> &'static str {
+    match load_state {
+        LoadState::NotLoaded => "NotLoaded",
+        LoadState::Loading => "Loading",
+        LoadState::Loaded => "Loaded",
+        LoadState::Failed(_) => "Failed",
+    }
+}
+
+/// Quotes `text` as a DOT string.
+fn quote(text: &str) -> String {
+    let mut quoted = String::with_capacity(text.len() + 2);
+    quoted.push('"');
+    for c in text.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            c if c.is_control() => {
+                let _ = write!(quoted, "\\u{:04x}", c as u32);
+            }
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
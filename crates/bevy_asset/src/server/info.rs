@@ -27,6 +27,8 @@ pub(crate) struct AssetInfo {
     pub(crate) load_state: LoadState,
     pub(crate) dep_load_state: DependencyLoadState,
     pub(crate) rec_dep_load_state: RecursiveDependencyLoadState,
+    /// The dependencies of this asset, set when it is loaded.
+    dependencies: HashSet<UntypedAssetId>,
     loading_dependencies: HashSet<UntypedAssetId>,
     failed_dependencies: HashSet<UntypedAssetId>,
     loading_rec_dependencies: HashSet<UntypedAssetId>,
@@ -43,11 +45,28 @@ pub(crate) struct AssetInfo {
     /// The number of handle drops to skip for this asset.
     /// See usage (and comments) in `get_or_create_path_handle` for context.
     handle_drops_to_skip: usize,
+    /// `true` if all handles were dropped, but the asset was kept in its [`Assets`](crate::Assets) cache.
+    cached: bool,
     /// List of tasks waiting for this asset to complete loading
     pub(crate) waiting_tasks: Vec<Waker>,
 }
 
 impl AssetInfo {
+    /// The number of strong handles to this asset that are currently alive.
+    pub(crate) fn strong_handle_count(&self) -> usize {
+        self.weak_handle.strong_count()
+    }
+
+    /// The dependencies of this asset. This is empty until the asset has loaded.
+    pub(crate) fn dependencies(&self) -> &HashSet<UntypedAssetId> {
+        &self.dependencies
+    }
+
+    /// Returns `true` if all handles were dropped, but the asset was kept in its [`Assets`](crate::Assets) cache.
+    pub(crate) fn is_cached(&self) -> bool {
+        self.cached
+    }
+
     fn new(weak_handle: Weak<StrongHandle>, path: Option<AssetPath<'static>>) -> Self {
         Self {
             weak_handle,
@@ -55,6 +74,7 @@ impl AssetInfo {
             load_state: LoadState::NotLoaded,
             dep_load_state: DependencyLoadState::NotLoaded,
             rec_dep_load_state: RecursiveDependencyLoadState::NotLoaded,
+            dependencies: HashSet::default(),
             loading_dependencies: HashSet::default(),
             failed_dependencies: HashSet::default(),
             loading_rec_dependencies: HashSet::default(),
@@ -63,6 +83,7 @@ impl AssetInfo {
             dependents_waiting_on_load: HashSet::default(),
             dependents_waiting_on_recursive_dep_load: HashSet::default(),
             handle_drops_to_skip: 0,
+            cached: false,
             waiting_tasks: Vec::new(),
         }
     }
@@ -245,8 +266,13 @@ impl AssetInfos {
                     // because it locks the AssetInfos collection)
 
                     // We must create a new strong handle for the existing id and ensure that the drop of the old
-                    // strong handle doesn't remove the asset from the Assets collection
-                    info.handle_drops_to_skip += 1;
+                    // strong handle doesn't remove the asset from the Assets collection.
+                    // If the asset was cached, the drop has already been processed.
+                    if info.cached {
+                        info.cached = false;
+                    } else {
+                        info.handle_drops_to_skip += 1;
+                    }
                     let provider = self
                         .handle_providers
                         .get(&type_id)
@@ -283,6 +309,10 @@ impl AssetInfos {
         self.infos.get(&id)
     }
 
+    pub(crate) fn iter(&self) -> impl Iterator<Item = (UntypedAssetId, &AssetInfo)> {
+        self.infos.iter().map(|(id, info)| (*id, info))
+    }
+
     pub(crate) fn contains_key(&self, id: UntypedAssetId) -> bool {
         self.infos.contains_key(&id)
     }
@@ -352,7 +382,7 @@ impl AssetInfos {
         let result = self
             .get_path_ids(&path)
             .filter_map(|id| self.infos.get(&id))
-            .any(|info| info.weak_handle.strong_count() > 0);
+            .any(|info| info.cached || info.weak_handle.strong_count() > 0);
 
         result
     }
@@ -383,6 +413,37 @@ impl AssetInfos {
         )
     }
 
+    /// Handles the drop of the last handle to an asset that can be kept in its [`Assets`](crate::Assets) cache.
+    /// Returns `Some(true)` if the asset should be cached, `Some(false)` if it is still alive, and `None`
+    /// if it can't be cached, in which case it should be dropped with [`AssetInfos::process_handle_drop`].
+    pub(crate) fn cache_handle_drop(&mut self, id: UntypedAssetId) -> Option<bool> {
+        let info = self.infos.get_mut(&id)?;
+        // only assets loaded from a path can be reused by a later load
+        if info.path.is_none() || !info.load_state.is_loaded() {
+            return None;
+        }
+        if info.handle_drops_to_skip > 0 {
+            info.handle_drops_to_skip -= 1;
+            return Some(false);
+        }
+        info.cached = true;
+        Some(true)
+    }
+
+    /// Returns `true` if the asset is unused, and only kept in its [`Assets`](crate::Assets) cache.
+    pub(crate) fn is_cached(&self, id: UntypedAssetId) -> bool {
+        self.infos.get(&id).is_some_and(AssetInfo::is_cached)
+    }
+
+    /// Removes a cached asset. Returns `true` if the asset should be removed from the collection,
+    /// or `false` if it has been loaded again since it was cached.
+    pub(crate) fn evict_cached(&mut self, id: UntypedAssetId) -> bool {
+        if !self.is_cached(id) {
+            return false;
+        }
+        self.process_handle_drop(id)
+    }
+
     /// Updates [`AssetInfo`] / load state for an asset that has finished loading (and relevant dependencies / dependents).
     pub(crate) fn process_asset_load(
         &mut self,
@@ -397,7 +458,8 @@ impl AssetInfos {
         }
 
         loaded_asset.value.insert(loaded_asset_id, world);
-        let mut loading_deps = loaded_asset.dependencies;
+        let dependencies = loaded_asset.dependencies;
+        let mut loading_deps = dependencies.clone();
         let mut failed_deps = <HashSet<_>>::default();
         let mut dep_error = None;
         let mut loading_rec_deps = loading_deps.clone();
@@ -493,6 +555,7 @@ impl AssetInfos {
             let info = self
                 .get_mut(loaded_asset_id)
                 .expect("Asset info should always exist at this point");
+            info.dependencies = dependencies;
             info.loading_dependencies = loading_deps;
             info.failed_dependencies = failed_deps;
             info.loading_rec_dependencies = loading_rec_deps;
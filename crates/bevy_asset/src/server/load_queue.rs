@@ -0,0 +1,219 @@
+use alloc::collections::BTreeMap;
+use bevy_platform_support::collections::HashSet;
+use core::{
+    cmp::Reverse,
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, Waker},
+};
+use parking_lot::Mutex;
+
+/// The priority of an asset load, set with [`LoadBuilder::with_priority`](crate::LoadBuilder::with_priority).
+///
+/// When the [`AssetServer`](crate::AssetServer) limits the number of
+/// [concurrent loads](crate::AssetPlugin::max_concurrent_loads), queued loads with a higher priority start first.
+/// Loads with the same priority start in the order they were requested.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LoadPriority(pub i32);
+
+impl LoadPriority {
+    /// A priority for loads that can wait, such as prefetching.
+    pub const LOW: Self = Self(-100);
+    /// The priority used by [`AssetServer::load`](crate::AssetServer::load) and
+    /// [`AssetServer::load_untyped`](crate::AssetServer::load_untyped).
+    /// Dependencies are loaded with the priority of the asset that depends on them.
+    pub const NORMAL: Self = Self(0);
+    /// A priority for loads that are needed as soon as possible.
+    pub const HIGH: Self = Self(100);
+}
+
+/// Limits the number of asset loads that run at the same time, starting queued loads in priority order.
+#[derive(Default)]
+pub(crate) struct LoadQueue(Mutex<LoadQueueState>);
+
+#[derive(Default)]
+struct LoadQueueState {
+    max_running: Option<usize>,
+    running: usize,
+    next_ticket: u64,
+    waiting: BTreeMap<(Reverse<LoadPriority>, u64), Option<Waker>>,
+    /// Tickets that were given a slot, but haven't been polled since.
+    granted: HashSet<u64>,
+}
+
+impl LoadQueueState {
+    fn has_free_slot(&self) -> bool {
+        self.max_running.is_none_or(|max| self.running < max)
+    }
+
+    /// Gives free slots to the waiting loads with the highest priority.
+    fn grant(&mut self) {
+        while self.has_free_slot() {
+            let Some(((_, ticket), waker)) = self.waiting.pop_first() else {
+                break;
+            };
+            self.running += 1;
+            self.granted.insert(ticket);
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        }
+    }
+
+    fn release(&mut self) {
+        self.running -= 1;
+        self.grant();
+    }
+}
+
+impl LoadQueue {
+    pub(crate) fn set_max_running(&self, max_running: Option<usize>) {
+        let mut state = self.0.lock();
+        state.max_running = max_running;
+        state.grant();
+    }
+
+    pub(crate) fn max_running(&self) -> Option<usize> {
+        self.0.lock().max_running
+    }
+
+    #[cfg(test)]
+    pub(crate) fn waiting_len(&self) -> usize {
+        self.0.lock().waiting.len()
+    }
+
+    /// Waits until a load with the given `priority` is allowed to run.
+    /// The load counts as running until the returned [`LoadPermit`] is dropped.
+    pub(crate) fn acquire(&self, priority: LoadPriority) -> Acquire<'_> {
+        Acquire {
+            queue: self,
+            priority,
+            ticket: None,
+        }
+    }
+}
+
+/// A future that resolves once a queued load is allowed to run. Dropping it removes the load from the queue.
+pub(crate) struct Acquire<'a> {
+    queue: &'a LoadQueue,
+    priority: LoadPriority,
+    ticket: Option<u64>,
+}
+
+impl<'a> Future for Acquire<'a> {
+    type Output = LoadPermit<'a>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let queue = self.queue;
+        let mut state = queue.0.lock();
+        match self.ticket {
+            None => {
+                if state.waiting.is_empty() && state.has_free_slot() {
+                    state.running += 1;
+                    return Poll::Ready(LoadPermit(queue));
+                }
+                let ticket = state.next_ticket;
+                state.next_ticket += 1;
+                state
+                    .waiting
+                    .insert((Reverse(self.priority), ticket), Some(cx.waker().clone()));
+                drop(state);
+                self.ticket = Some(ticket);
+                Poll::Pending
+            }
+            Some(ticket) => {
+                if state.granted.remove(&ticket) {
+                    drop(state);
+                    // The slot now belongs to the permit, so dropping this future mustn't release it.
+                    self.ticket = None;
+                    return Poll::Ready(LoadPermit(queue));
+                }
+                if let Some(waker) = state.waiting.get_mut(&(Reverse(self.priority), ticket)) {
+                    *waker = Some(cx.waker().clone());
+                }
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl Drop for Acquire<'_> {
+    fn drop(&mut self) {
+        let Some(ticket) = self.ticket else {
+            return;
+        };
+        let mut state = self.queue.0.lock();
+        if state.granted.remove(&ticket) {
+            // The load was cancelled after it was given a slot, so pass the slot on.
+            state.release();
+        } else {
+            state.waiting.remove(&(Reverse(self.priority), ticket));
+        }
+    }
+}
+
+/// Marks a load as running. The next queued load is started when this is dropped.
+pub(crate) struct LoadPermit<'a>(&'a LoadQueue);
+
+impl Drop for LoadPermit<'_> {
+    fn drop(&mut self) {
+        self.0 .0.lock().release();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LoadPriority, LoadQueue};
+    use alloc::{sync::Arc, task::Wake};
+    use core::{
+        future::Future,
+        pin::pin,
+        task::{Context, Poll, Waker},
+    };
+
+    struct NoopWaker;
+
+    impl Wake for NoopWaker {
+        fn wake(self: Arc<Self>) {}
+    }
+
+    #[test]
+    fn queued_loads_start_in_priority_order() {
+        let queue = LoadQueue::default();
+        queue.set_max_running(Some(1));
+        let waker = Waker::from(Arc::new(NoopWaker));
+        let mut cx = Context::from_waker(&waker);
+
+        let Poll::Ready(running) = pin!(queue.acquire(LoadPriority::NORMAL)).poll(&mut cx) else {
+            panic!("the first load should start immediately");
+        };
+        let mut low = pin!(queue.acquire(LoadPriority::LOW));
+        let mut cancelled = pin!(queue.acquire(LoadPriority::HIGH));
+        let mut high = pin!(queue.acquire(LoadPriority::HIGH));
+        let mut normal = pin!(queue.acquire(LoadPriority::NORMAL));
+        for future in [
+            low.as_mut(),
+            cancelled.as_mut(),
+            high.as_mut(),
+            normal.as_mut(),
+        ] {
+            assert!(future.poll(&mut cx).is_pending());
+        }
+        // Cancelling a queued load removes it from the queue.
+        cancelled.set(queue.acquire(LoadPriority::LOW));
+
+        drop(running);
+        assert!(low.as_mut().poll(&mut cx).is_pending());
+        assert!(normal.as_mut().poll(&mut cx).is_pending());
+        let Poll::Ready(running) = high.poll(&mut cx) else {
+            panic!("the high priority load should start first");
+        };
+        drop(running);
+        assert!(low.as_mut().poll(&mut cx).is_pending());
+        let Poll::Ready(running) = normal.poll(&mut cx) else {
+            panic!("the normal priority load should start second");
+        };
+        drop(running);
+        assert!(low.poll(&mut cx).is_ready());
+    }
+}
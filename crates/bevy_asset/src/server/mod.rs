@@ -1,23 +1,32 @@
+mod graph;
 mod info;
+mod load_queue;
 mod loaders;
 
 use crate::{
     folder::LoadedFolder,
     io::{
         AssetReaderError, AssetSource, AssetSourceEvent, AssetSourceId, AssetSources,
-        ErasedAssetReader, MissingAssetSourceError, MissingProcessedAssetReaderError, Reader,
+        AssetWriterError, ErasedAssetReader, MissingAssetSourceError, MissingAssetWriterError,
+        MissingProcessedAssetReaderError, Reader,
     },
     loader::{AssetLoader, ErasedAssetLoader, LoadContext, LoadedAsset},
     meta::{
         loader_settings_meta_transform, AssetActionMinimal, AssetMetaDyn, AssetMetaMinimal,
-        MetaTransform, Settings,
+        MetaMigrations, MetaTransform, Settings,
     },
     path::AssetPath,
+    processor::Process,
     Asset, AssetEvent, AssetHandleProvider, AssetId, AssetLoadFailedEvent, AssetMetaCheck, Assets,
-    DeserializeMetaError, ErasedLoadedAsset, Handle, LoadedUntypedAsset, UntypedAssetId,
-    UntypedAssetLoadFailedEvent, UntypedHandle,
+    DeserializeMetaError, ErasedLoadedAsset, Handle, LoadGroup, LoadGroupManifest,
+    LoadedUntypedAsset, UntypedAssetId, UntypedAssetLoadFailedEvent, UntypedHandle,
+};
+use alloc::{
+    borrow::{Cow, ToOwned},
+    boxed::Box,
+    vec,
+    vec::Vec,
 };
-use alloc::{borrow::ToOwned, boxed::Box, vec, vec::Vec};
 use alloc::{
     format,
     string::{String, ToString},
@@ -31,9 +40,13 @@ use core::{any::TypeId, future::Future, panic::AssertUnwindSafe, task::Poll};
 use crossbeam_channel::{Receiver, Sender};
 use either::Either;
 use futures_lite::{FutureExt, StreamExt};
+pub use graph::{AssetDependencyGraph, AssetGraphNode, LeakReport};
 use info::*;
+pub use load_queue::LoadPriority;
+use load_queue::LoadQueue;
 use loaders::*;
 use parking_lot::{RwLock, RwLockWriteGuard};
+use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 use tracing::{error, info};
@@ -61,11 +74,13 @@ pub struct AssetServer {
 pub(crate) struct AssetServerData {
     pub(crate) infos: RwLock<AssetInfos>,
     pub(crate) loaders: Arc<RwLock<AssetLoaders>>,
+    pub(crate) meta_migrations: Arc<RwLock<MetaMigrations>>,
     asset_event_sender: Sender<InternalAssetEvent>,
     asset_event_receiver: Receiver<InternalAssetEvent>,
     sources: AssetSources,
     mode: AssetServerMode,
     meta_check: AssetMetaCheck,
+    pub(crate) load_queue: LoadQueue,
 }
 
 /// The "asset mode" the server is currently in.
@@ -84,6 +99,7 @@ impl AssetServer {
         Self::new_with_loaders(
             sources,
             Default::default(),
+            Default::default(),
             mode,
             AssetMetaCheck::Always,
             watching_for_changes,
@@ -101,6 +117,7 @@ impl AssetServer {
         Self::new_with_loaders(
             sources,
             Default::default(),
+            Default::default(),
             mode,
             meta_check,
             watching_for_changes,
@@ -110,6 +127,7 @@ impl AssetServer {
     pub(crate) fn new_with_loaders(
         sources: AssetSources,
         loaders: Arc<RwLock<AssetLoaders>>,
+        meta_migrations: Arc<RwLock<MetaMigrations>>,
         mode: AssetServerMode,
         meta_check: AssetMetaCheck,
         watching_for_changes: bool,
@@ -125,7 +143,9 @@ impl AssetServer {
                 asset_event_sender,
                 asset_event_receiver,
                 loaders,
+                meta_migrations,
                 infos: RwLock::new(infos),
+                load_queue: LoadQueue::default(),
             }),
         }
     }
@@ -143,11 +163,153 @@ impl AssetServer {
         self.data.infos.read().watching_for_changes
     }
 
+    /// Limits the number of loads started with [`AssetServer::load`] (and its variants) that run at the same time.
+    /// Further loads are queued, and started in [`LoadPriority`] order. If [`None`], loads are never queued, and
+    /// `Some(0)` pauses loading until the limit is raised again.
+    ///
+    /// See [`AssetPlugin::max_concurrent_loads`](crate::AssetPlugin::max_concurrent_loads).
+    pub fn set_max_concurrent_loads(&self, max_concurrent_loads: Option<usize>) {
+        self.data.load_queue.set_max_running(max_concurrent_loads);
+    }
+
+    /// Returns the maximum number of loads that run at the same time, set by [`AssetServer::set_max_concurrent_loads`].
+    pub fn max_concurrent_loads(&self) -> Option<usize> {
+        self.data.load_queue.max_running()
+    }
+
     /// Registers a new [`AssetLoader`]. [`AssetLoader`]s must be registered before they can be used.
     pub fn register_loader<L: AssetLoader>(&self, loader: L) {
         self.data.loaders.write().push(loader);
     }
 
+    /// Registers a migration of the settings of the `L` [`AssetLoader`] from `from_version` to `from_version + 1`.
+    /// See [`MetaMigrations::add_loader_migration`].
+    pub fn register_loader_meta_migration<L, From, To>(
+        &self,
+        from_version: u32,
+        migrate: impl Fn(From) -> To + Send + Sync + 'static,
+    ) where
+        L: AssetLoader,
+        From: for<'a> Deserialize<'a>,
+        To: Serialize,
+    {
+        self.data
+            .meta_migrations
+            .write()
+            .add_loader_migration::<L, From, To>(from_version, migrate);
+    }
+
+    /// Registers a migration of the settings of the `P` [`Process`]or from `from_version` to `from_version + 1`.
+    /// See [`MetaMigrations::add_processor_migration`].
+    pub fn register_processor_meta_migration<P, From, To>(
+        &self,
+        from_version: u32,
+        migrate: impl Fn(From) -> To + Send + Sync + 'static,
+    ) where
+        P: Process,
+        From: for<'a> Deserialize<'a>,
+        To: Serialize,
+    {
+        self.data
+            .meta_migrations
+            .write()
+            .add_processor_migration::<P, From, To>(from_version, migrate);
+    }
+
+    /// Rewrites every meta file in the given asset `source` whose settings are out of date, by applying the registered
+    /// [`MetaMigrations`]. Returns the paths of the assets whose meta files were rewritten.
+    ///
+    /// Settings are migrated to the current version of their loader or processor. If that version is unknown, because
+    /// the loader or processor is not registered, or the settings can't be migrated to it, an error is returned and the
+    /// meta file is left as is.
+    ///
+    /// This reads and writes the unprocessed assets of the source, so the source must have an
+    /// [`AssetWriter`](crate::io::AssetWriter).
+    pub async fn migrate_meta_files<'a>(
+        &self,
+        source: impl Into<AssetSourceId<'a>>,
+    ) -> Result<Vec<AssetPath<'static>>, MigrateMetaFilesError> {
+        let source = self.get_source(source)?;
+        // Fail early, instead of after reading the whole source
+        source.writer()?;
+        let mut migrated = Vec::new();
+        self.migrate_meta_files_internal(source, PathBuf::new(), &mut migrated)
+            .await?;
+        Ok(migrated)
+    }
+
+    async fn migrate_meta_files_internal(
+        &self,
+        source: &AssetSource,
+        path: PathBuf,
+        migrated: &mut Vec<AssetPath<'static>>,
+    ) -> Result<(), MigrateMetaFilesError> {
+        let reader = source.reader();
+        if reader.is_directory(&path).await? {
+            let mut path_stream = reader.read_directory(&path).await?;
+            while let Some(path) = path_stream.next().await {
+                Box::pin(self.migrate_meta_files_internal(source, path, migrated)).await?;
+            }
+            return Ok(());
+        }
+
+        let asset_path = AssetPath::from(path).with_source(source.id());
+        let meta_bytes = match reader.read_meta_bytes(asset_path.path()).await {
+            Ok(meta_bytes) => meta_bytes,
+            Err(AssetReaderError::NotFound(_)) => return Ok(()),
+            Err(err) => return Err(err.into()),
+        };
+        let deserialize_error = |error| MigrateMetaFilesError::DeserializeMeta {
+            path: asset_path.clone(),
+            error,
+        };
+        let minimal: AssetMetaMinimal = ron::de::from_bytes(&meta_bytes)
+            .map_err(|e| deserialize_error(DeserializeMetaError::DeserializeMinimal(e)))?;
+        let current_version = match &minimal.asset {
+            AssetActionMinimal::Load { loader } => self
+                .get_asset_loader_with_type_name(loader)
+                .await
+                .map_err(|error| MigrateMetaFilesError::MissingAssetLoader {
+                    path: asset_path.clone(),
+                    error,
+                })?
+                .settings_version(),
+            AssetActionMinimal::Process { processor } => self
+                .data
+                .meta_migrations
+                .read()
+                .processor_version(processor)
+                .ok_or_else(|| MigrateMetaFilesError::MissingProcessor {
+                    path: asset_path.clone(),
+                    processor: processor.clone(),
+                })?,
+            AssetActionMinimal::Ignore => return Ok(()),
+        };
+        let migrated_bytes = self
+            .data
+            .meta_migrations
+            .read()
+            .migrate(
+                &minimal.asset,
+                minimal.settings_version,
+                current_version,
+                &meta_bytes,
+            )
+            .map_err(|e| deserialize_error(e.into()))?;
+        if let Cow::Owned(migrated_bytes) = migrated_bytes {
+            source
+                .writer()?
+                .write_meta_bytes(asset_path.path(), &migrated_bytes)
+                .await
+                .map_err(|error| MigrateMetaFilesError::AssetWriterError {
+                    path: asset_path.clone(),
+                    error,
+                })?;
+            migrated.push(asset_path);
+        }
+        Ok(())
+    }
+
     /// Registers a new [`Asset`] type. [`Asset`] types must be registered before assets of that type can be loaded.
     pub fn register_asset<A: Asset>(&self, assets: &Assets<A>) {
         self.register_handle_provider(assets.get_handle_provider());
@@ -309,7 +471,7 @@ impl AssetServer {
     /// The asset load will fail and an error will be printed to the logs if the asset stored at `path` is not of type `A`.
     #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
     pub fn load<'a, A: Asset>(&self, path: impl Into<AssetPath<'a>>) -> Handle<A> {
-        self.load_with_meta_transform(path, None, ())
+        self.load_with_meta_transform(path, None, LoadPriority::NORMAL, ())
     }
 
     /// Begins loading an [`Asset`] of type `A` stored at `path` while holding a guard item.
@@ -333,7 +495,7 @@ impl AssetServer {
         path: impl Into<AssetPath<'a>>,
         guard: G,
     ) -> Handle<A> {
-        self.load_with_meta_transform(path, None, guard)
+        self.load_with_meta_transform(path, None, LoadPriority::NORMAL, guard)
     }
 
     /// Begins loading an [`Asset`] of type `A` stored at `path`. The given `settings` function will override the asset's
@@ -345,7 +507,12 @@ impl AssetServer {
         path: impl Into<AssetPath<'a>>,
         settings: impl Fn(&mut S) + Send + Sync + 'static,
     ) -> Handle<A> {
-        self.load_with_meta_transform(path, Some(loader_settings_meta_transform(settings)), ())
+        self.load_with_meta_transform(
+            path,
+            Some(loader_settings_meta_transform(settings)),
+            LoadPriority::NORMAL,
+            (),
+        )
     }
 
     /// Begins loading an [`Asset`] of type `A` stored at `path` while holding a guard item.
@@ -364,13 +531,43 @@ impl AssetServer {
         settings: impl Fn(&mut S) + Send + Sync + 'static,
         guard: G,
     ) -> Handle<A> {
-        self.load_with_meta_transform(path, Some(loader_settings_meta_transform(settings)), guard)
+        self.load_with_meta_transform(
+            path,
+            Some(loader_settings_meta_transform(settings)),
+            LoadPriority::NORMAL,
+            guard,
+        )
+    }
+
+    /// Returns a [`LoadBuilder`] for configuring and starting a load, for example with a [`LoadPriority`].
+    ///
+    /// ```no_run
+    /// # use bevy_asset::{Asset, AssetServer, Handle, LoadPriority};
+    /// # use bevy_ecs::prelude::Res;
+    /// # use bevy_reflect::TypePath;
+    /// # #[derive(Asset, TypePath)]
+    /// # struct Chunk;
+    /// # fn setup(asset_server: Res<AssetServer>) {
+    /// let handle: Handle<Chunk> = asset_server
+    ///     .load_builder()
+    ///     .with_priority(LoadPriority::HIGH)
+    ///     .load("world/chunk_0_0.chunk");
+    /// # }
+    /// ```
+    pub fn load_builder(&self) -> LoadBuilder<'_> {
+        LoadBuilder {
+            server: self,
+            meta_transform: None,
+            priority: LoadPriority::NORMAL,
+            guard: (),
+        }
     }
 
     pub(crate) fn load_with_meta_transform<'a, A: Asset, G: Send + Sync + 'static>(
         &self,
         path: impl Into<AssetPath<'a>>,
         meta_transform: Option<MetaTransform>,
+        priority: LoadPriority,
         guard: G,
     ) -> Handle<A> {
         let path = path.into().into_owned();
@@ -382,7 +579,7 @@ impl AssetServer {
         );
 
         if should_load {
-            self.spawn_load_task(handle.clone().untyped(), path, infos, guard);
+            self.spawn_load_task(handle.clone().untyped(), path, infos, priority, guard);
         }
 
         handle
@@ -393,6 +590,7 @@ impl AssetServer {
         path: impl Into<AssetPath<'a>>,
         type_id: TypeId,
         meta_transform: Option<MetaTransform>,
+        priority: LoadPriority,
         guard: G,
     ) -> UntypedHandle {
         let path = path.into().into_owned();
@@ -406,7 +604,7 @@ impl AssetServer {
         );
 
         if should_load {
-            self.spawn_load_task(handle.clone(), path, infos, guard);
+            self.spawn_load_task(handle.clone(), path, infos, priority, guard);
         }
 
         handle
@@ -417,17 +615,27 @@ impl AssetServer {
         handle: UntypedHandle,
         path: AssetPath<'static>,
         infos: RwLockWriteGuard<AssetInfos>,
+        priority: LoadPriority,
         guard: G,
     ) {
         // drop the lock on `AssetInfos` before spawning a task that may block on it in single-threaded
         #[cfg(any(target_arch = "wasm32", not(feature = "multi_threaded")))]
         drop(infos);
 
-        let owned_handle = handle.clone();
+        // only keep weak references in the task, so that dropping all handles cancels the load
+        let UntypedHandle::Strong(strong_handle) = &handle else {
+            unreachable!("load tasks are only spawned for strong handles");
+        };
+        let queued_handle = Arc::downgrade(strong_handle);
+        let weak_handle = handle.clone_weak();
         let server = self.clone();
         let task = IoTaskPool::get().spawn(async move {
+            let _permit = server.data.load_queue.acquire(priority).await;
+            if queued_handle.strong_count() == 0 {
+                return;
+            }
             if let Err(err) = server
-                .load_internal(Some(owned_handle), path, false, None)
+                .load_internal(Some(weak_handle), path, false, None, priority)
                 .await
             {
                 error!("{}", err);
@@ -454,13 +662,16 @@ impl AssetServer {
         path: impl Into<AssetPath<'a>>,
     ) -> Result<UntypedHandle, AssetLoadError> {
         let path: AssetPath = path.into();
-        self.load_internal(None, path, false, None).await
+        self.load_internal(None, path, false, None, LoadPriority::NORMAL)
+            .await
     }
 
-    pub(crate) fn load_unknown_type_with_meta_transform<'a>(
+    pub(crate) fn load_unknown_type_with_meta_transform<'a, G: Send + Sync + 'static>(
         &self,
         path: impl Into<AssetPath<'a>>,
         meta_transform: Option<MetaTransform>,
+        priority: LoadPriority,
+        guard: G,
     ) -> Handle<LoadedUntypedAsset> {
         let path = path.into().into_owned();
         let untyped_source = AssetSourceId::Name(match path.source() {
@@ -485,10 +696,22 @@ impl AssetServer {
         }
         let id = handle.id().untyped();
 
+        // only keep a weak reference in the task, so that dropping all handles cancels the load
+        let Handle::Strong(strong_handle) = &handle else {
+            unreachable!("load tasks are only spawned for strong handles");
+        };
+        let queued_handle = Arc::downgrade(strong_handle);
         let server = self.clone();
         let task = IoTaskPool::get().spawn(async move {
+            let _permit = server.data.load_queue.acquire(priority).await;
+            if queued_handle.strong_count() == 0 {
+                return;
+            }
             let path_clone = path.clone();
-            match server.load_untyped_async(path).await {
+            match server
+                .load_internal(None, path, false, None, priority)
+                .await
+            {
                 Ok(handle) => server.send_asset_event(InternalAssetEvent::Loaded {
                     id,
                     loaded_asset: LoadedAsset::new_with_dependencies(LoadedUntypedAsset { handle })
@@ -503,6 +726,7 @@ impl AssetServer {
                     });
                 }
             }
+            drop(guard);
         });
 
         #[cfg(not(any(target_arch = "wasm32", not(feature = "multi_threaded"))))]
@@ -539,19 +763,23 @@ impl AssetServer {
     /// required to figure out the asset type before a handle can be created.
     #[must_use = "not using the returned strong handle may result in the unexpected release of the assets"]
     pub fn load_untyped<'a>(&self, path: impl Into<AssetPath<'a>>) -> Handle<LoadedUntypedAsset> {
-        self.load_unknown_type_with_meta_transform(path, None)
+        self.load_unknown_type_with_meta_transform(path, None, LoadPriority::NORMAL, ())
     }
 
     /// Performs an async asset load.
     ///
     /// `input_handle` must only be [`Some`] if `should_load` was true when retrieving `input_handle`. This is an optimization to
     /// avoid looking up `should_load` twice, but it means you _must_ be sure a load is necessary when calling this function with [`Some`].
+    ///
+    /// If `input_handle` is weak, the load stops once the asset's meta has been read if all strong handles to the asset
+    /// have been dropped by then. Dependencies of the asset are loaded with the given `priority`.
     async fn load_internal<'a>(
         &self,
         mut input_handle: Option<UntypedHandle>,
         path: AssetPath<'a>,
         force: bool,
         meta_transform: Option<MetaTransform>,
+        priority: LoadPriority,
     ) -> Result<UntypedHandle, AssetLoadError> {
         let asset_type_id = input_handle.as_ref().map(UntypedHandle::type_id);
 
@@ -572,6 +800,14 @@ impl AssetServer {
                 }
             })?;
 
+        // a weak input handle doesn't keep the asset alive while it is loading. Look up its strong handle to apply the
+        // meta transform, and stop if the asset has been dropped in the meantime.
+        if let Some(UntypedHandle::Weak(id)) = input_handle {
+            let Some(handle) = self.data.infos.read().get_id_handle(id) else {
+                return Ok(UntypedHandle::Weak(id));
+            };
+            input_handle = Some(handle);
+        }
         if let Some(meta_transform) = input_handle.as_ref().and_then(|h| h.meta_transform()) {
             (*meta_transform)(&mut *meta);
         }
@@ -655,6 +891,7 @@ impl AssetServer {
                 &mut *reader,
                 true,
                 false,
+                priority,
             )
             .await
         {
@@ -718,7 +955,15 @@ impl AssetServer {
                     .infos
                     .read()
                     .get_path_handles(&path)
-                    .map(|handle| server.load_internal(Some(handle), path.clone(), true, None))
+                    .map(|handle| {
+                        server.load_internal(
+                            Some(handle),
+                            path.clone(),
+                            true,
+                            None,
+                            LoadPriority::NORMAL,
+                        )
+                    })
                     .collect::<Vec<_>>();
 
                 for result in requests {
@@ -729,7 +974,10 @@ impl AssetServer {
                 }
 
                 if !reloaded && server.data.infos.read().should_reload(&path) {
-                    if let Err(err) = server.load_internal(None, path, true, None).await {
+                    if let Err(err) = server
+                        .load_internal(None, path, true, None, LoadPriority::NORMAL)
+                        .await
+                    {
                         error!("{}", err);
                     }
                 }
@@ -836,6 +1084,28 @@ impl AssetServer {
         handle.typed_debug_checked()
     }
 
+    /// Loads all assets at the given `paths` and returns a [`LoadGroup`] that tracks their aggregated progress,
+    /// including their recursive dependencies. Spawn it on an entity to receive
+    /// [`LoadGroupLoaded`](crate::LoadGroupLoaded) or [`LoadGroupFailed`](crate::LoadGroupFailed) when the group finishes loading.
+    ///
+    /// The group holds [`LoadedUntypedAsset`] handles, as with [`AssetServer::load_untyped`].
+    #[must_use = "not using the returned load group may result in the unexpected release of the assets"]
+    pub fn load_group<'a>(
+        &self,
+        paths: impl IntoIterator<Item = impl Into<AssetPath<'a>>>,
+    ) -> LoadGroup {
+        paths.into_iter().fold(LoadGroup::new(), |group, path| {
+            group.with_handle(self.load_untyped(path))
+        })
+    }
+
+    /// Loads the [`LoadGroupManifest`] at the given `path`, and returns a [`LoadGroup`] that tracks it along with
+    /// every asset it lists.
+    #[must_use = "not using the returned load group may result in the unexpected release of the assets"]
+    pub fn load_group_manifest<'a>(&self, path: impl Into<AssetPath<'a>>) -> LoadGroup {
+        LoadGroup::new().with_handle(self.load::<LoadGroupManifest>(path))
+    }
+
     /// Loads all assets from the specified folder recursively. The [`LoadedFolder`] asset (when it loads) will
     /// contain handles to all assets in the folder. You can wait for all assets to load by checking the [`LoadedFolder`]'s
     /// [`RecursiveDependencyLoadState`].
@@ -952,6 +1222,29 @@ impl AssetServer {
         self.data.asset_event_sender.send(event).unwrap();
     }
 
+    /// Returns a snapshot of the state of every asset managed by this server: those which were
+    /// loaded or added through it.
+    ///
+    /// This is intended for debugging tools, e.g. to find assets which are stuck loading or kept
+    /// alive by leaked handles.
+    pub fn managed_assets(&self) -> Vec<ManagedAssetInfo> {
+        self.data
+            .infos
+            .read()
+            .iter()
+            .map(|(id, info)| ManagedAssetInfo {
+                id,
+                path: info.path.clone(),
+                load_state: info.load_state.clone(),
+                dependency_load_state: info.dep_load_state.clone(),
+                recursive_dependency_load_state: info.rec_dep_load_state.clone(),
+                strong_handle_count: info.strong_handle_count(),
+                dependencies: info.dependencies().iter().copied().collect(),
+                cached: info.is_cached(),
+            })
+            .collect()
+    }
+
     /// Retrieves all loads states for the given asset id.
     pub fn get_load_states(
         &self,
@@ -1153,6 +1446,14 @@ impl AssetServer {
         infos.get_path_and_type_id_handle(&path, type_id)
     }
 
+    /// Returns the [managed assets](AssetServer::managed_assets) of this server as a graph, linking each asset to its
+    /// dependencies and dependents.
+    ///
+    /// Use [`AssetDependencyGraph::leak_report`] to find assets that are kept alive by handles that should have been dropped.
+    pub fn dependency_graph(&self) -> AssetDependencyGraph {
+        AssetDependencyGraph::new(self.managed_assets())
+    }
+
     /// Returns the path for the given `id`, if it has one.
     pub fn get_path(&self, id: impl Into<UntypedAssetId>) -> Option<AssetPath> {
         let infos = self.data.infos.read();
@@ -1250,7 +1551,7 @@ impl AssetServer {
                                 error: DeserializeMetaError::DeserializeMinimal(e).into(),
                             }
                         })?;
-                    let loader_name = match minimal.asset {
+                    let loader_name = match &minimal.asset {
                         AssetActionMinimal::Load { loader } => loader,
                         AssetActionMinimal::Process { .. } => {
                             return Err(AssetLoadError::CannotLoadProcessedAsset {
@@ -1263,7 +1564,21 @@ impl AssetServer {
                             })
                         }
                     };
-                    let loader = self.get_asset_loader_with_type_name(&loader_name).await?;
+                    let loader = self.get_asset_loader_with_type_name(loader_name).await?;
+                    let meta_bytes = self
+                        .data
+                        .meta_migrations
+                        .read()
+                        .migrate(
+                            &minimal.asset,
+                            minimal.settings_version,
+                            loader.settings_version(),
+                            &meta_bytes,
+                        )
+                        .map_err(|e| AssetLoadError::DeserializeMeta {
+                            path: asset_path.clone_owned(),
+                            error: DeserializeMetaError::from(e).into(),
+                        })?;
                     let meta = loader.deserialize_meta(&meta_bytes).map_err(|e| {
                         AssetLoadError::DeserializeMeta {
                             path: asset_path.clone_owned(),
@@ -1326,11 +1641,17 @@ impl AssetServer {
         reader: &mut dyn Reader,
         load_dependencies: bool,
         populate_hashes: bool,
+        priority: LoadPriority,
     ) -> Result<ErasedLoadedAsset, AssetLoadError> {
         // TODO: experiment with this
         let asset_path = asset_path.clone_owned();
-        let load_context =
-            LoadContext::new(self, asset_path.clone(), load_dependencies, populate_hashes);
+        let load_context = LoadContext::new(
+            self,
+            asset_path.clone(),
+            load_dependencies,
+            populate_hashes,
+            priority,
+        );
         AssertUnwindSafe(loader.load(reader, meta, load_context))
             .catch_unwind()
             .await
@@ -1607,6 +1928,67 @@ pub fn handle_internal_asset_events(world: &mut World) {
     });
 }
 
+/// Configures and starts an asset load. Created by [`AssetServer::load_builder`].
+pub struct LoadBuilder<'a, G = ()> {
+    server: &'a AssetServer,
+    meta_transform: Option<MetaTransform>,
+    priority: LoadPriority,
+    guard: G,
+}
+
+impl<'a, G: Send + Sync + 'static> LoadBuilder<'a, G> {
+    /// Sets the [`LoadPriority`] of the load. Defaults to [`LoadPriority::NORMAL`].
+    ///
+    /// The priority only applies if the asset isn't already loading. It affects the order in which queued loads are
+    /// started when [`AssetServer::max_concurrent_loads`] is set. Dependencies of the asset are loaded with the same priority.
+    pub fn with_priority(mut self, priority: LoadPriority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    /// Overrides the asset's [`AssetLoader`] settings. The type `S` _must_ match the configured [`AssetLoader::Settings`] or
+    /// `settings` changes will be ignored and an error will be printed to the log.
+    pub fn with_settings<S: Settings>(
+        mut self,
+        settings: impl Fn(&mut S) + Send + Sync + 'static,
+    ) -> Self {
+        self.meta_transform = Some(loader_settings_meta_transform(settings));
+        self
+    }
+
+    /// Holds `guard` until the asset is loaded or loading has failed. See [`AssetServer::load_acquire`].
+    pub fn with_guard<G2: Send + Sync + 'static>(self, guard: G2) -> LoadBuilder<'a, G2> {
+        LoadBuilder {
+            server: self.server,
+            meta_transform: self.meta_transform,
+            priority: self.priority,
+            guard,
+        }
+    }
+
+    /// Begins loading an [`Asset`] of type `A` stored at `path`. See [`AssetServer::load`].
+    ///
+    /// If all handles to the asset are dropped before it is loaded, the load is cancelled.
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
+    pub fn load<'p, A: Asset>(self, path: impl Into<AssetPath<'p>>) -> Handle<A> {
+        self.server
+            .load_with_meta_transform(path, self.meta_transform, self.priority, self.guard)
+    }
+
+    /// Begins loading an asset stored at `path` without knowing its type. See [`AssetServer::load_untyped`].
+    ///
+    /// If all handles to the [`LoadedUntypedAsset`] are dropped before it is loaded, the load is cancelled.
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the assets"]
+    pub fn load_untyped<'p>(self, path: impl Into<AssetPath<'p>>) -> Handle<LoadedUntypedAsset> {
+        self.server.load_unknown_type_with_meta_transform(
+            path,
+            self.meta_transform,
+            self.priority,
+            self.guard,
+        )
+    }
+}
+
 /// Internal events for asset load results
 pub(crate) enum InternalAssetEvent {
     Loaded {
@@ -1623,6 +2005,30 @@ pub(crate) enum InternalAssetEvent {
     },
 }
 
+/// The state of an asset managed by the [`AssetServer`], as returned by
+/// [`AssetServer::managed_assets`].
+#[derive(Clone, Debug)]
+pub struct ManagedAssetInfo {
+    /// The id of the asset.
+    pub id: UntypedAssetId,
+    /// The path the asset was loaded from, if any.
+    pub path: Option<AssetPath<'static>>,
+    /// The [`LoadState`] of the asset itself.
+    pub load_state: LoadState,
+    /// The [`DependencyLoadState`] of the asset's direct dependencies.
+    pub dependency_load_state: DependencyLoadState,
+    /// The [`RecursiveDependencyLoadState`] of the asset's dependency tree.
+    pub recursive_dependency_load_state: RecursiveDependencyLoadState,
+    /// The number of strong handles to the asset that are currently alive. The asset is
+    /// unloaded once this reaches zero.
+    pub strong_handle_count: usize,
+    /// The direct dependencies of the asset. This is empty until the asset has loaded.
+    pub dependencies: Vec<UntypedAssetId>,
+    /// `true` if the asset is unused, and only kept because of the [memory budget](crate::Assets::set_memory_budget)
+    /// of its collection.
+    pub cached: bool,
+}
+
 /// The load state of an asset.
 #[derive(Component, Clone, Debug)]
 pub enum LoadState {
@@ -1729,6 +2135,55 @@ impl RecursiveDependencyLoadState {
     }
 }
 
+/// An error that occurs while rewriting meta files with [`AssetServer::migrate_meta_files`].
+#[derive(Error, Debug)]
+pub enum MigrateMetaFilesError {
+    /// The requested asset source does not exist.
+    #[error(transparent)]
+    MissingAssetSourceError(#[from] MissingAssetSourceError),
+    /// The asset source has no writer, so its meta files can't be rewritten.
+    #[error(transparent)]
+    MissingAssetWriterError(#[from] MissingAssetWriterError),
+    /// The assets or meta files of the source could not be read.
+    #[error(transparent)]
+    AssetReaderError(#[from] AssetReaderError),
+    /// A migrated meta file could not be written.
+    #[error("Failed to write migrated meta for asset {path}: {error}")]
+    AssetWriterError {
+        /// The path of the asset whose meta file could not be written.
+        path: AssetPath<'static>,
+        /// The error returned by the writer.
+        error: AssetWriterError,
+    },
+    /// A meta file names a loader that is not registered, so the version to migrate its settings to is unknown.
+    #[error("Failed to migrate meta for asset {path}: {error}")]
+    MissingAssetLoader {
+        /// The path of the asset whose meta file names the missing loader.
+        path: AssetPath<'static>,
+        /// The error returned when looking up the loader.
+        error: MissingAssetLoaderForTypeNameError,
+    },
+    /// A meta file names a processor that is not registered, so the version to migrate its settings to is unknown.
+    #[error(
+        "Failed to migrate meta for asset {path}: the processor {processor} is not registered"
+    )]
+    MissingProcessor {
+        /// The path of the asset whose meta file names the missing processor.
+        path: AssetPath<'static>,
+        /// The type name of the missing processor.
+        processor: String,
+    },
+    /// A meta file could not be deserialized or migrated, because its settings are newer than the current version of
+    /// their loader or processor, or a migration to the current version is missing.
+    #[error("Failed to migrate meta for asset {path}: {error}")]
+    DeserializeMeta {
+        /// The path of the asset whose meta file could not be migrated.
+        path: AssetPath<'static>,
+        /// The error that occurred while deserializing or migrating the meta.
+        error: DeserializeMetaError,
+    },
+}
+
 /// An error that occurs during an [`Asset`] load.
 #[derive(Error, Debug, Clone)]
 pub enum AssetLoadError {
@@ -0,0 +1,413 @@
+//! A headless [`App`] wrapper for writing deterministic, frame-accurate integration tests.
+
+use bevy_app::{prelude::*, PluginsState};
+use bevy_diagnostic::FrameCountPlugin;
+use bevy_ecs::{event::Event, prelude::*};
+use bevy_input::{
+    gamepad::{
+        GamepadAxis, GamepadButton, GamepadConnection, GamepadConnectionEvent,
+        RawGamepadAxisChangedEvent, RawGamepadButtonChangedEvent, RawGamepadEvent,
+    },
+    keyboard::{Key, KeyCode, KeyboardInput, NativeKey},
+    mouse::{MouseButton, MouseButtonInput, MouseMotion, MouseScrollUnit, MouseWheel},
+    ButtonState, InputPlugin,
+};
+use bevy_math::Vec2;
+use bevy_time::{TimePlugin, TimeUpdateStrategy};
+use core::{
+    fmt,
+    ops::{Deref, DerefMut},
+    time::Duration,
+};
+
+/// A headless [`App`] that advances time by a fixed duration every frame, for writing
+/// deterministic gameplay integration tests.
+///
+/// It comes with the [`TaskPoolPlugin`], [`TimePlugin`], [`FrameCountPlugin`] and
+/// [`InputPlugin`], and dereferences to the wrapped [`App`] so the plugins under test can be
+/// added as usual. Input is injected as the same events a windowing or gamepad backend would
+/// send, and is processed by the next frame.
+///
+/// ```
+/// # use bevy_app::prelude::*;
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_input::{keyboard::KeyCode, ButtonInput};
+/// use bevy_dev_tools::test_app::TestApp;
+///
+/// #[derive(Resource, Default)]
+/// struct Jumps(u32);
+///
+/// fn jump(keys: Res<ButtonInput<KeyCode>>, mut jumps: ResMut<Jumps>) {
+///     if keys.just_pressed(KeyCode::Space) {
+///         jumps.0 += 1;
+///     }
+/// }
+///
+/// let mut app = TestApp::new();
+/// app.init_resource::<Jumps>().add_systems(Update, jump);
+///
+/// app.press_key(KeyCode::Space);
+/// app.advance_frames(10);
+/// app.assert_world("jumped once", |world| world.resource::<Jumps>().0 == 1);
+/// ```
+pub struct TestApp {
+    app: App,
+    frame_time: Duration,
+    frame: u32,
+}
+
+/// The default time that passes each frame of a [`TestApp`], matching 60 frames per second.
+pub const DEFAULT_FRAME_TIME: Duration = Duration::from_nanos(16_666_667);
+
+impl TestApp {
+    /// Creates a [`TestApp`] that advances time by [`DEFAULT_FRAME_TIME`] every frame.
+    pub fn new() -> Self {
+        Self::with_frame_time(DEFAULT_FRAME_TIME)
+    }
+
+    /// Creates a [`TestApp`] that advances time by `frame_time` every frame.
+    pub fn with_frame_time(frame_time: Duration) -> Self {
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            TimePlugin,
+            FrameCountPlugin,
+            InputPlugin,
+        ))
+        .insert_resource(TimeUpdateStrategy::ManualDuration(frame_time));
+        Self {
+            app,
+            frame_time,
+            frame: 0,
+        }
+    }
+
+    /// The time that passes each frame.
+    pub fn frame_time(&self) -> Duration {
+        self.frame_time
+    }
+
+    /// The number of frames that have been run.
+    pub fn frame(&self) -> u32 {
+        self.frame
+    }
+
+    /// Runs a single frame.
+    ///
+    /// The first frame finishes and cleans up the plugins, as the app runner would.
+    pub fn advance_frame(&mut self) -> &mut Self {
+        if self.app.plugins_state() != PluginsState::Cleaned {
+            while self.app.plugins_state() == PluginsState::Adding {
+                bevy_tasks::tick_global_task_pools_on_main_thread();
+            }
+            self.app.finish();
+            self.app.cleanup();
+        }
+        self.app.update();
+        self.frame += 1;
+        self
+    }
+
+    /// Runs `frames` frames.
+    pub fn advance_frames(&mut self, frames: u32) -> &mut Self {
+        for _ in 0..frames {
+            self.advance_frame();
+        }
+        self
+    }
+
+    /// Runs as many frames as it takes for `duration` to pass, rounding up.
+    ///
+    /// Time doesn't advance during the first frame of an app, so if no frame has been run yet,
+    /// this runs one extra frame.
+    pub fn advance_time(&mut self, duration: Duration) -> &mut Self {
+        let frames = duration
+            .as_nanos()
+            .div_ceil(self.frame_time.as_nanos().max(1))
+            + u128::from(self.frame == 0);
+        self.advance_frames(frames.try_into().unwrap_or(u32::MAX))
+    }
+
+    /// Runs frames until `condition` holds after a frame, up to `max_frames` frames.
+    ///
+    /// Returns the number of frames that were run.
+    ///
+    /// The limit counts frames, not wall-clock time, so the result doesn't depend on how fast
+    /// the machine running the test is. A frame that never returns, e.g. because a system
+    /// blocks on a task that never completes, is not interrupted.
+    pub fn advance_until(
+        &mut self,
+        max_frames: u32,
+        mut condition: impl FnMut(&mut World) -> bool,
+    ) -> Result<u32, TestAppTimeout> {
+        for frames in 1..=max_frames {
+            self.advance_frame();
+            if condition(self.app.world_mut()) {
+                return Ok(frames);
+            }
+        }
+        Err(TestAppTimeout {
+            frames: max_frames,
+            frame: self.frame,
+        })
+    }
+
+    /// Panics with `description` and the current frame if `condition` doesn't hold.
+    #[track_caller]
+    pub fn assert_world(
+        &mut self,
+        description: &str,
+        condition: impl FnOnce(&mut World) -> bool,
+    ) -> &mut Self {
+        assert!(
+            condition(self.app.world_mut()),
+            "assertion failed on frame {}: {description}",
+            self.frame
+        );
+        self
+    }
+
+    /// Sends an event, to be read by the next frame.
+    pub fn send_event<E: Event>(&mut self, event: E) -> &mut Self {
+        self.app.world_mut().send_event(event);
+        self
+    }
+
+    /// Presses a key on the keyboard.
+    pub fn press_key(&mut self, key_code: KeyCode) -> &mut Self {
+        self.send_key(key_code, ButtonState::Pressed)
+    }
+
+    /// Releases a key on the keyboard.
+    pub fn release_key(&mut self, key_code: KeyCode) -> &mut Self {
+        self.send_key(key_code, ButtonState::Released)
+    }
+
+    fn send_key(&mut self, key_code: KeyCode, state: ButtonState) -> &mut Self {
+        self.send_event(KeyboardInput {
+            key_code,
+            logical_key: Key::Unidentified(NativeKey::Unidentified),
+            state,
+            text: None,
+            repeat: false,
+            window: Entity::PLACEHOLDER,
+        })
+    }
+
+    /// Presses a mouse button.
+    pub fn press_mouse_button(&mut self, button: MouseButton) -> &mut Self {
+        self.send_event(MouseButtonInput {
+            button,
+            state: ButtonState::Pressed,
+            window: Entity::PLACEHOLDER,
+        })
+    }
+
+    /// Releases a mouse button.
+    pub fn release_mouse_button(&mut self, button: MouseButton) -> &mut Self {
+        self.send_event(MouseButtonInput {
+            button,
+            state: ButtonState::Released,
+            window: Entity::PLACEHOLDER,
+        })
+    }
+
+    /// Moves the mouse by `delta`.
+    pub fn move_mouse(&mut self, delta: Vec2) -> &mut Self {
+        self.send_event(MouseMotion { delta })
+    }
+
+    /// Scrolls the mouse wheel by `delta` lines.
+    pub fn scroll_mouse(&mut self, delta: Vec2) -> &mut Self {
+        self.send_event(MouseWheel {
+            unit: MouseScrollUnit::Line,
+            x: delta.x,
+            y: delta.y,
+            window: Entity::PLACEHOLDER,
+        })
+    }
+
+    /// Connects a new gamepad, and returns its entity.
+    pub fn connect_gamepad(&mut self) -> Entity {
+        let gamepad = self.app.world_mut().spawn_empty().id();
+        let event = GamepadConnectionEvent::new(
+            gamepad,
+            GamepadConnection::Connected {
+                name: "Test Gamepad".into(),
+                vendor_id: None,
+                product_id: None,
+            },
+        );
+        self.send_event(RawGamepadEvent::from(event.clone()))
+            .send_event(event);
+        gamepad
+    }
+
+    /// Disconnects a gamepad.
+    pub fn disconnect_gamepad(&mut self, gamepad: Entity) -> &mut Self {
+        let event = GamepadConnectionEvent::new(gamepad, GamepadConnection::Disconnected);
+        self.send_event(RawGamepadEvent::from(event.clone()))
+            .send_event(event)
+    }
+
+    /// Presses a button of a gamepad.
+    pub fn press_gamepad_button(&mut self, gamepad: Entity, button: GamepadButton) -> &mut Self {
+        self.set_gamepad_button(gamepad, button, 1.0)
+    }
+
+    /// Releases a button of a gamepad.
+    pub fn release_gamepad_button(&mut self, gamepad: Entity, button: GamepadButton) -> &mut Self {
+        self.set_gamepad_button(gamepad, button, 0.0)
+    }
+
+    /// Sets the raw value of a button of a gamepad, between `0.0` and `1.0`.
+    pub fn set_gamepad_button(
+        &mut self,
+        gamepad: Entity,
+        button: GamepadButton,
+        value: f32,
+    ) -> &mut Self {
+        let event = RawGamepadButtonChangedEvent::new(gamepad, button, value);
+        self.send_event(RawGamepadEvent::from(event))
+            .send_event(event)
+    }
+
+    /// Sets the raw value of an axis of a gamepad, between `-1.0` and `1.0`.
+    pub fn set_gamepad_axis(
+        &mut self,
+        gamepad: Entity,
+        axis: GamepadAxis,
+        value: f32,
+    ) -> &mut Self {
+        let event = RawGamepadAxisChangedEvent::new(gamepad, axis, value);
+        self.send_event(RawGamepadEvent::from(event))
+            .send_event(event)
+    }
+
+    /// Returns the wrapped [`App`].
+    pub fn into_app(self) -> App {
+        self.app
+    }
+}
+
+impl Default for TestApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Deref for TestApp {
+    type Target = App;
+
+    fn deref(&self) -> &Self::Target {
+        &self.app
+    }
+}
+
+impl DerefMut for TestApp {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.app
+    }
+}
+
+/// The error returned by [`TestApp::advance_until`] when the condition didn't hold within its
+/// frame limit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TestAppTimeout {
+    /// The number of frames that were run while waiting.
+    pub frames: u32,
+    /// The frame the app stopped at.
+    pub frame: u32,
+}
+
+impl fmt::Display for TestAppTimeout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "condition did not hold after {} frames (stopped at frame {})",
+            self.frames, self.frame
+        )
+    }
+}
+
+impl core::error::Error for TestAppTimeout {}
+
+#[cfg(test)]
+mod tests {
+    use super::TestApp;
+    use bevy_app::Update;
+    use bevy_ecs::prelude::*;
+    use bevy_input::{
+        gamepad::{Gamepad, GamepadButton},
+        keyboard::KeyCode,
+        ButtonInput,
+    };
+    use bevy_time::Time;
+    use core::time::Duration;
+
+    #[derive(Resource, Default)]
+    struct Elapsed(Duration);
+
+    #[test]
+    fn time_advances_by_frame_time() {
+        let mut app = TestApp::with_frame_time(Duration::from_millis(10));
+        app.init_resource::<Elapsed>().add_systems(
+            Update,
+            |time: Res<Time>, mut elapsed: ResMut<Elapsed>| {
+                elapsed.0 = time.elapsed();
+            },
+        );
+
+        // The first frame doesn't advance time.
+        app.advance_time(Duration::from_millis(50));
+        assert_eq!(app.frame(), 6);
+        app.assert_world("time advanced by the duration", |world| {
+            world.resource::<Elapsed>().0 == Duration::from_millis(50)
+        });
+
+        app.advance_time(Duration::from_millis(15));
+        assert_eq!(app.frame(), 8);
+        app.assert_world("time advanced by whole frames", |world| {
+            world.resource::<Elapsed>().0 == Duration::from_millis(70)
+        });
+    }
+
+    #[test]
+    fn advance_until_times_out() {
+        let mut app = TestApp::new();
+        let frames = app
+            .advance_until(10, |world| world.resource::<Time>().elapsed_secs() > 0.06)
+            .unwrap();
+        assert_eq!(frames, 5);
+
+        let timeout = app.advance_until(3, |_| false).unwrap_err();
+        assert_eq!((timeout.frames, timeout.frame), (3, 8));
+    }
+
+    #[test]
+    fn injects_input() {
+        let mut app = TestApp::new();
+        app.press_key(KeyCode::Space);
+        let gamepad = app.connect_gamepad();
+        app.advance_frame();
+        app.assert_world("space is pressed", |world| {
+            world
+                .resource::<ButtonInput<KeyCode>>()
+                .just_pressed(KeyCode::Space)
+        });
+
+        app.press_gamepad_button(gamepad, GamepadButton::South)
+            .release_key(KeyCode::Space)
+            .advance_frame()
+            .assert_world("space is released", |world| {
+                world
+                    .resource::<ButtonInput<KeyCode>>()
+                    .just_released(KeyCode::Space)
+            })
+            .assert_world("south is pressed", |world| {
+                world
+                    .get::<Gamepad>(gamepad)
+                    .is_some_and(|gamepad| gamepad.pressed(GamepadButton::South))
+            });
+    }
+}
@@ -0,0 +1,19 @@
+//! A hot-reloadable plugin, loaded by the tests of `bevy_dylib::hot_reload`.
+
+use bevy_internal::{
+    app::{App, Plugin, Update},
+    ecs::{name::Name, system::Commands},
+};
+
+/// Spawns an entity named `hot_reload_fixture` every update.
+pub struct FixturePlugin;
+
+impl Plugin for FixturePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(Update, |mut commands: Commands| {
+            commands.spawn(Name::new("hot_reload_fixture"));
+        });
+    }
+}
+
+bevy_dylib::hot_reload_plugin!(FixturePlugin);
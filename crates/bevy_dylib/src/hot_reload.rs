@@ -0,0 +1,787 @@
+//! Hot reloading of game logic from a dynamically linked plugin.
+//!
+//! Game logic that changes often can be moved into its own crate, built as a `dylib` that
+//! exports a [`Plugin`] with [`hot_reload_plugin!`](crate::hot_reload_plugin). The
+//! [`HotReloadPlugin`] loads that library at startup, rebuilds it when its sources change, and
+//! swaps the new version in while the app keeps running:
+//!
+//! - the systems and observers added by the previous version are removed, and the new version
+//!   is built again,
+//! - types it registers replace their previous registrations in the [`AppTypeRegistry`],
+//! - components and resources of those types are serialized through reflection before the
+//!   reload, and deserialized with the new registrations afterwards, so world state survives.
+//!
+//! ```toml
+//! # gameplay/Cargo.toml
+//! [lib]
+//! crate-type = ["dylib"]
+//!
+//! [dependencies]
+//! bevy = { version = "0.16", features = ["hot_reload"] }
+//! ```
+//!
+//! ```ignore
+//! // gameplay/src/lib.rs
+//! bevy::hot_reload::hot_reload_plugin!(GameplayPlugin);
+//!
+//! // src/main.rs
+//! App::new()
+//!     .add_plugins(DefaultPlugins)
+//!     .add_plugins(HotReloadPlugin::new("gameplay").watch("gameplay/src"))
+//!     .run();
+//! ```
+//!
+//! # Limitations
+//!
+//! - Both the app and the library must use the `hot_reload` feature, which enables
+//!   `dynamic_linking` so they share a single copy of Bevy, and must be built by the same
+//!   compiler with the same features.
+//! - Changing the fields of a component or resource type requires a restart: the world keeps
+//!   the memory layout a type was first registered with.
+//! - Previous versions of the library are never unloaded, since the world may still hold
+//!   pointers into them (for example to drop unreflected components and resources). Their
+//!   copies in the temporary directory are removed when the app exits, and copies left behind
+//!   by previous runs are removed on the next start, where the platform allows it.
+//! - The plugin is built against the main world only. Its systems run in the
+//!   [`HotReloadSystems`] set of each schedule they are added to.
+//! - Startup systems only run for the version loaded at startup. If the library has to be
+//!   built first, the build runs in the background and the library is loaded once it
+//!   finishes, so none of its startup systems run.
+
+use bevy_internal::{
+    app::{App, Last, MainScheduleOrder, Plugin},
+    ecs::{
+        entity::Entity,
+        event::{Event, Events},
+        observer::Observer,
+        query::With,
+        reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+        resource::Resource,
+        schedule::{
+            InternedScheduleLabel, IntoSystemConfigs, Schedule, ScheduleLabel, Schedules, SystemSet,
+        },
+        world::{Mut, World},
+    },
+    log::{error, info, warn},
+    platform_support::collections::{HashMap, HashSet},
+    reflect::{
+        serde::{TypedReflectDeserializer, TypedReflectSerializer},
+        PartialReflect, TypeRegistry,
+    },
+};
+use core::{any::TypeId, time::Duration};
+use libloading::Library;
+use serde::de::DeserializeSeed;
+use std::{
+    env::consts::{DLL_PREFIX, DLL_SUFFIX},
+    path::{Path, PathBuf},
+    process::{Child, Command},
+    time::{Instant, SystemTime},
+};
+use thiserror::Error;
+
+#[doc(hidden)]
+pub use bevy_internal::app::Plugin as __Plugin;
+
+/// The name of the function exported by [`hot_reload_plugin!`](crate::hot_reload_plugin).
+pub const HOT_RELOAD_SYMBOL: &[u8] = b"bevy_hot_reload_plugin\0";
+
+/// Exports a [`Plugin`] from a `dylib` crate, so that it can be loaded by a
+/// [`HotReloadPlugin`].
+///
+/// The argument is an expression that creates the plugin.
+#[macro_export]
+macro_rules! hot_reload_plugin {
+    ($plugin:expr) => {
+        #[no_mangle]
+        pub fn bevy_hot_reload_plugin() -> ::std::boxed::Box<dyn $crate::hot_reload::__Plugin> {
+            ::std::boxed::Box::new($plugin)
+        }
+    };
+}
+
+pub use crate::hot_reload_plugin;
+
+/// Loads a [`Plugin`] from a dynamic library, and reloads it whenever the library is rebuilt.
+///
+/// See the [module docs](self) for how to set up the library.
+///
+/// Add this plugin after the plugins the hot-reloaded plugin depends on, such as
+/// `DefaultPlugins`.
+///
+/// All of the systems the hot-reloaded plugin adds to a schedule run together, from a single
+/// exclusive system in the [`HotReloadSystems`] set of that schedule. Ordering constraints
+/// between them are kept, but any `before`, `after` or `in_set` constraints they have on the
+/// app's systems or sets are ignored. Order the [`HotReloadSystems`] set relative to the app's
+/// systems instead.
+pub struct HotReloadPlugin {
+    package: String,
+    directory: Option<PathBuf>,
+    profile: Option<String>,
+    watch: Vec<PathBuf>,
+    poll_interval: Duration,
+}
+
+impl HotReloadPlugin {
+    /// Creates a plugin that loads the library built by the `package` crate.
+    pub fn new(package: impl Into<String>) -> Self {
+        Self {
+            package: package.into(),
+            directory: None,
+            profile: None,
+            watch: Vec::new(),
+            poll_interval: Duration::from_millis(500),
+        }
+    }
+
+    /// Sets the directory the library is built to.
+    ///
+    /// Defaults to the directory of the running executable.
+    pub fn with_directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.directory = Some(directory.into());
+        self
+    }
+
+    /// Sets the Cargo profile the library is built with, such as `"release"`.
+    ///
+    /// The library must be built with the same profile as the app, or it can't be loaded.
+    /// Defaults to the profile of the directory the library is built to, as laid out by Cargo:
+    /// `dev` for `target/debug`, and the name of the directory otherwise, such as `release` for
+    /// `target/release`.
+    pub fn with_profile(mut self, profile: impl Into<String>) -> Self {
+        self.profile = Some(profile.into());
+        self
+    }
+
+    /// Rebuilds the library with `cargo build` when a file under `path` changes.
+    ///
+    /// Without any watched paths, the library is only reloaded when something else rebuilds
+    /// it, for example `cargo watch`.
+    pub fn watch(mut self, path: impl Into<PathBuf>) -> Self {
+        self.watch.push(path.into());
+        self
+    }
+
+    /// Sets how often the sources and the library are checked for changes.
+    ///
+    /// Defaults to 500 milliseconds.
+    pub fn with_poll_interval(mut self, poll_interval: Duration) -> Self {
+        self.poll_interval = poll_interval;
+        self
+    }
+
+    fn library_path(&self) -> PathBuf {
+        let directory = self.directory.clone().unwrap_or_else(|| {
+            std::env::current_exe()
+                .ok()
+                .and_then(|exe| exe.parent().map(Path::to_path_buf))
+                .unwrap_or_default()
+        });
+        let name = self.package.replace('-', "_");
+        directory.join(format!("{DLL_PREFIX}{name}{DLL_SUFFIX}"))
+    }
+
+    fn profile(&self, library: &Path) -> String {
+        if let Some(profile) = &self.profile {
+            return profile.clone();
+        }
+        profile_of_directory(library.parent().unwrap_or(library))
+    }
+}
+
+/// Returns the Cargo profile whose output is in `directory`, such as `target/release` or
+/// `target/debug/examples`.
+fn profile_of_directory(directory: &Path) -> String {
+    let directory = match directory.file_name() {
+        Some(name) if name == "examples" || name == "deps" => directory.parent(),
+        _ => Some(directory),
+    };
+    match directory
+        .and_then(Path::file_name)
+        .and_then(|name| name.to_str())
+    {
+        Some("debug") | None => "dev".into(),
+        Some(profile) => profile.into(),
+    }
+}
+
+impl Plugin for HotReloadPlugin {
+    fn build(&self, app: &mut App) {
+        let library = self.library_path();
+        let mut watcher = HotReloadWatcher {
+            package: self.package.clone(),
+            profile: self.profile(&library),
+            library,
+            watch: self.watch.clone(),
+            poll_interval: self.poll_interval,
+            last_poll: Instant::now(),
+            sources_modified: latest_modification(&self.watch),
+            library_modified: None,
+            build: None,
+        };
+
+        app.add_event::<HotReloaded>()
+            .init_resource::<HotReload>()
+            .init_schedule(CheckHotReload)
+            .add_systems(CheckHotReload, check_for_reload);
+        app.world_mut()
+            .resource_mut::<MainScheduleOrder>()
+            .insert_after(Last, CheckHotReload);
+
+        if watcher.library.exists() {
+            watcher.reload(app.world_mut());
+        } else {
+            info!("Building {}", watcher.package);
+            watcher.spawn_build();
+        }
+        app.insert_resource(watcher);
+    }
+}
+
+/// The schedule that checks for changes to the hot-reloaded library, and reloads it.
+///
+/// This runs after [`Last`].
+#[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct CheckHotReload;
+
+/// The set that runs the systems of the hot-reloaded plugin, in each schedule it adds systems
+/// to.
+#[derive(SystemSet, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct HotReloadSystems;
+
+/// Sent after a new version of the hot-reloaded plugin has been loaded.
+#[derive(Event, Clone, Debug)]
+pub struct HotReloaded {
+    /// The number of times the plugin has been loaded, starting at 1.
+    pub generation: u32,
+}
+
+/// Tracks what the hot-reloaded plugin added to the world, so it can be replaced.
+#[derive(Resource, Default)]
+pub struct HotReload {
+    generation: u32,
+    libraries: Vec<Library>,
+    copies: Vec<PathBuf>,
+    schedules: HashMap<InternedScheduleLabel, Schedule>,
+    runners: HashSet<InternedScheduleLabel>,
+    types: HashSet<TypeId>,
+    observers: Vec<Entity>,
+}
+
+impl HotReload {
+    /// The number of times the plugin has been loaded.
+    pub fn generation(&self) -> u32 {
+        self.generation
+    }
+}
+
+impl Drop for HotReload {
+    fn drop(&mut self) {
+        // Components and resources dropped after this resource may still run code from the
+        // libraries, so they are leaked instead of unloaded.
+        for library in self.libraries.drain(..) {
+            core::mem::forget(library);
+        }
+        for copy in &self.copies {
+            let _ = std::fs::remove_file(copy);
+        }
+    }
+}
+
+/// An error loading a hot-reloaded library.
+#[derive(Error, Debug)]
+pub enum HotReloadError {
+    /// The library couldn't be copied before loading it.
+    #[error("failed to copy {path:?}: {error}")]
+    Io {
+        /// The path of the library.
+        path: PathBuf,
+        /// The underlying error.
+        error: std::io::Error,
+    },
+    /// The library couldn't be loaded, or doesn't export a plugin.
+    #[error("failed to load {path:?}: {error}")]
+    Library {
+        /// The path of the library.
+        path: PathBuf,
+        /// The underlying error.
+        error: libloading::Error,
+    },
+}
+
+/// Loads the plugin exported by the library at `path`.
+///
+/// The library is copied to a temporary path first, so that it can be rebuilt while loaded.
+/// It is never unloaded, and the copy is removed when the [`HotReload`] resource is dropped.
+#[expect(
+    unsafe_code,
+    reason = "Loading a library and calling into it is unsafe."
+)]
+pub fn load_library(world: &mut World, path: &Path) -> Result<Box<dyn Plugin>, HotReloadError> {
+    let mut hot_reload = world.get_resource_or_init::<HotReload>();
+    let directory = std::env::temp_dir().join("bevy_hot_reload");
+    let name = path.file_stem().unwrap_or_default().to_string_lossy();
+    let copy = directory.join(format!(
+        "{name}-{}-{}{DLL_SUFFIX}",
+        std::process::id(),
+        hot_reload.libraries.len()
+    ));
+    if hot_reload.copies.is_empty() {
+        remove_stale_copies(&directory, &name);
+    }
+    std::fs::create_dir_all(&directory)
+        .and_then(|()| std::fs::copy(path, &copy))
+        .map_err(|error| HotReloadError::Io {
+            path: path.to_path_buf(),
+            error,
+        })?;
+
+    // SAFETY: Loading a library runs its initialization code. The library is expected to be
+    // built from the same Bevy version and compiler as the app, as documented.
+    let loaded = unsafe { Library::new(&copy) }.and_then(|library| {
+        // SAFETY: The symbol is generated by `hot_reload_plugin!` with this signature.
+        let plugin = unsafe {
+            library
+                .get::<fn() -> Box<dyn Plugin>>(HOT_RELOAD_SYMBOL)
+                .map(|create_plugin| create_plugin())
+        }?;
+        Ok((library, plugin))
+    });
+    match loaded {
+        Ok((library, plugin)) => {
+            hot_reload.libraries.push(library);
+            hot_reload.copies.push(copy);
+            Ok(plugin)
+        }
+        Err(error) => {
+            let _ = std::fs::remove_file(&copy);
+            Err(HotReloadError::Library {
+                path: path.to_path_buf(),
+                error,
+            })
+        }
+    }
+}
+
+/// Removes the copies of the library called `name` left behind by previous runs.
+///
+/// Copies still loaded by another running app can't be removed on some platforms, and are
+/// skipped.
+fn remove_stale_copies(directory: &Path, name: &str) {
+    let Ok(entries) = std::fs::read_dir(directory) else {
+        return;
+    };
+    let prefix = format!("{name}-");
+    let own_prefix = format!("{name}-{}-", std::process::id());
+    for entry in entries.flatten() {
+        let file_name = entry.file_name();
+        let file_name = file_name.to_string_lossy();
+        if file_name.starts_with(&prefix) && !file_name.starts_with(&own_prefix) {
+            let _ = std::fs::remove_file(entry.path());
+        }
+    }
+}
+
+/// Replaces the currently loaded hot-reloaded plugin, if any, with `plugin`.
+///
+/// The systems and observers added by the previous plugin are removed, and components and
+/// resources of the types it registered are serialized, removed, and restored after `plugin`
+/// has been built.
+pub fn load_plugin(world: &mut World, plugin: Box<dyn Plugin>) {
+    world.init_resource::<HotReload>();
+    world.resource_scope(|world, mut hot_reload: Mut<HotReload>| {
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let state = take_state(world, &hot_reload, &registry.read());
+
+        for observer in hot_reload.observers.drain(..) {
+            if let Ok(entity) = world.get_entity_mut(observer) {
+                entity.despawn();
+            }
+        }
+        hot_reload.schedules.clear();
+
+        let observers_before: HashSet<Entity> = observers(world).into_iter().collect();
+        let plugin_registry = AppTypeRegistry::default();
+        let schedules = {
+            let mut app = App::empty();
+            core::mem::swap(app.world_mut(), world);
+            let real_schedules = app.world_mut().remove_resource::<Schedules>().unwrap();
+            app.insert_resource(Schedules::default())
+                .insert_resource(plugin_registry.clone());
+
+            plugin.build(&mut app);
+            app.finish();
+            plugin.finish(&mut app);
+            app.cleanup();
+            plugin.cleanup(&mut app);
+
+            let schedules = app.world_mut().remove_resource::<Schedules>().unwrap();
+            app.insert_resource(real_schedules)
+                .insert_resource(registry.clone());
+            core::mem::swap(app.world_mut(), world);
+            schedules
+        };
+
+        {
+            let mut registry = registry.write();
+            for registration in plugin_registry.read().iter() {
+                let type_id = registration.type_id();
+                if !registry.contains(type_id) || hot_reload.types.contains(&type_id) {
+                    registry.overwrite_registration(registration.clone());
+                    hot_reload.types.insert(type_id);
+                }
+            }
+        }
+
+        hot_reload.observers = observers(world)
+            .into_iter()
+            .filter(|entity| !observers_before.contains(entity))
+            .collect();
+
+        let mut schedules = schedules;
+        let labels: Vec<_> = schedules
+            .iter()
+            .map(|(_, schedule)| schedule.label())
+            .collect();
+        for label in labels {
+            let schedule = schedules.remove(label).unwrap();
+            if hot_reload.runners.insert(label) {
+                world.resource_mut::<Schedules>().add_systems(
+                    label,
+                    (move |world: &mut World| run_schedule(world, label)).in_set(HotReloadSystems),
+                );
+            }
+            hot_reload.schedules.insert(label, schedule);
+        }
+
+        restore_state(world, state, &registry.read());
+
+        hot_reload.generation += 1;
+        let generation = hot_reload.generation;
+        if world.contains_resource::<Events<HotReloaded>>() {
+            world.send_event(HotReloaded { generation });
+        }
+    });
+}
+
+fn observers(world: &mut World) -> Vec<Entity> {
+    world
+        .query_filtered::<Entity, With<Observer>>()
+        .iter(world)
+        .collect()
+}
+
+fn run_schedule(world: &mut World, label: InternedScheduleLabel) {
+    world.resource_scope(|world, mut hot_reload: Mut<HotReload>| {
+        if let Some(schedule) = hot_reload.schedules.get_mut(&label) {
+            schedule.run(world);
+        }
+    });
+}
+
+/// Serialized components and resources of hot-reloaded types.
+#[derive(Default)]
+struct State {
+    resources: Vec<(String, String)>,
+    components: Vec<(Entity, String, String)>,
+}
+
+fn serialize(value: &dyn PartialReflect, registry: &TypeRegistry) -> Option<String> {
+    ron::to_string(&TypedReflectSerializer::new(value, registry))
+        .inspect_err(|error| {
+            warn!(
+                "Unable to preserve {} across the reload: {error}",
+                value.reflect_type_path()
+            );
+        })
+        .ok()
+}
+
+fn take_state(world: &mut World, hot_reload: &HotReload, registry: &TypeRegistry) -> State {
+    let mut state = State::default();
+    for &type_id in &hot_reload.types {
+        let Some(registration) = registry.get(type_id) else {
+            continue;
+        };
+        let type_path = registration.type_info().type_path();
+
+        if let Some(reflect_resource) = registration.data::<ReflectResource>() {
+            if let Some(resource) = reflect_resource.reflect(world) {
+                if let Some(serialized) = serialize(resource.as_partial_reflect(), registry) {
+                    state.resources.push((type_path.to_string(), serialized));
+                }
+                reflect_resource.remove(world);
+            }
+        }
+
+        if let Some(reflect_component) = registration.data::<ReflectComponent>() {
+            let entities: Vec<Entity> = world
+                .iter_entities()
+                .filter(|entity| entity.contains_type_id(type_id))
+                .map(|entity| entity.id())
+                .collect();
+            for entity in entities {
+                let mut entity = world.entity_mut(entity);
+                if let Some(component) = reflect_component.reflect(entity.as_readonly()) {
+                    if let Some(serialized) = serialize(component.as_partial_reflect(), registry) {
+                        state
+                            .components
+                            .push((entity.id(), type_path.to_string(), serialized));
+                    }
+                }
+                reflect_component.remove(&mut entity);
+            }
+        }
+    }
+    state
+}
+
+fn deserialize(
+    type_path: &str,
+    serialized: &str,
+    registry: &TypeRegistry,
+) -> Option<Box<dyn PartialReflect>> {
+    let Some(registration) = registry.get_with_type_path(type_path) else {
+        warn!("Unable to restore {type_path} after the reload: the type is no longer registered");
+        return None;
+    };
+    ron::Deserializer::from_str(serialized)
+        .map_err(|error| error.to_string())
+        .and_then(|mut deserializer| {
+            TypedReflectDeserializer::new(registration, registry)
+                .deserialize(&mut deserializer)
+                .map_err(|error| error.to_string())
+        })
+        .inspect_err(|error| warn!("Unable to restore {type_path} after the reload: {error}"))
+        .ok()
+}
+
+fn restore_state(world: &mut World, state: State, registry: &TypeRegistry) {
+    for (type_path, serialized) in state.resources {
+        let Some(value) = deserialize(&type_path, &serialized, registry) else {
+            continue;
+        };
+        if let Some(reflect_resource) = registry
+            .get_with_type_path(&type_path)
+            .and_then(|registration| registration.data::<ReflectResource>())
+        {
+            reflect_resource.insert(world, value.as_ref(), registry);
+        }
+    }
+
+    for (entity, type_path, serialized) in state.components {
+        let Some(value) = deserialize(&type_path, &serialized, registry) else {
+            continue;
+        };
+        let Ok(mut entity) = world.get_entity_mut(entity) else {
+            continue;
+        };
+        if let Some(reflect_component) = registry
+            .get_with_type_path(&type_path)
+            .and_then(|registration| registration.data::<ReflectComponent>())
+        {
+            reflect_component.insert(&mut entity, value.as_ref(), registry);
+        }
+    }
+}
+
+/// Watches the sources and the library of the [`HotReloadPlugin`].
+#[derive(Resource)]
+struct HotReloadWatcher {
+    package: String,
+    profile: String,
+    library: PathBuf,
+    watch: Vec<PathBuf>,
+    poll_interval: Duration,
+    last_poll: Instant,
+    sources_modified: Option<SystemTime>,
+    library_modified: Option<SystemTime>,
+    build: Option<Child>,
+}
+
+impl HotReloadWatcher {
+    fn build_command(&self) -> Command {
+        let mut command = Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+        command.args(["build", "-p", &self.package, "--profile", &self.profile]);
+        command
+    }
+
+    /// Starts building the library in the background. [`check_for_reload`] waits for the build
+    /// and loads the new library once it finishes.
+    fn spawn_build(&mut self) {
+        match self.build_command().spawn() {
+            Ok(build) => self.build = Some(build),
+            Err(error) => error!("Unable to build {}: {error}", self.package),
+        }
+    }
+
+    fn reload(&mut self, world: &mut World) {
+        self.library_modified = modified(&self.library);
+        match load_library(world, &self.library) {
+            Ok(plugin) => {
+                load_plugin(world, plugin);
+                info!("Loaded {}", self.library.display());
+            }
+            Err(error) => error!("{error}"),
+        }
+    }
+}
+
+fn check_for_reload(world: &mut World) {
+    world.resource_scope(|world, mut watcher: Mut<HotReloadWatcher>| {
+        if watcher.last_poll.elapsed() < watcher.poll_interval {
+            return;
+        }
+        watcher.last_poll = Instant::now();
+
+        if let Some(build) = watcher.build.as_mut() {
+            match build.try_wait() {
+                Ok(None) => return,
+                Ok(Some(status)) if !status.success() => {
+                    warn!("Building {} failed: {status}", watcher.package);
+                }
+                Ok(Some(_)) => {}
+                Err(error) => error!(
+                    "Unable to wait for the build of {}: {error}",
+                    watcher.package
+                ),
+            }
+            watcher.build = None;
+        }
+
+        let sources_modified = latest_modification(&watcher.watch);
+        if sources_modified > watcher.sources_modified {
+            watcher.sources_modified = sources_modified;
+            info!("Rebuilding {}", watcher.package);
+            watcher.spawn_build();
+            return;
+        }
+
+        if modified(&watcher.library) > watcher.library_modified {
+            watcher.reload(world);
+        }
+    });
+}
+
+fn modified(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/// Returns the latest modification time of the files under `paths`.
+fn latest_modification(paths: &[PathBuf]) -> Option<SystemTime> {
+    fn visit(path: &Path, latest: &mut Option<SystemTime>) {
+        if path.is_dir() {
+            let Ok(entries) = std::fs::read_dir(path) else {
+                return;
+            };
+            for entry in entries.flatten() {
+                visit(&entry.path(), latest);
+            }
+        } else {
+            *latest = (*latest).max(modified(path));
+        }
+    }
+
+    let mut latest = None;
+    for path in paths {
+        visit(path, &mut latest);
+    }
+    latest
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{load_library, load_plugin, profile_of_directory, HotReload};
+    use bevy_internal::{
+        app::{App, Plugin, Update},
+        ecs::{name::Name, prelude::*, reflect::ReflectResource},
+        reflect::{prelude::ReflectDefault, Reflect},
+    };
+    use std::path::Path;
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource, Default)]
+    struct Score(u32);
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component, Default)]
+    struct Health(u32);
+
+    struct Version<const POINTS: u32>;
+
+    impl<const POINTS: u32> Plugin for Version<POINTS> {
+        fn build(&self, app: &mut App) {
+            app.register_type::<Score>()
+                .register_type::<Health>()
+                .init_resource::<Score>()
+                .add_systems(Update, |mut score: ResMut<Score>| score.0 += POINTS);
+        }
+    }
+
+    #[test]
+    fn reloading_replaces_systems_and_preserves_state() {
+        let mut app = App::new();
+        load_plugin(app.world_mut(), Box::new(Version::<1>));
+        let player = app.world_mut().spawn(Health(5)).id();
+
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<Score>().0, 2);
+
+        load_plugin(app.world_mut(), Box::new(Version::<10>));
+        assert_eq!(app.world().resource::<HotReload>().generation(), 2);
+        assert_eq!(app.world().resource::<Score>().0, 2);
+        assert_eq!(app.world().get::<Health>(player), Some(&Health(5)));
+
+        app.update();
+        assert_eq!(app.world().resource::<Score>().0, 12);
+    }
+
+    #[test]
+    fn profile_is_inferred_from_the_target_directory() {
+        assert_eq!(profile_of_directory(Path::new("target/debug")), "dev");
+        assert_eq!(
+            profile_of_directory(Path::new("target/debug/examples")),
+            "dev"
+        );
+        assert_eq!(profile_of_directory(Path::new("target/release")), "release");
+        assert_eq!(
+            profile_of_directory(Path::new("target/profiling/deps")),
+            "profiling"
+        );
+    }
+
+    /// Loads the library built from `hot_reload_fixture`, at the path given by the
+    /// `BEVY_HOT_RELOAD_FIXTURE` environment variable:
+    ///
+    /// ```sh
+    /// cargo build -p bevy_dylib_hot_reload_fixture
+    /// BEVY_HOT_RELOAD_FIXTURE=target/debug/libbevy_dylib_hot_reload_fixture.so \
+    ///     cargo test -p bevy_dylib --features hot_reload -- --ignored
+    /// ```
+    #[test]
+    #[ignore = "requires the hot_reload_fixture library to be built, see the doc comment"]
+    fn loads_plugin_from_library() {
+        let path = std::env::var_os("BEVY_HOT_RELOAD_FIXTURE")
+            .expect("BEVY_HOT_RELOAD_FIXTURE must be set to the path of the fixture library");
+
+        let mut app = App::new();
+        for _ in 0..2 {
+            let plugin = load_library(app.world_mut(), Path::new(&path)).unwrap();
+            load_plugin(app.world_mut(), plugin);
+        }
+        app.update();
+
+        let mut names = app.world_mut().query::<&Name>();
+        let names: Vec<_> = names.iter(app.world()).map(Name::as_str).collect();
+        assert_eq!(names, ["hot_reload_fixture"]);
+
+        let copies = app.world().resource::<HotReload>().copies.clone();
+        assert_eq!(copies.len(), 2);
+        assert!(copies.iter().all(|copy| copy.exists()));
+        drop(app);
+        assert!(copies.iter().all(|copy| !copy.exists()));
+    }
+}
@@ -53,6 +53,9 @@
 //! use bevy_dylib;
 //! ```
 
+#[cfg(feature = "hot_reload")]
+pub mod hot_reload;
+
 // Force linking of the main bevy crate
 #[expect(
     unused_imports,
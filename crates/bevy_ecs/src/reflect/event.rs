@@ -0,0 +1,218 @@
+//! Definitions for [`Event`] reflection.
+//!
+//! # Architecture
+//!
+//! See the module doc for [`crate::reflect::component`].
+
+use crate::{
+    entity::Entity,
+    event::{Event, Events},
+    world::World,
+};
+use alloc::vec::Vec;
+use bevy_reflect::{FromReflect, FromType, PartialReflect, TypePath, TypeRegistry};
+
+use super::from_reflect_with_fallback;
+
+/// A struct used to send and trigger reflected [`Event`]s of a type.
+///
+/// A [`ReflectEvent`] for type `T` can be obtained via
+/// [`bevy_reflect::TypeRegistration::data`].
+#[derive(Clone)]
+pub struct ReflectEvent(ReflectEventFns);
+
+/// The raw function pointers needed to make up a [`ReflectEvent`].
+///
+/// This is used when creating custom implementations of [`ReflectEvent`] with
+/// [`ReflectEvent::new()`].
+///
+/// > **Note:**
+/// > Creating custom implementations of [`ReflectEvent`] is an advanced feature that most users
+/// > will not need.
+/// > Usually a [`ReflectEvent`] is created for a type by deriving [`Reflect`](bevy_reflect::Reflect)
+/// > and adding the `#[reflect(Event)]` attribute.
+/// > After adding the event to the [`TypeRegistry`],
+/// > its [`ReflectEvent`] can then be retrieved when needed.
+#[derive(Clone)]
+pub struct ReflectEventFns {
+    /// Function pointer implementing [`ReflectEvent::send()`].
+    pub send: fn(&mut World, &dyn PartialReflect, &TypeRegistry) -> bool,
+    /// Function pointer implementing [`ReflectEvent::trigger()`].
+    pub trigger: fn(&mut World, &dyn PartialReflect, &TypeRegistry),
+    /// Function pointer implementing [`ReflectEvent::trigger_targets()`].
+    pub trigger_targets: fn(&mut World, &dyn PartialReflect, Vec<Entity>, &TypeRegistry),
+}
+
+impl ReflectEventFns {
+    /// Get the default set of [`ReflectEventFns`] for a specific event type using its
+    /// [`FromType`] implementation.
+    ///
+    /// This is useful if you want to start with the default implementation before overriding some
+    /// of the functions to create a custom implementation.
+    pub fn new<T: Event + FromReflect + TypePath>() -> Self {
+        <ReflectEvent as FromType<T>>::from_type().0
+    }
+}
+
+impl ReflectEvent {
+    /// Send a reflected [`Event`] into its [`Events`] resource like [`send_event()`](World::send_event).
+    ///
+    /// Returns `false` if the [`Events`] resource for this event type doesn't exist,
+    /// i.e. if the event was never added to the app.
+    pub fn send(
+        &self,
+        world: &mut World,
+        event: &dyn PartialReflect,
+        registry: &TypeRegistry,
+    ) -> bool {
+        (self.0.send)(world, event, registry)
+    }
+
+    /// Trigger a reflected [`Event`] without any targets like [`trigger()`](World::trigger),
+    /// running any global observers watching for it.
+    pub fn trigger(&self, world: &mut World, event: &dyn PartialReflect, registry: &TypeRegistry) {
+        (self.0.trigger)(world, event, registry);
+    }
+
+    /// Trigger a reflected [`Event`] for the given entities like
+    /// [`trigger_targets()`](World::trigger_targets), running any observers watching for it.
+    pub fn trigger_targets(
+        &self,
+        world: &mut World,
+        event: &dyn PartialReflect,
+        targets: Vec<Entity>,
+        registry: &TypeRegistry,
+    ) {
+        (self.0.trigger_targets)(world, event, targets, registry);
+    }
+
+    /// Create a custom implementation of [`ReflectEvent`].
+    ///
+    /// This is an advanced feature,
+    /// useful for scripting implementations,
+    /// that should not be used by most users
+    /// unless you know what you are doing.
+    ///
+    /// Usually you should derive [`Reflect`](bevy_reflect::Reflect) and add the
+    /// `#[reflect(Event)]` attribute to generate a [`ReflectEvent`] implementation automatically.
+    ///
+    /// See [`ReflectEventFns`] for more information.
+    pub fn new(fns: ReflectEventFns) -> Self {
+        Self(fns)
+    }
+
+    /// The underlying function pointers implementing methods on `ReflectEvent`.
+    ///
+    /// This is useful when you want to keep track locally of an individual
+    /// function pointer.
+    pub fn fn_pointers(&self) -> &ReflectEventFns {
+        &self.0
+    }
+}
+
+impl<E: Event + FromReflect + TypePath> FromType<E> for ReflectEvent {
+    fn from_type() -> Self {
+        ReflectEvent(ReflectEventFns {
+            send: |world, reflected_event, registry| {
+                if !world.contains_resource::<Events<E>>() {
+                    return false;
+                }
+                let event = from_reflect_with_fallback::<E>(reflected_event, world, registry);
+                world.send_event(event).is_some()
+            },
+            trigger: |world, reflected_event, registry| {
+                let event = from_reflect_with_fallback::<E>(reflected_event, world, registry);
+                world.trigger(event);
+            },
+            trigger_targets: |world, reflected_event, targets, registry| {
+                let event = from_reflect_with_fallback::<E>(reflected_event, world, registry);
+                world.trigger_targets(event, targets);
+            },
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as bevy_ecs,
+        event::{Event, EventReader, Events},
+        observer::Trigger,
+        reflect::{AppTypeRegistry, ReflectEvent},
+        resource::Resource,
+        system::{ResMut, RunSystemOnce},
+        world::World,
+    };
+    use bevy_reflect::{DynamicStruct, Reflect};
+
+    #[derive(Event, Reflect, Clone, Debug, PartialEq)]
+    #[reflect(Event)]
+    struct Damage {
+        amount: u32,
+    }
+
+    #[derive(Resource, Default)]
+    struct Received(u32);
+
+    fn dynamic_damage(amount: u32) -> DynamicStruct {
+        let mut event = DynamicStruct::default();
+        event.insert("amount", amount);
+        event
+    }
+
+    #[test]
+    fn send_reflected_event() {
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        registry.write().register::<Damage>();
+        let registry = registry.read();
+        let reflect_event = registry
+            .get_type_data::<ReflectEvent>(core::any::TypeId::of::<Damage>())
+            .unwrap();
+
+        assert!(!reflect_event.send(&mut world, &dynamic_damage(3), &registry));
+
+        world.init_resource::<Events<Damage>>();
+        assert!(reflect_event.send(&mut world, &dynamic_damage(3), &registry));
+
+        let received = world
+            .run_system_once(|mut reader: EventReader<Damage>| {
+                reader.read().cloned().collect::<alloc::vec::Vec<_>>()
+            })
+            .unwrap();
+        assert_eq!(received, [Damage { amount: 3 }]);
+    }
+
+    #[test]
+    fn trigger_reflected_event() {
+        let mut world = World::new();
+        world.init_resource::<Received>();
+        let registry = AppTypeRegistry::default();
+        registry.write().register::<Damage>();
+        let registry = registry.read();
+        let reflect_event = registry
+            .get_type_data::<ReflectEvent>(core::any::TypeId::of::<Damage>())
+            .unwrap();
+
+        let entity = world
+            .spawn_empty()
+            .observe(|trigger: Trigger<Damage>, mut received: ResMut<Received>| {
+                received.0 += trigger.event().amount;
+            })
+            .id();
+        world.flush();
+
+        reflect_event.trigger(&mut world, &dynamic_damage(1), &registry);
+        world.flush();
+        assert_eq!(world.resource::<Received>().0, 0);
+
+        reflect_event.trigger_targets(
+            &mut world,
+            &dynamic_damage(2),
+            alloc::vec![entity],
+            &registry,
+        );
+        world.flush();
+        assert_eq!(world.resource::<Received>().0, 2);
+    }
+}
@@ -332,6 +332,7 @@ impl ImageFormat {
 }
 
 #[derive(Asset, Debug, Clone)]
+#[asset(size_in_bytes = Image::memory_size)]
 #[cfg_attr(
     feature = "bevy_reflect",
     derive(Reflect),
@@ -813,6 +814,11 @@ impl Image {
         value
     }
 
+    /// Returns the memory used by this image, including its pixel data.
+    fn memory_size(&self) -> usize {
+        size_of::<Self>() + self.data.len()
+    }
+
     /// Returns the width of a 2D image.
     #[inline]
     pub fn width(&self) -> u32 {
@@ -53,6 +53,11 @@ pub use tracing::{
 pub use tracing_subscriber;
 
 use bevy_app::{App, Plugin};
+use bevy_reflect::{
+    reflect_remote, PartialReflect, Reflect, ReflectDeserialize, ReflectSerialize, TypeRegistry,
+};
+use core::str::FromStr;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 use tracing_log::LogTracer;
 use tracing_subscriber::{
     filter::{FromEnvError, ParseError},
@@ -218,12 +223,18 @@ pub(crate) struct FlushGuard(SyncCell<tracing_chrome::FlushGuard>);
 /// [dependencies]
 /// log = { version = "0.4", features = ["max_level_debug", "release_max_level_warn"] }
 /// ```
+#[derive(Reflect)]
+#[reflect(from_reflect = false)]
 pub struct LogPlugin {
     /// Filters logs using the [`EnvFilter`] format
     pub filter: String,
 
     /// Filters out logs that are "less than" the given level.
     /// This can be further filtered using the `filter` setting.
+    ///
+    /// The level is reflected as its name, so an `AppConfig` can override it with e.g.
+    /// `level: "debug"`.
+    #[reflect(remote = LevelWrapper)]
     pub level: Level,
 
     /// Optionally add an extra [`Layer`] to the tracing subscriber
@@ -236,9 +247,32 @@ pub struct LogPlugin {
     /// [`Subscriber`](tracing::Subscriber) and the [`App`].
     ///
     /// Please see the `examples/log_layers.rs` for a complete example.
+    #[reflect(ignore)]
     pub custom_layer: fn(app: &mut App) -> Option<BoxedLayer>,
 }
 
+/// Reflects a [`Level`] as an opaque value, serialized as the name of the level.
+#[reflect_remote(Level)]
+#[derive(Clone)]
+#[reflect(opaque)]
+#[reflect(Serialize, Deserialize)]
+struct LevelWrapper;
+
+impl Serialize for LevelWrapper {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.0.as_str())
+    }
+}
+
+impl<'de> Deserialize<'de> for LevelWrapper {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        Level::from_str(&name)
+            .map(LevelWrapper)
+            .map_err(|_| de::Error::custom(format!("unknown log level `{name}`")))
+    }
+}
+
 /// A boxed [`Layer`] that can be used with [`LogPlugin`].
 pub type BoxedLayer = Box<dyn Layer<Registry> + Send + Sync + 'static>;
 
@@ -376,4 +410,51 @@ impl Plugin for LogPlugin {
             (false, false) => (),
         }
     }
+
+    fn settings_mut(&mut self) -> Option<&mut dyn PartialReflect> {
+        Some(self)
+    }
+
+    fn register_settings(&self, registry: &mut TypeRegistry) {
+        registry.register::<Self>();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_app::AppConfig;
+
+    #[test]
+    fn level_can_be_overridden() {
+        let mut registry = TypeRegistry::default();
+        registry.register::<LogPlugin>();
+
+        let config = AppConfig::new()
+            .with_ron(r#"{ "bevy_log::LogPlugin": (level: "debug", filter: "wgpu=warn") }"#)
+            .unwrap();
+        let mut plugin = LogPlugin::default();
+        assert!(config.apply(&mut plugin, &registry).unwrap());
+        assert_eq!(plugin.level, Level::DEBUG);
+        assert_eq!(plugin.filter, "wgpu=warn");
+
+        let config = AppConfig::new()
+            .with_ron(r#"{ "bevy_log::LogPlugin": (level: "loud") }"#)
+            .unwrap();
+        assert!(config.apply(&mut plugin, &registry).is_err());
+        assert_eq!(plugin.level, Level::DEBUG);
+    }
+
+    #[test]
+    fn level_is_overridden_before_the_plugin_is_built() {
+        let mut app = App::new();
+        app.insert_resource(
+            AppConfig::new()
+                .with_ron(r#"{ "bevy_log::LogPlugin": (level: "debug") }"#)
+                .unwrap(),
+        )
+        .add_plugins(LogPlugin::default());
+
+        assert_eq!(app.get_added_plugins::<LogPlugin>()[0].level, Level::DEBUG);
+    }
 }
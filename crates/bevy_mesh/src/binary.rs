@@ -0,0 +1,668 @@
+use super::{Indices, Mesh, MeshVertexAttribute, VertexAttributeValues};
+use alloc::{boxed::Box, string::String, vec, vec::Vec};
+use bevy_asset::{
+    io::{Reader, Writer},
+    saver::{AssetSaver, SavedAsset},
+    AssetLoader, AsyncWriteExt, LoadContext, RenderAssetUsages,
+};
+use bevy_image::Image;
+use bevy_platform_support::collections::HashMap;
+use bytemuck::Pod;
+use thiserror::Error;
+use wgpu_types::{Extent3d, PrimitiveTopology, TextureDimension, TextureFormat, VertexFormat};
+
+/// Unique identifier for the binary [`Mesh`] asset format.
+const MESH_ASSET_MAGIC: u64 = 0x6873_656d_7976_6562;
+
+/// The current version of the binary [`Mesh`] asset format.
+pub const MESH_ASSET_VERSION: u64 = 1;
+
+/// The label of the morph target image of meshes loaded by [`MeshLoader`].
+pub const MORPH_TARGETS_LABEL: &str = "MorphTargets";
+
+/// An [`AssetSaver`] for `.mesh` [`Mesh`] assets, in a compact binary format that loads much faster than formats
+/// that need to be parsed, such as glTF.
+///
+/// The vertex attributes, indices, morph target names and morph targets of the mesh are saved. Skinned meshes are saved
+/// with their [joint indices](Mesh::ATTRIBUTE_JOINT_INDEX) and [joint weights](Mesh::ATTRIBUTE_JOINT_WEIGHT).
+/// The [morph target image](crate::morph::MorphTargetImage) must be a labeled asset of the saved mesh.
+pub struct MeshSaver;
+
+impl AssetSaver for MeshSaver {
+    type Asset = Mesh;
+    type Settings = ();
+    type OutputLoader = MeshLoader;
+    type Error = MeshSaveOrLoadError;
+
+    async fn save(
+        &self,
+        writer: &mut Writer,
+        asset: SavedAsset<'_, Mesh>,
+        _settings: &(),
+    ) -> Result<(), MeshSaveOrLoadError> {
+        let morph_targets = match asset.morph_targets() {
+            Some(handle) => {
+                let label = asset
+                    .iter_labels()
+                    .find(|label| asset.get_handle::<_, Image>(*label).as_ref() == Some(handle))
+                    .ok_or(MeshSaveOrLoadError::MissingMorphTargets)?;
+                Some(
+                    asset
+                        .get_labeled::<Image, _>(label)
+                        .ok_or(MeshSaveOrLoadError::MissingMorphTargets)?
+                        .get(),
+                )
+            }
+            None => None,
+        };
+
+        let mut bytes = Vec::new();
+        write_mesh(&asset, morph_targets, &mut bytes)?;
+        writer.write_all(&bytes).await?;
+
+        Ok(())
+    }
+}
+
+/// An [`AssetLoader`] for `.mesh` [`Mesh`] assets saved by [`MeshSaver`].
+///
+/// The morph target image of the mesh is loaded as a labeled asset, with the [`MORPH_TARGETS_LABEL`] label.
+#[derive(Default)]
+pub struct MeshLoader {
+    custom_vertex_attributes: HashMap<Box<str>, MeshVertexAttribute>,
+}
+
+impl MeshLoader {
+    /// Registers a custom vertex attribute, so meshes with that attribute can be loaded.
+    ///
+    /// The built-in attributes of [`Mesh`] are always supported.
+    pub fn with_custom_vertex_attribute(mut self, attribute: MeshVertexAttribute) -> Self {
+        self.custom_vertex_attributes
+            .insert(attribute.name.into(), attribute);
+        self
+    }
+}
+
+impl AssetLoader for MeshLoader {
+    type Asset = Mesh;
+    type Settings = ();
+    type Error = MeshSaveOrLoadError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Mesh, MeshSaveOrLoadError> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+
+        let (mut mesh, morph_targets) =
+            read_mesh(&mut bytes.as_slice(), &self.custom_vertex_attributes)?;
+        if let Some(morph_targets) = morph_targets {
+            let handle = load_context.add_labeled_asset(MORPH_TARGETS_LABEL.into(), morph_targets);
+            mesh.set_morph_targets(handle);
+        }
+
+        Ok(mesh)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["mesh"]
+    }
+}
+
+/// An error that occurs when saving a [`Mesh`] with [`MeshSaver`] or loading it with [`MeshLoader`].
+#[derive(Error, Debug)]
+pub enum MeshSaveOrLoadError {
+    /// The loaded file does not start with the magic number of the binary [`Mesh`] format.
+    #[error("file was not a Mesh asset")]
+    WrongFileType,
+    /// The loaded file was saved in a different version of the binary [`Mesh`] format.
+    #[error("expected asset version {MESH_ASSET_VERSION} but found version {found}")]
+    WrongVersion {
+        /// The version of the loaded file.
+        found: u64,
+    },
+    /// The saved mesh has morph targets, but its morph target image is not one of its labeled assets.
+    #[error(
+        "the mesh has morph targets, but the morph target image is not a labeled asset of the mesh"
+    )]
+    MissingMorphTargets,
+    /// The loaded mesh has a vertex attribute that is neither built in nor registered with
+    /// [`MeshLoader::with_custom_vertex_attribute`].
+    #[error("unknown vertex attribute {name} ({id}), register it with `MeshLoader::with_custom_vertex_attribute`")]
+    UnknownVertexAttribute {
+        /// The name of the attribute.
+        name: String,
+        /// The id of the attribute.
+        id: u64,
+    },
+    /// The values of a loaded vertex attribute don't have the format of the attribute.
+    #[error("vertex attribute {name} has format {found:?}, but {expected:?} was expected")]
+    WrongVertexAttributeFormat {
+        /// The name of the attribute.
+        name: &'static str,
+        /// The format of the attribute.
+        expected: VertexFormat,
+        /// The format of the loaded values.
+        found: VertexFormat,
+    },
+    /// The mesh could not be saved, or the loaded file is malformed.
+    #[error("asset data is invalid: {0}")]
+    InvalidData(&'static str),
+    /// The mesh could not be written, or the file could not be read.
+    #[error("failed to read or write asset data")]
+    Io(#[from] std::io::Error),
+}
+
+/// The built-in attributes of [`Mesh`], which are always supported by [`MeshLoader`].
+const BUILTIN_VERTEX_ATTRIBUTES: [MeshVertexAttribute; 8] = [
+    Mesh::ATTRIBUTE_POSITION,
+    Mesh::ATTRIBUTE_NORMAL,
+    Mesh::ATTRIBUTE_UV_0,
+    Mesh::ATTRIBUTE_UV_1,
+    Mesh::ATTRIBUTE_TANGENT,
+    Mesh::ATTRIBUTE_COLOR,
+    Mesh::ATTRIBUTE_JOINT_WEIGHT,
+    Mesh::ATTRIBUTE_JOINT_INDEX,
+];
+
+macro_rules! vertex_attribute_values_tags {
+    ($($variant:ident = $tag:literal,)*) => {
+        fn vertex_attribute_values_tag(values: &VertexAttributeValues) -> u8 {
+            match values {
+                $(VertexAttributeValues::$variant(_) => $tag,)*
+            }
+        }
+
+        fn read_vertex_attribute_values(
+            tag: u8,
+            len: usize,
+            reader: &mut &[u8],
+        ) -> Result<VertexAttributeValues, MeshSaveOrLoadError> {
+            Ok(match tag {
+                $($tag => VertexAttributeValues::$variant(read_vec(len, reader)?),)*
+                _ => return Err(MeshSaveOrLoadError::InvalidData("unknown vertex attribute format")),
+            })
+        }
+    };
+}
+
+vertex_attribute_values_tags! {
+    Float32 = 0,
+    Sint32 = 1,
+    Uint32 = 2,
+    Float32x2 = 3,
+    Sint32x2 = 4,
+    Uint32x2 = 5,
+    Float32x3 = 6,
+    Sint32x3 = 7,
+    Uint32x3 = 8,
+    Float32x4 = 9,
+    Sint32x4 = 10,
+    Uint32x4 = 11,
+    Sint16x2 = 12,
+    Snorm16x2 = 13,
+    Uint16x2 = 14,
+    Unorm16x2 = 15,
+    Sint16x4 = 16,
+    Snorm16x4 = 17,
+    Uint16x4 = 18,
+    Unorm16x4 = 19,
+    Sint8x2 = 20,
+    Snorm8x2 = 21,
+    Uint8x2 = 22,
+    Unorm8x2 = 23,
+    Sint8x4 = 24,
+    Snorm8x4 = 25,
+    Uint8x4 = 26,
+    Unorm8x4 = 27,
+}
+
+fn write_mesh(
+    mesh: &Mesh,
+    morph_targets: Option<&Image>,
+    bytes: &mut Vec<u8>,
+) -> Result<(), MeshSaveOrLoadError> {
+    bytes.extend_from_slice(&MESH_ASSET_MAGIC.to_le_bytes());
+    bytes.extend_from_slice(&MESH_ASSET_VERSION.to_le_bytes());
+    bytes.push(match mesh.primitive_topology() {
+        PrimitiveTopology::PointList => 0,
+        PrimitiveTopology::LineList => 1,
+        PrimitiveTopology::LineStrip => 2,
+        PrimitiveTopology::TriangleList => 3,
+        PrimitiveTopology::TriangleStrip => 4,
+    });
+    bytes.push(mesh.asset_usage.bits());
+
+    let attributes: Vec<_> = mesh.attributes().collect();
+    write_u64(attributes.len() as u64, bytes);
+    for (attribute, values) in attributes {
+        write_u64(attribute.id.0, bytes);
+        write_str(attribute.name, bytes);
+        bytes.push(vertex_attribute_values_tag(values));
+        write_u64(values.len() as u64, bytes);
+        bytes.extend_from_slice(values.get_bytes());
+    }
+
+    match mesh.indices() {
+        None => bytes.push(0),
+        Some(Indices::U16(indices)) => {
+            bytes.push(1);
+            write_slice(indices, bytes);
+        }
+        Some(Indices::U32(indices)) => {
+            bytes.push(2);
+            write_slice(indices, bytes);
+        }
+    }
+
+    match mesh.morph_target_names() {
+        None => bytes.push(0),
+        Some(names) => {
+            bytes.push(1);
+            write_u64(names.len() as u64, bytes);
+            for name in names {
+                write_str(name, bytes);
+            }
+        }
+    }
+
+    match morph_targets {
+        None => bytes.push(0),
+        Some(image) => {
+            if image.texture_descriptor.format != TextureFormat::R32Float
+                || image.texture_descriptor.dimension != TextureDimension::D3
+            {
+                return Err(MeshSaveOrLoadError::InvalidData(
+                    "morph target image is not a 3D R32Float image",
+                ));
+            }
+            bytes.push(1);
+            let size = image.texture_descriptor.size;
+            write_u64(size.width.into(), bytes);
+            write_u64(size.height.into(), bytes);
+            write_u64(size.depth_or_array_layers.into(), bytes);
+            bytes.push(image.asset_usage.bits());
+            write_slice(&image.data, bytes);
+        }
+    }
+
+    Ok(())
+}
+
+fn read_mesh(
+    reader: &mut &[u8],
+    custom_vertex_attributes: &HashMap<Box<str>, MeshVertexAttribute>,
+) -> Result<(Mesh, Option<Image>), MeshSaveOrLoadError> {
+    let magic = read_u64(reader).map_err(|_| MeshSaveOrLoadError::WrongFileType)?;
+    if magic != MESH_ASSET_MAGIC {
+        return Err(MeshSaveOrLoadError::WrongFileType);
+    }
+    let version = read_u64(reader)?;
+    if version != MESH_ASSET_VERSION {
+        return Err(MeshSaveOrLoadError::WrongVersion { found: version });
+    }
+
+    let primitive_topology = match read_u8(reader)? {
+        0 => PrimitiveTopology::PointList,
+        1 => PrimitiveTopology::LineList,
+        2 => PrimitiveTopology::LineStrip,
+        3 => PrimitiveTopology::TriangleList,
+        4 => PrimitiveTopology::TriangleStrip,
+        _ => {
+            return Err(MeshSaveOrLoadError::InvalidData(
+                "unknown primitive topology",
+            ))
+        }
+    };
+    let asset_usage = RenderAssetUsages::from_bits_truncate(read_u8(reader)?);
+    let mut mesh = Mesh::new(primitive_topology, asset_usage);
+
+    let attribute_count = read_u64(reader)?;
+    for _ in 0..attribute_count {
+        let id = read_u64(reader)?;
+        let name = read_string(reader)?;
+        let tag = read_u8(reader)?;
+        let len = read_len(reader)?;
+        let values = read_vertex_attribute_values(tag, len, reader)?;
+        let attribute = BUILTIN_VERTEX_ATTRIBUTES
+            .iter()
+            .find(|attribute| attribute.id.0 == id)
+            .or_else(|| {
+                custom_vertex_attributes
+                    .get(name.as_str())
+                    .filter(|attribute| attribute.id.0 == id)
+            })
+            .ok_or(MeshSaveOrLoadError::UnknownVertexAttribute { name, id })?;
+        let format = VertexFormat::from(&values);
+        if format != attribute.format {
+            return Err(MeshSaveOrLoadError::WrongVertexAttributeFormat {
+                name: attribute.name,
+                expected: attribute.format,
+                found: format,
+            });
+        }
+        mesh.insert_attribute(*attribute, values);
+    }
+
+    match read_u8(reader)? {
+        0 => {}
+        1 => {
+            let len = read_len(reader)?;
+            mesh.insert_indices(Indices::U16(read_vec(len, reader)?));
+        }
+        2 => {
+            let len = read_len(reader)?;
+            mesh.insert_indices(Indices::U32(read_vec(len, reader)?));
+        }
+        _ => return Err(MeshSaveOrLoadError::InvalidData("unknown index format")),
+    }
+
+    if read_u8(reader)? != 0 {
+        let count = read_u64(reader)?;
+        let names = (0..count)
+            .map(|_| read_string(reader))
+            .collect::<Result<_, _>>()?;
+        mesh.set_morph_target_names(names);
+    }
+
+    let morph_targets = if read_u8(reader)? != 0 {
+        let mut read_dimension = || {
+            u32::try_from(read_u64(reader)?)
+                .map_err(|_| MeshSaveOrLoadError::InvalidData("morph target image is too large"))
+        };
+        let size = Extent3d {
+            width: read_dimension()?,
+            height: read_dimension()?,
+            depth_or_array_layers: read_dimension()?,
+        };
+        let asset_usage = RenderAssetUsages::from_bits_truncate(read_u8(reader)?);
+        let len = read_len(reader)?;
+        let data = read_vec(len, reader)?;
+        let byte_len = (size.width as usize)
+            .checked_mul(size.height as usize)
+            .and_then(|len| len.checked_mul(size.depth_or_array_layers as usize))
+            .and_then(|len| len.checked_mul(size_of::<f32>()))
+            .ok_or(MeshSaveOrLoadError::InvalidData(
+                "morph target image is too large",
+            ))?;
+        if byte_len != data.len() {
+            return Err(MeshSaveOrLoadError::InvalidData(
+                "morph target image size does not match its data",
+            ));
+        }
+        Some(Image::new(
+            size,
+            TextureDimension::D3,
+            data,
+            TextureFormat::R32Float,
+            asset_usage,
+        ))
+    } else {
+        None
+    };
+
+    Ok((mesh, morph_targets))
+}
+
+fn write_u64(value: u64, bytes: &mut Vec<u8>) {
+    bytes.extend_from_slice(&value.to_le_bytes());
+}
+
+fn write_str(value: &str, bytes: &mut Vec<u8>) {
+    write_slice(value.as_bytes(), bytes);
+}
+
+fn write_slice<T: Pod>(values: &[T], bytes: &mut Vec<u8>) {
+    write_u64(values.len() as u64, bytes);
+    bytes.extend_from_slice(bytemuck::cast_slice(values));
+}
+
+fn read_bytes<'a>(len: usize, reader: &mut &'a [u8]) -> Result<&'a [u8], std::io::Error> {
+    if reader.len() < len {
+        return Err(std::io::ErrorKind::UnexpectedEof.into());
+    }
+    let (bytes, rest) = reader.split_at(len);
+    *reader = rest;
+    Ok(bytes)
+}
+
+fn read_u8(reader: &mut &[u8]) -> Result<u8, std::io::Error> {
+    Ok(read_bytes(1, reader)?[0])
+}
+
+fn read_u64(reader: &mut &[u8]) -> Result<u64, std::io::Error> {
+    Ok(u64::from_le_bytes(
+        read_bytes(8, reader)?.try_into().unwrap(),
+    ))
+}
+
+fn read_len(reader: &mut &[u8]) -> Result<usize, MeshSaveOrLoadError> {
+    usize::try_from(read_u64(reader)?)
+        .map_err(|_| MeshSaveOrLoadError::InvalidData("length is too large"))
+}
+
+fn read_string(reader: &mut &[u8]) -> Result<String, MeshSaveOrLoadError> {
+    let len = read_len(reader)?;
+    String::from_utf8(read_bytes(len, reader)?.to_vec())
+        .map_err(|_| MeshSaveOrLoadError::InvalidData("string is not valid UTF-8"))
+}
+
+fn read_vec<T: Pod>(len: usize, reader: &mut &[u8]) -> Result<Vec<T>, std::io::Error> {
+    let byte_len = len
+        .checked_mul(size_of::<T>())
+        .ok_or(std::io::ErrorKind::UnexpectedEof)?;
+    let bytes = read_bytes(byte_len, reader)?;
+    // `bytes` may not be aligned for `T`, so copy it into a new allocation
+    let mut values = vec![T::zeroed(); len];
+    bytemuck::cast_slice_mut(&mut values).copy_from_slice(bytes);
+    Ok(values)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        read_mesh, write_mesh, MeshLoader, MeshSaveOrLoadError, MeshSaver, MORPH_TARGETS_LABEL,
+    };
+    use crate::{Indices, Mesh, MeshVertexAttribute, PrimitiveTopology, VertexAttributeValues};
+    use bevy_app::{App, TaskPoolPlugin};
+    use bevy_asset::{
+        io::{memory::Dir, memory::MemoryAssetReader, AssetSourceBuilder, AssetSourceId, Reader},
+        saver::{AssetSaver, SavedAsset},
+        AssetApp, AssetLoader, AssetPath, AssetPlugin, AssetServer, Assets, ErasedLoadedAsset,
+        Handle, LoadContext, RenderAssetUsages,
+    };
+    use bevy_image::Image;
+    use bevy_platform_support::collections::HashMap;
+    use std::path::Path;
+    use wgpu_types::{Extent3d, TextureDimension, TextureFormat, VertexFormat};
+
+    const ATTRIBUTE_CUSTOM: MeshVertexAttribute =
+        MeshVertexAttribute::new("Vertex_Custom", 988540917, VertexFormat::Uint16x2);
+
+    const MORPH_TARGETS_SIZE: Extent3d = Extent3d {
+        width: 9,
+        height: 1,
+        depth_or_array_layers: 1,
+    };
+
+    fn test_mesh() -> Mesh {
+        Mesh::new(
+            PrimitiveTopology::TriangleList,
+            RenderAssetUsages::MAIN_WORLD,
+        )
+        .with_inserted_attribute(
+            Mesh::ATTRIBUTE_POSITION,
+            vec![[0., 0., 0.], [1., 0., 0.], [0., 1., 0.]],
+        )
+        .with_inserted_attribute(Mesh::ATTRIBUTE_UV_0, vec![[0., 0.], [1., 0.], [0., 1.]])
+        .with_inserted_attribute(
+            Mesh::ATTRIBUTE_JOINT_INDEX,
+            VertexAttributeValues::Uint16x4(vec![[0, 1, 0, 0]; 3]),
+        )
+        .with_inserted_attribute(Mesh::ATTRIBUTE_JOINT_WEIGHT, vec![[0.5, 0.5, 0., 0.]; 3])
+        .with_inserted_attribute(
+            ATTRIBUTE_CUSTOM,
+            VertexAttributeValues::Uint16x2(vec![[1, 2], [3, 4], [5, 6]]),
+        )
+        .with_inserted_indices(Indices::U16(vec![0, 1, 2]))
+        .with_morph_target_names(vec!["smile".into()])
+    }
+
+    fn test_morph_targets() -> Image {
+        Image::new(
+            MORPH_TARGETS_SIZE,
+            TextureDimension::D3,
+            (0..36).collect(),
+            TextureFormat::R32Float,
+            RenderAssetUsages::RENDER_WORLD,
+        )
+    }
+
+    fn assert_same_mesh(loaded: &Mesh, mesh: &Mesh) {
+        assert_eq!(loaded.primitive_topology(), mesh.primitive_topology());
+        assert_eq!(loaded.asset_usage, mesh.asset_usage);
+        assert_eq!(loaded.attributes().count(), mesh.attributes().count());
+        for (attribute, values) in mesh.attributes() {
+            assert_eq!(
+                loaded.attribute(attribute.id).unwrap().get_bytes(),
+                values.get_bytes()
+            );
+        }
+        assert_eq!(
+            loaded.get_index_buffer_bytes(),
+            mesh.get_index_buffer_bytes()
+        );
+        assert_eq!(loaded.morph_target_names(), mesh.morph_target_names());
+    }
+
+    #[test]
+    fn mesh_round_trip() {
+        let mesh = test_mesh();
+        let morph_targets = test_morph_targets();
+
+        let mut bytes = Vec::new();
+        write_mesh(&mesh, Some(&morph_targets), &mut bytes).unwrap();
+
+        assert!(matches!(
+            read_mesh(&mut bytes.as_slice(), &HashMap::default()),
+            Err(MeshSaveOrLoadError::UnknownVertexAttribute { .. })
+        ));
+        let custom_vertex_attributes =
+            HashMap::from_iter([(ATTRIBUTE_CUSTOM.name.into(), ATTRIBUTE_CUSTOM)]);
+        let (loaded, loaded_morph_targets) =
+            read_mesh(&mut bytes.as_slice(), &custom_vertex_attributes).unwrap();
+
+        assert_same_mesh(&loaded, &mesh);
+        let loaded_morph_targets = loaded_morph_targets.unwrap();
+        assert_eq!(
+            loaded_morph_targets.texture_descriptor.size,
+            MORPH_TARGETS_SIZE
+        );
+        assert_eq!(loaded_morph_targets.data, morph_targets.data);
+
+        bytes.truncate(bytes.len() - 1);
+        assert!(read_mesh(&mut bytes.as_slice(), &custom_vertex_attributes).is_err());
+    }
+
+    /// Builds the test mesh with its morph targets as a labeled asset, saves it with [`MeshSaver`] and stores the saved
+    /// bytes in `dir` as `saved.mesh`.
+    struct SavingMeshLoader {
+        dir: Dir,
+    }
+
+    impl AssetLoader for SavingMeshLoader {
+        type Asset = Mesh;
+        type Settings = ();
+        type Error = MeshSaveOrLoadError;
+
+        async fn load(
+            &self,
+            _reader: &mut dyn Reader,
+            _settings: &(),
+            load_context: &mut LoadContext<'_>,
+        ) -> Result<Mesh, MeshSaveOrLoadError> {
+            let mut mesh_context = load_context.begin_labeled_asset();
+            let morph_targets =
+                mesh_context.add_labeled_asset(MORPH_TARGETS_LABEL.into(), test_morph_targets());
+            let mesh = ErasedLoadedAsset::from(
+                mesh_context.finish(test_mesh().with_morph_targets(morph_targets)),
+            );
+
+            let mut bytes = Vec::new();
+            MeshSaver
+                .save(&mut bytes, SavedAsset::from_loaded(&mesh).unwrap(), &())
+                .await?;
+            self.dir.insert_asset(Path::new("saved.mesh"), bytes);
+            Ok(test_mesh())
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["procedural"]
+        }
+    }
+
+    fn run_app_until(app: &mut App, mut predicate: impl FnMut(&AssetServer) -> bool) {
+        for _ in 0..1000 {
+            app.update();
+            if predicate(app.world().resource::<AssetServer>()) {
+                return;
+            }
+        }
+        panic!("Ran out of loops before `predicate` returned `true`");
+    }
+
+    #[test]
+    fn mesh_saver_and_loader_round_trip() {
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("a.procedural"), "");
+
+        let mut app = App::new();
+        let reader_dir = dir.clone();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSourceBuilder::default().with_reader(move || {
+                Box::new(MemoryAssetReader {
+                    root: reader_dir.clone(),
+                })
+            }),
+        )
+        .add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+        .init_asset::<Mesh>()
+        .init_asset::<Image>()
+        .register_asset_loader(SavingMeshLoader { dir })
+        .register_asset_loader(
+            MeshLoader::default().with_custom_vertex_attribute(ATTRIBUTE_CUSTOM),
+        );
+
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let procedural: Handle<Mesh> = asset_server.load("a.procedural");
+        run_app_until(&mut app, |asset_server| {
+            asset_server.is_loaded(&procedural) || asset_server.load_state(&procedural).is_failed()
+        });
+        assert!(asset_server.is_loaded(&procedural));
+
+        let saved: Handle<Mesh> = asset_server.load("saved.mesh");
+        run_app_until(&mut app, |asset_server| {
+            asset_server.is_loaded_with_dependencies(&saved)
+                || asset_server.load_state(&saved).is_failed()
+        });
+        let mesh = app.world().resource::<Assets<Mesh>>().get(&saved).unwrap();
+        assert_same_mesh(mesh, &test_mesh());
+
+        let morph_targets = mesh.morph_targets().unwrap();
+        assert_eq!(
+            asset_server.get_path(morph_targets),
+            Some(AssetPath::from("saved.mesh").with_label(MORPH_TARGETS_LABEL))
+        );
+        let morph_targets = app
+            .world()
+            .resource::<Assets<Image>>()
+            .get(morph_targets)
+            .unwrap();
+        assert_eq!(morph_targets.texture_descriptor.size, MORPH_TARGETS_SIZE);
+        assert_eq!(morph_targets.data, test_morph_targets().data);
+    }
+}
@@ -3,6 +3,7 @@
 extern crate alloc;
 extern crate core;
 
+mod binary;
 mod conversions;
 mod index;
 mod mesh;
@@ -11,6 +12,7 @@ pub mod morph;
 pub mod primitives;
 pub mod skinning;
 mod vertex;
+pub use binary::*;
 use bitflags::bitflags;
 pub use index::*;
 pub use mesh::*;
@@ -106,6 +106,7 @@ pub const VERTEX_ATTRIBUTE_BUFFER_ID: u64 = 10;
 ///     which means that Bevy would *only* render the "front" of each triangle, which
 ///     is the side of the triangle from where the vertices appear in a *counter-clockwise* order.
 #[derive(Asset, Debug, Clone, Reflect)]
+#[asset(size_in_bytes = Mesh::memory_size)]
 pub struct Mesh {
     #[reflect(ignore)]
     primitive_topology: PrimitiveTopology,
@@ -399,6 +400,13 @@ impl Mesh {
         })
     }
 
+    /// Returns the memory used by this mesh, including its vertex and index data.
+    fn memory_size(&self) -> usize {
+        size_of::<Self>()
+            + self.get_vertex_buffer_size()
+            + self.get_index_buffer_bytes().map_or(0, <[u8]>::len)
+    }
+
     /// Get this `Mesh`'s [`MeshVertexBufferLayout`], used in `SpecializedMeshPipeline`.
     pub fn get_mesh_vertex_buffer_layout(
         &self,
@@ -37,7 +37,7 @@ impl MeshVertexAttribute {
 }
 
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Ord, PartialOrd, Hash)]
-pub struct MeshVertexAttributeId(u64);
+pub struct MeshVertexAttributeId(pub(crate) u64);
 
 impl From<MeshVertexAttribute> for MeshVertexAttributeId {
     fn from(attribute: MeshVertexAttribute) -> Self {
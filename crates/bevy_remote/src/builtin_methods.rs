@@ -1,6 +1,6 @@
 //! Built-in verbs for the Bevy Remote Protocol.
 
-use core::any::TypeId;
+use core::{any::TypeId, cmp::Ordering};
 
 use anyhow::{anyhow, Result as AnyhowResult};
 use bevy_ecs::{
@@ -9,22 +9,41 @@ use bevy_ecs::{
     event::EventCursor,
     hierarchy::ChildOf,
     query::QueryBuilder,
-    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectEvent, ReflectResource},
     removal_detection::RemovedComponentEntity,
+    schedule::{NodeId, Schedule, Schedules, Stepping},
     system::{In, Local},
     world::{EntityRef, EntityWorldMut, FilteredEntityRef, World},
 };
 use bevy_platform_support::collections::HashMap;
 use bevy_reflect::{
     prelude::ReflectDefault,
-    serde::{ReflectSerializer, TypedReflectDeserializer},
+    serde::{ReflectSerializer, TypedReflectDeserializer, TypedReflectSerializer},
     GetPath as _, NamedField, OpaqueInfo, PartialReflect, ReflectDeserialize, ReflectSerialize,
     TypeInfo, TypeRegistration, TypeRegistry, VariantInfo,
 };
 use serde::{de::DeserializeSeed as _, Deserialize, Serialize};
 use serde_json::{json, Map, Value};
 
-use crate::{error_codes, BrpError, BrpResult};
+#[cfg(feature = "reflect_functions")]
+use {
+    bevy_ecs::reflect::AppFunctionRegistry,
+    bevy_reflect::{
+        func::{args::ArgInfo, args::Ownership, ArgList, Return},
+        ReflectFromReflect,
+    },
+};
+
+#[cfg(feature = "bevy_asset")]
+use {
+    bevy_asset::{
+        AssetPath, AssetServer, DependencyLoadState, Handle, LoadState, LoadedUntypedAsset,
+        ManagedAssetInfo, RecursiveDependencyLoadState,
+    },
+    bevy_ecs::resource::Resource,
+};
+
+use crate::{error_codes, BrpError, BrpResult, RemoteFrameTick};
 
 /// The method path for a `bevy/get` request.
 pub const BRP_GET_METHOD: &str = "bevy/get";
@@ -59,9 +78,90 @@ pub const BRP_GET_AND_WATCH_METHOD: &str = "bevy/get+watch";
 /// The method path for a `bevy/list+watch` request.
 pub const BRP_LIST_AND_WATCH_METHOD: &str = "bevy/list+watch";
 
+/// The method path for a `bevy/get_resource` request.
+pub const BRP_GET_RESOURCE_METHOD: &str = "bevy/get_resource";
+
+/// The method path for a `bevy/insert_resource` request.
+pub const BRP_INSERT_RESOURCE_METHOD: &str = "bevy/insert_resource";
+
+/// The method path for a `bevy/remove_resource` request.
+pub const BRP_REMOVE_RESOURCE_METHOD: &str = "bevy/remove_resource";
+
+/// The method path for a `bevy/mutate_resource` request.
+pub const BRP_MUTATE_RESOURCE_METHOD: &str = "bevy/mutate_resource";
+
+/// The method path for a `bevy/list_resources` request.
+pub const BRP_LIST_RESOURCES_METHOD: &str = "bevy/list_resources";
+
+/// The method path for a `bevy/get_resource+watch` request.
+pub const BRP_GET_RESOURCE_AND_WATCH_METHOD: &str = "bevy/get_resource+watch";
+
+/// The method path for a `bevy/send_event` request.
+pub const BRP_SEND_EVENT_METHOD: &str = "bevy/send_event";
+
+/// The method path for a `bevy/trigger_event` request.
+pub const BRP_TRIGGER_EVENT_METHOD: &str = "bevy/trigger_event";
+
+/// The method path for a `bevy/schedule/list` request.
+pub const BRP_SCHEDULE_LIST_METHOD: &str = "bevy/schedule/list";
+
+/// The method path for a `bevy/schedule/graph` request.
+pub const BRP_SCHEDULE_GRAPH_METHOD: &str = "bevy/schedule/graph";
+
+/// The method path for a `bevy/stepping/state` request.
+pub const BRP_STEPPING_STATE_METHOD: &str = "bevy/stepping/state";
+
+/// The method path for a `bevy/stepping/enable` request.
+pub const BRP_STEPPING_ENABLE_METHOD: &str = "bevy/stepping/enable";
+
+/// The method path for a `bevy/stepping/disable` request.
+pub const BRP_STEPPING_DISABLE_METHOD: &str = "bevy/stepping/disable";
+
+/// The method path for a `bevy/stepping/step_system` request.
+pub const BRP_STEPPING_STEP_SYSTEM_METHOD: &str = "bevy/stepping/step_system";
+
+/// The method path for a `bevy/stepping/continue` request.
+pub const BRP_STEPPING_CONTINUE_METHOD: &str = "bevy/stepping/continue";
+
+/// The method path for a `bevy/stepping/add_schedule` request.
+pub const BRP_STEPPING_ADD_SCHEDULE_METHOD: &str = "bevy/stepping/add_schedule";
+
+/// The method path for a `bevy/stepping/remove_schedule` request.
+pub const BRP_STEPPING_REMOVE_SCHEDULE_METHOD: &str = "bevy/stepping/remove_schedule";
+
+/// The method path for a `bevy/stepping/set_breakpoint` request.
+pub const BRP_STEPPING_SET_BREAKPOINT_METHOD: &str = "bevy/stepping/set_breakpoint";
+
+/// The method path for a `bevy/stepping/clear_breakpoint` request.
+pub const BRP_STEPPING_CLEAR_BREAKPOINT_METHOD: &str = "bevy/stepping/clear_breakpoint";
+
+/// The method path for a `bevy/asset/list` request.
+#[cfg(feature = "bevy_asset")]
+pub const BRP_ASSET_LIST_METHOD: &str = "bevy/asset/list";
+
+/// The method path for a `bevy/asset/load` request.
+#[cfg(feature = "bevy_asset")]
+pub const BRP_ASSET_LOAD_METHOD: &str = "bevy/asset/load";
+
+/// The method path for a `bevy/asset/reload` request.
+#[cfg(feature = "bevy_asset")]
+pub const BRP_ASSET_RELOAD_METHOD: &str = "bevy/asset/reload";
+
+/// The method path for a `bevy/asset/unload` request.
+#[cfg(feature = "bevy_asset")]
+pub const BRP_ASSET_UNLOAD_METHOD: &str = "bevy/asset/unload";
+
 /// The method path for a `bevy/registry/schema` request.
 pub const BRP_REGISTRY_SCHEMA_METHOD: &str = "bevy/registry/schema";
 
+/// The method path for a `bevy/list_functions` request.
+#[cfg(feature = "reflect_functions")]
+pub const BRP_LIST_FUNCTIONS_METHOD: &str = "bevy/list_functions";
+
+/// The method path for a `bevy/call_function` request.
+#[cfg(feature = "reflect_functions")]
+pub const BRP_CALL_FUNCTION_METHOD: &str = "bevy/call_function";
+
 /// `bevy/get`: Retrieves one or more components from the entity with the given
 /// ID.
 ///
@@ -105,6 +205,17 @@ pub struct BrpQueryParams {
     /// than skipping it. Defaults to false.
     #[serde(default)]
     pub strict: bool,
+
+    /// The maximum number of entities to return.
+    ///
+    /// When this or `after` is given, the results are ordered by entity, so that the entity
+    /// of the last row can be passed as `after` to fetch the next page.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub limit: Option<usize>,
+
+    /// Only return entities which are ordered after this one.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub after: Option<Entity>,
 }
 
 /// `bevy/spawn`: Creates a new entity with the given components and responds
@@ -222,6 +333,166 @@ pub struct BrpMutateParams {
     pub value: Value,
 }
 
+/// `bevy/get_resource`: Retrieves the value of a given resource.
+///
+/// The server responds with a [`BrpGetResourceResponse`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpGetResourceParams {
+    /// The [full path] of the resource type being requested.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub resource: String,
+}
+
+/// `bevy/insert_resource`: Inserts a resource into the world with a given
+/// value, replacing any existing value.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpInsertResourceParams {
+    /// The [full path] of the resource type being inserted.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub resource: String,
+
+    /// The serialized value of the resource to be inserted.
+    pub value: Value,
+}
+
+/// `bevy/remove_resource`: Removes the given resource from the world.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpRemoveResourceParams {
+    /// The [full path] of the resource type to remove.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub resource: String,
+}
+
+/// `bevy/mutate_resource`: Mutates a field in a resource.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpMutateResourceParams {
+    /// The [full path] of the resource to mutate.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub resource: String,
+
+    /// The [path] of the field within the resource.
+    ///
+    /// [path]: bevy_reflect::GetPath
+    pub path: String,
+
+    /// The value to insert at `path`.
+    pub value: Value,
+}
+
+/// `bevy/send_event`: Sends an event into its `Events` resource, to be read by
+/// `EventReader`s.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSendEventParams {
+    /// The [full path] of the event type to send.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub event: String,
+
+    /// The serialized value of the event.
+    pub value: Value,
+}
+
+/// `bevy/trigger_event`: Triggers an event, running the observers watching for it.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpTriggerEventParams {
+    /// The [full path] of the event type to trigger.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub event: String,
+
+    /// The serialized value of the event.
+    pub value: Value,
+
+    /// The entities to trigger the event for.
+    ///
+    /// If this is empty, the event is triggered without any targets, so that
+    /// only global observers run.
+    #[serde(default)]
+    pub targets: Vec<Entity>,
+}
+
+/// `bevy/schedule/graph`, `bevy/stepping/add_schedule` and `bevy/stepping/remove_schedule`:
+/// Identifies a single schedule.
+///
+/// For `bevy/schedule/graph`, the server responds with a [`BrpScheduleGraphResponse`]. For
+/// the stepping methods, it responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpScheduleParams {
+    /// The label of the schedule, formatted with [`Debug`], as returned by `bevy/schedule/list`.
+    pub schedule: String,
+}
+
+/// `bevy/stepping/set_breakpoint` and `bevy/stepping/clear_breakpoint`: Identifies a
+/// single system within a schedule.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSteppingSystemParams {
+    /// The label of the schedule, formatted with [`Debug`], as returned by `bevy/schedule/list`.
+    pub schedule: String,
+
+    /// The id of the system within the schedule, as returned by `bevy/schedule/graph`.
+    pub system: usize,
+}
+
+/// `bevy/asset/list`: Lists the assets tracked by the [`AssetServer`], grouped by asset type.
+///
+/// The server responds with a [`BrpAssetListResponse`].
+#[cfg(feature = "bevy_asset")]
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct BrpAssetListParams {
+    /// The [full path] of an asset type to restrict the listing to.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub asset_type: Option<String>,
+}
+
+/// `bevy/asset/load`, `bevy/asset/reload` and `bevy/asset/unload`: Identifies an asset by
+/// its [`AssetPath`].
+///
+/// The server responds with a null.
+#[cfg(feature = "bevy_asset")]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpAssetPathParams {
+    /// The path of the asset, e.g. `textures/player.png` or `embedded://my_crate/shader.wgsl`.
+    pub path: String,
+}
+
+/// `bevy/call_function`: Calls a function registered in the [`AppFunctionRegistry`]
+/// with the given arguments.
+///
+/// The server responds with the serialized return value of the function.
+///
+/// [`AppFunctionRegistry`]: bevy_ecs::reflect::AppFunctionRegistry
+#[cfg(feature = "reflect_functions")]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpCallFunctionParams {
+    /// The name the function was registered with.
+    pub function: String,
+
+    /// The serialized arguments to pass to the function, in order.
+    ///
+    /// Each argument is deserialized using the type of the corresponding
+    /// parameter in the function's signature.
+    #[serde(default)]
+    pub args: Vec<Value>,
+}
+
 /// Describes the data that is to be fetched in a query.
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
 pub struct BrpQuery {
@@ -264,6 +535,79 @@ pub struct BrpQueryFilter {
     /// [full path]: bevy_reflect::TypePath::type_path
     #[serde(default)]
     pub with: Vec<String>,
+
+    /// Predicates on component values that must all hold for the entity to be
+    /// included in the results.
+    #[serde(default)]
+    pub values: Vec<BrpValueFilter>,
+}
+
+/// A predicate on the value of a field of a component, used in [`BrpQueryFilter`].
+///
+/// Entities without the component don't match.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpValueFilter {
+    /// The [full path] of the type name of the component.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub component: String,
+
+    /// The [path] of the field within the component. If empty, the whole component is
+    /// compared.
+    ///
+    /// [path]: bevy_reflect::GetPath
+    #[serde(default)]
+    pub path: String,
+
+    /// The comparison to make between the field and `value`.
+    pub op: BrpComparison,
+
+    /// The value to compare the field against, in the same format the field is serialized in.
+    pub value: Value,
+}
+
+/// A comparison made by a [`BrpValueFilter`].
+///
+/// Equality compares the serialized values, so it works for fields of any type, while the
+/// ordering comparisons only hold between two numbers or two strings.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BrpComparison {
+    /// The field is equal to the value.
+    Eq,
+    /// The field isn't equal to the value.
+    Ne,
+    /// The field is less than the value.
+    Lt,
+    /// The field is less than or equal to the value.
+    Le,
+    /// The field is greater than the value.
+    Gt,
+    /// The field is greater than or equal to the value.
+    Ge,
+}
+
+impl BrpComparison {
+    /// Returns `true` if `field` compares to `value` as specified.
+    fn holds(self, field: &Value, value: &Value) -> bool {
+        let ordering = match (field, value) {
+            (Value::Number(field), Value::Number(value)) => field
+                .as_f64()
+                .zip(value.as_f64())
+                .and_then(|(field, value)| field.partial_cmp(&value)),
+            (Value::String(field), Value::String(value)) => Some(field.cmp(value)),
+            _ => None,
+        };
+
+        match self {
+            Self::Eq => ordering.map_or(field == value, Ordering::is_eq),
+            Self::Ne => !ordering.map_or(field == value, Ordering::is_eq),
+            Self::Lt => ordering.is_some_and(Ordering::is_lt),
+            Self::Le => ordering.is_some_and(Ordering::is_le),
+            Self::Gt => ordering.is_some_and(Ordering::is_gt),
+            Self::Ge => ordering.is_some_and(Ordering::is_ge),
+        }
+    }
 }
 
 /// Constraints that can be placed on a query to include or exclude
@@ -348,9 +692,183 @@ pub enum BrpGetWatchingResponse {
     },
 }
 
+/// The response to a `bevy/get_resource` request.
+///
+/// This is also sent by `bevy/get_resource+watch` whenever the resource changes.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpGetResourceResponse {
+    /// The value of the requested resource.
+    pub value: Value,
+}
+
 /// The response to a `bevy/list` request.
 pub type BrpListResponse = Vec<String>;
 
+/// The response to a `bevy/schedule/list` request.
+pub type BrpScheduleListResponse = Vec<String>;
+
+/// The response to a `bevy/schedule/graph` request.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpScheduleGraphResponse {
+    /// The systems in the schedule, in the order the executor considers them.
+    pub systems: Vec<BrpScheduleSystem>,
+
+    /// The system sets in the schedule.
+    pub sets: Vec<BrpScheduleSet>,
+
+    /// Edges from each system set to the systems and sets it contains.
+    pub hierarchy: Vec<BrpScheduleEdge>,
+
+    /// Edges from each system or set to the systems and sets that must run after it.
+    pub dependencies: Vec<BrpScheduleEdge>,
+}
+
+/// A system in a [`BrpScheduleGraphResponse`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpScheduleSystem {
+    /// The id of the system within its schedule.
+    pub id: usize,
+
+    /// The name of the system.
+    pub name: String,
+
+    /// Whether the system ran since the remote requests were last processed, i.e. during the
+    /// last frame.
+    ///
+    /// This is `false` if the system was skipped because of its run conditions or stepping.
+    pub ran_last_frame: bool,
+
+    /// How long the system took the last time it ran, in seconds.
+    ///
+    /// This is only recorded with the `system_timing` feature.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub last_run_time: Option<f64>,
+}
+
+/// A system set in a [`BrpScheduleGraphResponse`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpScheduleSet {
+    /// The id of the system set within its schedule.
+    pub id: usize,
+
+    /// The name of the system set, formatted with [`Debug`].
+    pub name: String,
+}
+
+/// A node of a schedule graph: either a system or a system set.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum BrpScheduleNode {
+    /// The system with the given id.
+    System(usize),
+    /// The system set with the given id.
+    Set(usize),
+}
+
+impl From<NodeId> for BrpScheduleNode {
+    fn from(node: NodeId) -> Self {
+        match node {
+            NodeId::System(id) => Self::System(id),
+            NodeId::Set(id) => Self::Set(id),
+        }
+    }
+}
+
+/// An edge in a schedule graph.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq)]
+pub struct BrpScheduleEdge {
+    /// The node the edge starts from.
+    pub from: BrpScheduleNode,
+    /// The node the edge points to.
+    pub to: BrpScheduleNode,
+}
+
+/// The response to a `bevy/stepping/state` request.
+#[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSteppingStateResponse {
+    /// Whether stepping is enabled.
+    pub enabled: bool,
+
+    /// The schedules with stepping enabled, in the order they run.
+    ///
+    /// This is empty until the schedules have run once with stepping enabled.
+    pub schedules: Vec<String>,
+
+    /// The next system that will run when stepping, if any.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub cursor: Option<BrpSteppingCursor>,
+}
+
+/// The position of the stepping cursor in a [`BrpSteppingStateResponse`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSteppingCursor {
+    /// The label of the schedule, formatted with [`Debug`].
+    pub schedule: String,
+
+    /// The id of the system within the schedule.
+    pub system: usize,
+}
+
+/// The response to a `bevy/asset/list` request.
+///
+/// Maps the [full path] of each asset type to the assets of that type. Assets whose type isn't
+/// registered in the [`AppTypeRegistry`] are listed under [`UNREGISTERED_ASSET_TYPE`].
+///
+/// [full path]: bevy_reflect::TypePath::type_path
+#[cfg(feature = "bevy_asset")]
+pub type BrpAssetListResponse = HashMap<String, Vec<BrpAssetInfo>>;
+
+/// The key of a [`BrpAssetListResponse`] listing the assets whose type isn't registered in the
+/// [`AppTypeRegistry`], and whose type path is therefore unknown.
+#[cfg(feature = "bevy_asset")]
+pub const UNREGISTERED_ASSET_TYPE: &str = "unregistered";
+
+/// An asset tracked by the [`AssetServer`], as reported by `bevy/asset/list`.
+#[cfg(feature = "bevy_asset")]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpAssetInfo {
+    /// The id of the asset.
+    pub id: String,
+
+    /// The path the asset was loaded from, if any.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub path: Option<String>,
+
+    /// The load state of the asset itself.
+    pub load_state: BrpLoadState,
+
+    /// The combined load state of the asset's direct dependencies.
+    pub dependency_load_state: BrpLoadState,
+
+    /// The combined load state of the asset's dependencies, recursively.
+    pub recursive_dependency_load_state: BrpLoadState,
+
+    /// The error that caused the asset or one of its dependencies to fail to load, if any.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub error: Option<String>,
+
+    /// The number of strong handles keeping the asset alive.
+    pub strong_handles: usize,
+}
+
+/// The load state of an asset or its dependencies in a [`BrpAssetInfo`].
+#[cfg(feature = "bevy_asset")]
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BrpLoadState {
+    /// Loading hasn't started yet.
+    NotLoaded,
+    /// Loading is in progress.
+    Loading,
+    /// Loading has finished.
+    Loaded,
+    /// Loading failed.
+    Failed,
+}
+
+/// The response to a `bevy/list_resources` request.
+pub type BrpListResourcesResponse = Vec<String>;
+
 /// A single response from a `bevy/list+watch` request.
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct BrpListWatchingResponse {
@@ -358,6 +876,53 @@ pub struct BrpListWatchingResponse {
     removed: Vec<String>,
 }
 
+/// The response to a `bevy/list_functions` request.
+#[cfg(feature = "reflect_functions")]
+pub type BrpListFunctionsResponse = Vec<BrpFunctionInfo>;
+
+/// A function registered in the [`AppFunctionRegistry`], as reported by `bevy/list_functions`.
+///
+/// [`AppFunctionRegistry`]: bevy_ecs::reflect::AppFunctionRegistry
+#[cfg(feature = "reflect_functions")]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpFunctionInfo {
+    /// The name the function was registered with.
+    pub name: String,
+
+    /// The signatures of the function.
+    ///
+    /// Overloaded functions have more than one signature.
+    pub signatures: Vec<BrpFunctionSignature>,
+}
+
+/// A single signature of a function reported by `bevy/list_functions`.
+#[cfg(feature = "reflect_functions")]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpFunctionSignature {
+    /// The arguments of the function, in order.
+    pub args: Vec<BrpFunctionArg>,
+
+    /// The [full path] of the return type.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    #[serde(rename = "return")]
+    pub return_type: String,
+}
+
+/// A single argument of a [`BrpFunctionSignature`].
+#[cfg(feature = "reflect_functions")]
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpFunctionArg {
+    /// The name of the argument, if it was given one.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub name: Option<String>,
+
+    /// The [full path] of the argument type.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub type_path: String,
+}
+
 /// The response to a `bevy/query` request.
 pub type BrpQueryResponse = Vec<BrpQueryRow>;
 
@@ -585,32 +1150,123 @@ fn reflect_component(
     Ok(serialized_object)
 }
 
-/// Handles a `bevy/query` request coming from a client.
-pub fn process_remote_query_request(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
-    let BrpQueryParams {
-        data: BrpQuery {
-            components,
-            option,
-            has,
-        },
-        filter: BrpQueryFilter { without, with },
-        strict,
+/// Handles a `bevy/get_resource` request coming from a client.
+pub fn process_remote_get_resource_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let BrpGetResourceParams {
+        resource: resource_path,
     } = parse_some(params)?;
 
-    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let app_type_registry = world.resource::<AppTypeRegistry>();
     let type_registry = app_type_registry.read();
 
-    let components = get_component_ids(&type_registry, world, components, strict)
-        .map_err(BrpError::component_error)?;
-    let option = get_component_ids(&type_registry, world, option, strict)
-        .map_err(BrpError::component_error)?;
-    let has =
-        get_component_ids(&type_registry, world, has, strict).map_err(BrpError::component_error)?;
-    let without = get_component_ids(&type_registry, world, without, strict)
-        .map_err(BrpError::component_error)?;
-    let with = get_component_ids(&type_registry, world, with, strict)
+    let response = reflect_resource_to_response(world, &resource_path, &type_registry)?;
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/get_resource+watch` request coming from a client.
+pub fn process_remote_get_resource_watching_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult<Option<Value>> {
+    let BrpGetResourceParams {
+        resource: resource_path,
+    } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
+
+    let type_registration = get_resource_type_registration(&type_registry, &resource_path)
+        .map_err(BrpError::resource_error)?;
+    let Some(ticks) = world
+        .components()
+        .get_resource_id(type_registration.type_id())
+        .and_then(|component_id| world.get_resource_change_ticks_by_id(component_id))
+    else {
+        return Err(BrpError::resource_not_present(&resource_path));
+    };
+
+    if !ticks.is_changed(world.last_change_tick(), world.read_change_tick()) {
+        return Ok(None);
+    }
+
+    let response = reflect_resource_to_response(world, &resource_path, &type_registry)?;
+    Ok(Some(
+        serde_json::to_value(response).map_err(BrpError::internal)?,
+    ))
+}
+
+/// Reflect the resource with the given type path into a [`BrpGetResourceResponse`].
+fn reflect_resource_to_response(
+    world: &World,
+    resource_path: &str,
+    type_registry: &TypeRegistry,
+) -> BrpResult<BrpGetResourceResponse> {
+    let reflect_resource =
+        get_reflect_resource(type_registry, resource_path).map_err(BrpError::resource_error)?;
+
+    let Some(reflected) = reflect_resource.reflect(world) else {
+        return Err(BrpError::resource_not_present(resource_path));
+    };
+
+    // The resource value is serialized on its own, without being wrapped in a map keyed by
+    // its type path.
+    let reflect_serializer =
+        TypedReflectSerializer::new(reflected.as_partial_reflect(), type_registry);
+    let value = serde_json::to_value(&reflect_serializer).map_err(BrpError::resource_error)?;
+
+    Ok(BrpGetResourceResponse { value })
+}
+
+/// Handles a `bevy/query` request coming from a client.
+pub fn process_remote_query_request(In(params): In<Option<Value>>, world: &mut World) -> BrpResult {
+    let BrpQueryParams {
+        data: BrpQuery {
+            components,
+            option,
+            has,
+        },
+        filter:
+            BrpQueryFilter {
+                without,
+                with,
+                values,
+            },
+        strict,
+        limit,
+        after,
+    } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    let components = get_component_ids(&type_registry, world, components, strict)
+        .map_err(BrpError::component_error)?;
+    let option = get_component_ids(&type_registry, world, option, strict)
+        .map_err(BrpError::component_error)?;
+    let has =
+        get_component_ids(&type_registry, world, has, strict).map_err(BrpError::component_error)?;
+    let without = get_component_ids(&type_registry, world, without, strict)
+        .map_err(BrpError::component_error)?;
+    let with = get_component_ids(&type_registry, world, with, strict)
         .map_err(BrpError::component_error)?;
 
+    let mut value_filters = Vec::with_capacity(values.len());
+    for filter in values {
+        let type_registration = get_component_type_registration(&type_registry, &filter.component)
+            .map_err(BrpError::component_error)?;
+        let Some(component_id) = world.components().get_id(type_registration.type_id()) else {
+            // No entity can have a component that isn't used in the world.
+            return serde_json::to_value(BrpQueryResponse::default()).map_err(BrpError::internal);
+        };
+        let (_, reflect_component) =
+            reflect_component_from_id(type_registration.type_id(), &type_registry)
+                .map_err(BrpError::component_error)?;
+        value_filters.push((component_id, reflect_component, filter));
+    }
+
     let mut query = QueryBuilder::<FilteredEntityRef>::new(world);
     for (_, component) in &components {
         query.ref_id(*component);
@@ -631,6 +1287,9 @@ pub fn process_remote_query_request(In(params): In<Option<Value>>, world: &mut W
     for (_, with) in with {
         query.with_id(with);
     }
+    for (component_id, _, _) in &value_filters {
+        query.ref_id(*component_id);
+    }
 
     // At this point, we can safely unify `components` and `option`, since we only retrieved
     // entities that actually have all the `components` already.
@@ -651,9 +1310,26 @@ pub fn process_remote_query_request(In(params): In<Option<Value>>, world: &mut W
         .collect::<AnyhowResult<Vec<(&str, &ReflectComponent)>>>()
         .map_err(BrpError::component_error)?;
 
-    let mut response = BrpQueryResponse::default();
     let mut query = query.build();
+    let mut rows = Vec::new();
     for row in query.iter(world) {
+        if matches_value_filters(&row, &value_filters, &type_registry)
+            .map_err(BrpError::component_error)?
+        {
+            rows.push(row);
+        }
+    }
+
+    if limit.is_some() || after.is_some() {
+        rows.sort_unstable_by_key(FilteredEntityRef::id);
+        if let Some(after) = after {
+            rows.retain(|row| row.id() > after);
+        }
+        rows.truncate(limit.unwrap_or(usize::MAX));
+    }
+
+    let mut response = BrpQueryResponse::default();
+    for row in rows {
         // The map of component values:
         let components_map = build_components_map(
             row.clone(),
@@ -783,6 +1459,156 @@ pub fn process_remote_mutate_component_request(
     Ok(Value::Null)
 }
 
+/// Handles a `bevy/insert_resource` request coming from a client.
+pub fn process_remote_insert_resource_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpInsertResourceParams {
+        resource: resource_path,
+        value,
+    } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    let reflected_resource = deserialize_resource(&type_registry, &resource_path, value)
+        .map_err(BrpError::resource_error)?;
+
+    let reflect_resource =
+        get_reflect_resource(&type_registry, &resource_path).map_err(BrpError::resource_error)?;
+    reflect_resource.insert(world, &*reflected_resource, &type_registry);
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/mutate_resource` request coming from a client.
+///
+/// This method allows you to mutate a single field inside a resource.
+pub fn process_remote_mutate_resource_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpMutateResourceParams {
+        resource: resource_path,
+        path: field_path,
+        value,
+    } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    // Get the reflected representation of the resource.
+    let reflect_resource =
+        get_reflect_resource(&type_registry, &resource_path).map_err(BrpError::resource_error)?;
+    let Some(mut reflected_resource) = reflect_resource.reflect_mut(world) else {
+        return Err(BrpError::resource_not_present(&resource_path));
+    };
+
+    // Get the type of the field in the resource that is to be mutated.
+    let field_type_path = reflected_resource
+        .reflect_path(field_path.as_str())
+        .map_err(BrpError::resource_error)?
+        .reflect_type_path();
+    let value_registration = type_registry
+        .get_with_type_path(field_type_path)
+        .ok_or_else(|| {
+            BrpError::resource_error(anyhow!(
+                "Unknown resource field type: `{}`",
+                field_type_path
+            ))
+        })?;
+
+    // Get the reflected representation of the value to be inserted into the resource.
+    let value: Box<dyn PartialReflect> =
+        TypedReflectDeserializer::new(value_registration, &type_registry)
+            .deserialize(&value)
+            .map_err(BrpError::resource_error)?;
+
+    // Apply the mutation.
+    reflected_resource
+        .reflect_path_mut(field_path.as_str())
+        .map_err(BrpError::resource_error)?
+        .try_apply(value.as_ref())
+        .map_err(BrpError::resource_error)?;
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/remove_resource` request coming from a client.
+pub fn process_remote_remove_resource_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpRemoveResourceParams {
+        resource: resource_path,
+    } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    let reflect_resource =
+        get_reflect_resource(&type_registry, &resource_path).map_err(BrpError::resource_error)?;
+    reflect_resource.remove(world);
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/send_event` request coming from a client.
+pub fn process_remote_send_event_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpSendEventParams {
+        event: event_path,
+        value,
+    } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    let (reflect_event, reflected_event) =
+        deserialize_event(&type_registry, &event_path, value).map_err(BrpError::event_error)?;
+
+    if !reflect_event.send(world, &*reflected_event, &type_registry) {
+        return Err(BrpError::event_error(anyhow!(
+            "Event `{}` has not been added to the app",
+            event_path
+        )));
+    }
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/trigger_event` request coming from a client.
+pub fn process_remote_trigger_event_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpTriggerEventParams {
+        event: event_path,
+        value,
+        targets,
+    } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    let (reflect_event, reflected_event) =
+        deserialize_event(&type_registry, &event_path, value).map_err(BrpError::event_error)?;
+
+    if targets.is_empty() {
+        reflect_event.trigger(world, &*reflected_event, &type_registry);
+    } else {
+        for &target in &targets {
+            get_entity(world, target)?;
+        }
+        reflect_event.trigger_targets(world, &*reflected_event, targets, &type_registry);
+    }
+
+    Ok(Value::Null)
+}
+
 /// Handles a `bevy/remove` request (remove components) coming from a client.
 pub fn process_remote_remove_request(
     In(params): In<Option<Value>>,
@@ -805,126 +1631,601 @@ pub fn process_remote_remove_request(
     Ok(Value::Null)
 }
 
-/// Handles a `bevy/destroy` (despawn entity) request coming from a client.
-pub fn process_remote_destroy_request(
+/// Handles a `bevy/destroy` (despawn entity) request coming from a client.
+pub fn process_remote_destroy_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpDestroyParams { entity } = parse_some(params)?;
+
+    get_entity_mut(world, entity)?.despawn();
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/reparent` request coming from a client.
+pub fn process_remote_reparent_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpReparentParams {
+        entities,
+        parent: maybe_parent,
+    } = parse_some(params)?;
+
+    // If `Some`, reparent the entities.
+    if let Some(parent) = maybe_parent {
+        let mut parent_commands =
+            get_entity_mut(world, parent).map_err(|_| BrpError::entity_not_found(parent))?;
+        for entity in entities {
+            if entity == parent {
+                return Err(BrpError::self_reparent(entity));
+            }
+            parent_commands.add_child(entity);
+        }
+    }
+    // If `None`, remove the entities' parents.
+    else {
+        for entity in entities {
+            get_entity_mut(world, entity)?.remove::<ChildOf>();
+        }
+    }
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/list` request (list all components) coming from a client.
+pub fn process_remote_list_request(In(params): In<Option<Value>>, world: &World) -> BrpResult {
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
+
+    let mut response = BrpListResponse::default();
+
+    // If `Some`, return all components of the provided entity.
+    if let Some(BrpListParams { entity }) = params.map(parse).transpose()? {
+        let entity = get_entity(world, entity)?;
+        for component_id in entity.archetype().components() {
+            let Some(component_info) = world.components().get_info(component_id) else {
+                continue;
+            };
+            response.push(component_info.name().to_owned());
+        }
+    }
+    // If `None`, list all registered components.
+    else {
+        for registered_type in type_registry.iter() {
+            if registered_type.data::<ReflectComponent>().is_some() {
+                response.push(registered_type.type_info().type_path().to_owned());
+            }
+        }
+    }
+
+    // Sort both for cleanliness and to reduce the risk that clients start
+    // accidentally depending on the order.
+    response.sort();
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/list_resources` request coming from a client.
+pub fn process_remote_list_resources_request(
+    In(_params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
+
+    let mut response = BrpListResourcesResponse::default();
+    for registered_type in type_registry.iter() {
+        if registered_type.data::<ReflectResource>().is_some() {
+            response.push(registered_type.type_info().type_path().to_owned());
+        }
+    }
+
+    // Sort both for cleanliness and to reduce the risk that clients start
+    // accidentally depending on the order.
+    response.sort();
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/list` request (list all components) coming from a client.
+pub fn process_remote_list_watching_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+    mut removal_cursors: Local<HashMap<ComponentId, EventCursor<RemovedComponentEntity>>>,
+) -> BrpResult<Option<Value>> {
+    let BrpListParams { entity } = parse_some(params)?;
+    let entity_ref = get_entity(world, entity)?;
+    let mut response = BrpListWatchingResponse::default();
+
+    for component_id in entity_ref.archetype().components() {
+        let ticks = entity_ref
+            .get_change_ticks_by_id(component_id)
+            .ok_or(BrpError::internal("Failed to get ticks"))?;
+
+        if ticks.is_added(world.last_change_tick(), world.read_change_tick()) {
+            let Some(component_info) = world.components().get_info(component_id) else {
+                continue;
+            };
+            response.added.push(component_info.name().to_owned());
+        }
+    }
+
+    for (component_id, events) in world.removed_components().iter() {
+        let cursor = removal_cursors
+            .entry(*component_id)
+            .or_insert_with(|| events.get_cursor());
+        for event in cursor.read(events) {
+            if Entity::from(event.clone()) == entity {
+                let Some(component_info) = world.components().get_info(*component_id) else {
+                    continue;
+                };
+                response.removed.push(component_info.name().to_owned());
+            }
+        }
+    }
+
+    if response.added.is_empty() && response.removed.is_empty() {
+        Ok(None)
+    } else {
+        Ok(Some(
+            serde_json::to_value(response).map_err(BrpError::internal)?,
+        ))
+    }
+}
+
+/// Handles a `bevy/list_functions` request coming from a client.
+#[cfg(feature = "reflect_functions")]
+pub fn process_remote_list_functions_request(
+    In(_params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let function_registry = world.resource::<AppFunctionRegistry>().read();
+
+    let mut response: BrpListFunctionsResponse = function_registry
+        .iter()
+        .filter_map(|function| {
+            let info = function.info();
+            let signatures = info
+                .signatures()
+                .iter()
+                .map(|signature| BrpFunctionSignature {
+                    args: signature
+                        .args()
+                        .iter()
+                        .map(|arg| BrpFunctionArg {
+                            name: arg.name().map(str::to_owned),
+                            type_path: arg.type_path().to_owned(),
+                        })
+                        .collect(),
+                    return_type: signature.return_info().type_path().to_owned(),
+                })
+                .collect();
+
+            Some(BrpFunctionInfo {
+                name: info.name()?.to_string(),
+                signatures,
+            })
+        })
+        .collect();
+
+    // Sort both for cleanliness and to reduce the risk that clients start
+    // accidentally depending on the order.
+    response.sort_by(|a, b| a.name.cmp(&b.name));
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/call_function` request coming from a client.
+///
+/// Each argument is deserialized according to the first signature of the function
+/// with a matching number of arguments for which deserialization succeeds.
+#[cfg(feature = "reflect_functions")]
+pub fn process_remote_call_function_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let BrpCallFunctionParams { function, args } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
+    let function_registry = world.resource::<AppFunctionRegistry>().read();
+
+    let Some(dynamic_function) = function_registry.get(&function) else {
+        return Err(BrpError::function_not_found(&function));
+    };
+
+    let mut last_error = None;
+    let mut candidate = None;
+    for signature in dynamic_function.info().signatures() {
+        if signature.arg_count() != args.len() {
+            continue;
+        }
+        match deserialize_function_args(&type_registry, signature.args(), &args) {
+            Ok(values) => {
+                candidate = Some((signature, values));
+                break;
+            }
+            Err(err) => last_error = Some(err),
+        }
+    }
+
+    let Some((signature, mut values)) = candidate else {
+        return Err(BrpError::function_error(last_error.unwrap_or_else(|| {
+            anyhow!(
+                "Function `{}` does not take {} argument(s)",
+                function,
+                args.len()
+            )
+        })));
+    };
+
+    let mut arg_list = ArgList::new();
+    for (arg_info, value) in signature.args().iter().zip(values.iter_mut()) {
+        match arg_info.ownership() {
+            Ownership::Owned => arg_list.push_boxed(core::mem::replace(value, Box::new(()))),
+            Ownership::Ref => arg_list.push_ref(&**value),
+            Ownership::Mut => arg_list.push_mut(&mut **value),
+        }
+    }
+
+    let returned = dynamic_function
+        .call(arg_list)
+        .map_err(BrpError::function_error)?;
+
+    let returned: &dyn PartialReflect = match &returned {
+        _ if returned.is_unit() => return Ok(Value::Null),
+        Return::Owned(value) => &**value,
+        Return::Ref(value) => *value,
+        Return::Mut(value) => &**value,
+    };
+
+    serde_json::to_value(TypedReflectSerializer::new(returned, &type_registry))
+        .map_err(BrpError::function_error)
+}
+
+/// Deserializes the JSON `args` of a `bevy/call_function` request into concrete reflected values
+/// of the types described by `arg_infos`.
+#[cfg(feature = "reflect_functions")]
+fn deserialize_function_args(
+    type_registry: &TypeRegistry,
+    arg_infos: &[ArgInfo],
+    args: &[Value],
+) -> AnyhowResult<Vec<Box<dyn PartialReflect>>> {
+    let mut values = vec![];
+
+    for (arg_info, arg) in arg_infos.iter().zip(args) {
+        // Reference arguments are deserialized as their referent and borrowed when the function
+        // is called, so strip the reference from the type path.
+        let type_path = arg_info.type_path();
+        let type_path = match arg_info.ownership() {
+            Ownership::Owned => type_path,
+            Ownership::Ref => type_path.strip_prefix('&').unwrap_or(type_path),
+            Ownership::Mut => type_path.strip_prefix("&mut ").unwrap_or(type_path),
+        };
+
+        let Some(registration) = type_registry.get_with_type_path(type_path) else {
+            return Err(anyhow!("Unknown argument type: `{}`", type_path));
+        };
+
+        let reflected: Box<dyn PartialReflect> =
+            TypedReflectDeserializer::new(registration, type_registry)
+                .deserialize(arg)
+                .map_err(|err| anyhow!("Argument {} is invalid: {err}", arg_info.index()))?;
+
+        // Functions downcast their arguments, so dynamic values need to be converted into
+        // their concrete types first.
+        let concrete = registration
+            .data::<ReflectFromReflect>()
+            .and_then(|from_reflect| from_reflect.from_reflect(reflected.as_ref()))
+            .ok_or_else(|| {
+                anyhow!(
+                    "Argument {} could not be converted to `{}`",
+                    arg_info.index(),
+                    type_path
+                )
+            })?;
+
+        values.push(concrete.into_partial_reflect());
+    }
+
+    Ok(values)
+}
+
+/// Handles a `bevy/schedule/list` request coming from a client.
+///
+/// Note that the schedules which are running while the request is processed, such as `Main`
+/// and `RemoteLast`, are not listed.
+pub fn process_remote_schedule_list_request(
+    In(_params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let mut response = BrpScheduleListResponse::default();
+    if let Some(schedules) = world.get_resource::<Schedules>() {
+        for (label, _) in schedules.iter() {
+            response.push(format!("{label:?}"));
+        }
+    }
+
+    // Sort both for cleanliness and to reduce the risk that clients start
+    // accidentally depending on the order.
+    response.sort();
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/schedule/graph` request coming from a client.
+pub fn process_remote_schedule_graph_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let BrpScheduleParams { schedule } = parse_some(params)?;
+    let schedule = get_schedule(world, &schedule)?;
+
+    // Systems which ran after the last time the remote requests were processed ran during the
+    // last frame.
+    let this_run = world.read_change_tick();
+    let last_frame = world
+        .get_resource::<RemoteFrameTick>()
+        .map(|tick| tick.0)
+        .unwrap_or_default();
+
+    let mut response = BrpScheduleGraphResponse::default();
+    let Ok(systems) = schedule.systems() else {
+        // The systems aren't moved into the executor until the schedule is first run.
+        return serde_json::to_value(response).map_err(BrpError::internal);
+    };
+    for (node, system) in systems {
+        let NodeId::System(id) = node else {
+            continue;
+        };
+        let name = system.name();
+        #[cfg(feature = "system_timing")]
+        let last_run_time = world
+            .get_resource::<crate::timing::SystemTimings>()
+            .and_then(|timings| timings.get(&name))
+            .map(|duration| duration.as_secs_f64());
+        #[cfg(not(feature = "system_timing"))]
+        let last_run_time = None;
+        response.systems.push(BrpScheduleSystem {
+            id,
+            name: name.into_owned(),
+            ran_last_frame: system.get_last_run().is_newer_than(last_frame, this_run),
+            last_run_time,
+        });
+    }
+
+    let graph = schedule.graph();
+    for (node, set, _) in graph.system_sets() {
+        let NodeId::Set(id) = node else {
+            continue;
+        };
+        response.sets.push(BrpScheduleSet {
+            id,
+            name: format!("{set:?}"),
+        });
+    }
+    response.hierarchy = graph
+        .hierarchy()
+        .graph()
+        .all_edges()
+        .map(|(from, to)| BrpScheduleEdge {
+            from: from.into(),
+            to: to.into(),
+        })
+        .collect();
+    response.dependencies = graph
+        .dependency()
+        .graph()
+        .all_edges()
+        .map(|(from, to)| BrpScheduleEdge {
+            from: from.into(),
+            to: to.into(),
+        })
+        .collect();
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/stepping/state` request coming from a client.
+pub fn process_remote_stepping_state_request(
+    In(_params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let mut response = BrpSteppingStateResponse::default();
+    if let Some(stepping) = world.get_resource::<Stepping>() {
+        response.enabled = stepping.is_enabled();
+        if let Ok(schedules) = stepping.schedules() {
+            response.schedules = schedules.iter().map(|label| format!("{label:?}")).collect();
+        }
+        response.cursor = stepping.cursor().and_then(|(label, node)| match node {
+            NodeId::System(system) => Some(BrpSteppingCursor {
+                schedule: format!("{label:?}"),
+                system,
+            }),
+            NodeId::Set(_) => None,
+        });
+    }
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/stepping/enable` request coming from a client.
+pub fn process_remote_stepping_enable_request(
+    In(_params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    world.get_resource_or_init::<Stepping>().enable();
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/disable` request coming from a client.
+pub fn process_remote_stepping_disable_request(
+    In(_params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    world.get_resource_or_init::<Stepping>().disable();
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/step_system` request coming from a client.
+pub fn process_remote_stepping_step_system_request(
+    In(_params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    world.get_resource_or_init::<Stepping>().step_frame();
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/continue` request coming from a client.
+pub fn process_remote_stepping_continue_request(
+    In(_params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    world.get_resource_or_init::<Stepping>().continue_frame();
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/add_schedule` request coming from a client.
+pub fn process_remote_stepping_add_schedule_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpScheduleParams { schedule } = parse_some(params)?;
+    let label = get_schedule(world, &schedule)?.label();
+    world.get_resource_or_init::<Stepping>().add_schedule(label);
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/remove_schedule` request coming from a client.
+pub fn process_remote_stepping_remove_schedule_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpScheduleParams { schedule } = parse_some(params)?;
+    let label = get_schedule(world, &schedule)?.label();
+    world
+        .get_resource_or_init::<Stepping>()
+        .remove_schedule(label);
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/stepping/set_breakpoint` request coming from a client.
+pub fn process_remote_stepping_set_breakpoint_request(
     In(params): In<Option<Value>>,
     world: &mut World,
 ) -> BrpResult {
-    let BrpDestroyParams { entity } = parse_some(params)?;
-
-    get_entity_mut(world, entity)?.despawn();
-
+    let BrpSteppingSystemParams { schedule, system } = parse_some(params)?;
+    let label = get_schedule_system(world, &schedule, system)?.label();
+    world
+        .get_resource_or_init::<Stepping>()
+        .set_breakpoint_node(label, NodeId::System(system));
     Ok(Value::Null)
 }
 
-/// Handles a `bevy/reparent` request coming from a client.
-pub fn process_remote_reparent_request(
+/// Handles a `bevy/stepping/clear_breakpoint` request coming from a client.
+pub fn process_remote_stepping_clear_breakpoint_request(
     In(params): In<Option<Value>>,
     world: &mut World,
 ) -> BrpResult {
-    let BrpReparentParams {
-        entities,
-        parent: maybe_parent,
-    } = parse_some(params)?;
-
-    // If `Some`, reparent the entities.
-    if let Some(parent) = maybe_parent {
-        let mut parent_commands =
-            get_entity_mut(world, parent).map_err(|_| BrpError::entity_not_found(parent))?;
-        for entity in entities {
-            if entity == parent {
-                return Err(BrpError::self_reparent(entity));
-            }
-            parent_commands.add_child(entity);
-        }
-    }
-    // If `None`, remove the entities' parents.
-    else {
-        for entity in entities {
-            get_entity_mut(world, entity)?.remove::<ChildOf>();
-        }
-    }
-
+    let BrpSteppingSystemParams { schedule, system } = parse_some(params)?;
+    let label = get_schedule_system(world, &schedule, system)?.label();
+    world
+        .get_resource_or_init::<Stepping>()
+        .clear_breakpoint_node(label, NodeId::System(system));
     Ok(Value::Null)
 }
 
-/// Handles a `bevy/list` request (list all components) coming from a client.
-pub fn process_remote_list_request(In(params): In<Option<Value>>, world: &World) -> BrpResult {
+/// Handles of the assets loaded through `bevy/asset/load`, keyed by path.
+///
+/// These keep the assets alive until they are released by `bevy/asset/unload`.
+#[cfg(feature = "bevy_asset")]
+#[derive(Resource, Default)]
+struct RemoteAssetHandles(HashMap<String, Handle<LoadedUntypedAsset>>);
+
+/// Handles a `bevy/asset/list` request coming from a client.
+#[cfg(feature = "bevy_asset")]
+pub fn process_remote_asset_list_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let BrpAssetListParams { asset_type } = params.map(parse).transpose()?.unwrap_or_default();
+
+    let asset_server = get_asset_server(world)?;
     let app_type_registry = world.resource::<AppTypeRegistry>();
     let type_registry = app_type_registry.read();
 
-    let mut response = BrpListResponse::default();
-
-    // If `Some`, return all components of the provided entity.
-    if let Some(BrpListParams { entity }) = params.map(parse).transpose()? {
-        let entity = get_entity(world, entity)?;
-        for component_id in entity.archetype().components() {
-            let Some(component_info) = world.components().get_info(component_id) else {
-                continue;
-            };
-            response.push(component_info.name().to_owned());
-        }
-    }
-    // If `None`, list all registered components.
-    else {
-        for registered_type in type_registry.iter() {
-            if registered_type.data::<ReflectComponent>().is_some() {
-                response.push(registered_type.type_info().type_path().to_owned());
-            }
+    let mut response = BrpAssetListResponse::default();
+    for info in asset_server.managed_assets() {
+        let type_path = type_registry
+            .get_type_info(info.id.type_id())
+            .map_or(UNREGISTERED_ASSET_TYPE, |type_info| type_info.type_path())
+            .to_owned();
+        if asset_type
+            .as_ref()
+            .is_some_and(|asset_type| *asset_type != type_path)
+        {
+            continue;
         }
+        response
+            .entry(type_path)
+            .or_default()
+            .push(asset_info_to_response(info));
     }
 
-    // Sort both for cleanliness and to reduce the risk that clients start
+    // Sort for cleanliness and to reduce the risk that clients start
     // accidentally depending on the order.
-    response.sort();
+    for assets in response.values_mut() {
+        assets.sort_by(|a, b| a.path.cmp(&b.path).then_with(|| a.id.cmp(&b.id)));
+    }
 
     serde_json::to_value(response).map_err(BrpError::internal)
 }
 
-/// Handles a `bevy/list` request (list all components) coming from a client.
-pub fn process_remote_list_watching_request(
+/// Handles a `bevy/asset/load` request coming from a client.
+///
+/// BRP keeps the loaded asset alive until a matching `bevy/asset/unload` request.
+#[cfg(feature = "bevy_asset")]
+pub fn process_remote_asset_load_request(
     In(params): In<Option<Value>>,
-    world: &World,
-    mut removal_cursors: Local<HashMap<ComponentId, EventCursor<RemovedComponentEntity>>>,
-) -> BrpResult<Option<Value>> {
-    let BrpListParams { entity } = parse_some(params)?;
-    let entity_ref = get_entity(world, entity)?;
-    let mut response = BrpListWatchingResponse::default();
-
-    for component_id in entity_ref.archetype().components() {
-        let ticks = entity_ref
-            .get_change_ticks_by_id(component_id)
-            .ok_or(BrpError::internal("Failed to get ticks"))?;
-
-        if ticks.is_added(world.last_change_tick(), world.read_change_tick()) {
-            let Some(component_info) = world.components().get_info(component_id) else {
-                continue;
-            };
-            response.added.push(component_info.name().to_owned());
-        }
-    }
+    world: &mut World,
+) -> BrpResult {
+    let BrpAssetPathParams { path } = parse_some(params)?;
+    let handle = get_asset_server(world)?.load_untyped(parse_asset_path(&path)?);
+    world
+        .get_resource_or_init::<RemoteAssetHandles>()
+        .0
+        .insert(path, handle);
+    Ok(Value::Null)
+}
 
-    for (component_id, events) in world.removed_components().iter() {
-        let cursor = removal_cursors
-            .entry(*component_id)
-            .or_insert_with(|| events.get_cursor());
-        for event in cursor.read(events) {
-            if Entity::from(event.clone()) == entity {
-                let Some(component_info) = world.components().get_info(*component_id) else {
-                    continue;
-                };
-                response.removed.push(component_info.name().to_owned());
-            }
-        }
-    }
+/// Handles a `bevy/asset/reload` request coming from a client.
+#[cfg(feature = "bevy_asset")]
+pub fn process_remote_asset_reload_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let BrpAssetPathParams { path } = parse_some(params)?;
+    get_asset_server(world)?.reload(parse_asset_path(&path)?);
+    Ok(Value::Null)
+}
 
-    if response.added.is_empty() && response.removed.is_empty() {
-        Ok(None)
-    } else {
-        Ok(Some(
-            serde_json::to_value(response).map_err(BrpError::internal)?,
-        ))
-    }
+/// Handles a `bevy/asset/unload` request coming from a client.
+///
+/// This only releases the handle held by BRP: the asset is unloaded once no other
+/// strong handles to it remain.
+#[cfg(feature = "bevy_asset")]
+pub fn process_remote_asset_unload_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpAssetPathParams { path } = parse_some(params)?;
+    world
+        .get_resource_mut::<RemoteAssetHandles>()
+        .and_then(|mut handles| handles.0.remove(&path))
+        .map(|_| Value::Null)
+        .ok_or_else(|| BrpError::asset_not_loaded(&path))
 }
 
 /// Handles a `bevy/registry/schema` request (list all registry types in form of schema) coming from a client.
@@ -1113,9 +2414,10 @@ fn export_type(reg: &TypeRegistration) -> (String, JsonSchemaBevyType) {
 
 fn get_registered_reflect_types(reg: &TypeRegistration) -> Vec<String> {
     // Vec could be moved to allow registering more types by game maker.
-    let registered_reflect_types: [(TypeId, &str); 5] = [
+    let registered_reflect_types: [(TypeId, &str); 6] = [
         { (TypeId::of::<ReflectComponent>(), "Component") },
         { (TypeId::of::<ReflectResource>(), "Resource") },
+        { (TypeId::of::<ReflectEvent>(), "Event") },
         { (TypeId::of::<ReflectDefault>(), "Default") },
         { (TypeId::of::<ReflectSerialize>(), "Serialize") },
         { (TypeId::of::<ReflectDeserialize>(), "Deserialize") },
@@ -1310,6 +2612,94 @@ impl SchemaJsonReference for &NamedField {
     }
 }
 
+/// Retrieves the schedule whose label is formatted as `label` from the [`Schedules`],
+/// returning an error if it isn't present.
+fn get_schedule<'w>(world: &'w World, label: &str) -> Result<&'w Schedule, BrpError> {
+    world
+        .get_resource::<Schedules>()
+        .and_then(|schedules| {
+            schedules
+                .iter()
+                .find(|(schedule_label, _)| format!("{schedule_label:?}") == label)
+        })
+        .map(|(_, schedule)| schedule)
+        .ok_or_else(|| BrpError::schedule_not_found(label))
+}
+
+/// Retrieves the schedule whose label is formatted as `label`, returning an error if it isn't
+/// present or has no system with the id `system`.
+fn get_schedule_system<'w>(
+    world: &'w World,
+    label: &str,
+    system: usize,
+) -> Result<&'w Schedule, BrpError> {
+    let schedule = get_schedule(world, label)?;
+    // Systems are never removed from a schedule, so their ids are the indices below its length.
+    if system >= schedule.systems_len() {
+        return Err(BrpError::system_not_found(label, system));
+    }
+    Ok(schedule)
+}
+
+/// Returns the [`AssetServer`], or an error if the [`AssetPlugin`] wasn't added.
+///
+/// [`AssetPlugin`]: bevy_asset::AssetPlugin
+#[cfg(feature = "bevy_asset")]
+fn get_asset_server(world: &World) -> Result<&AssetServer, BrpError> {
+    world
+        .get_resource::<AssetServer>()
+        .ok_or_else(|| BrpError::resource_not_present(core::any::type_name::<AssetServer>()))
+}
+
+/// Parses an [`AssetPath`] sent by a client.
+#[cfg(feature = "bevy_asset")]
+fn parse_asset_path(path: &str) -> Result<AssetPath<'_>, BrpError> {
+    AssetPath::try_parse(path).map_err(|err| BrpError {
+        code: error_codes::INVALID_PARAMS,
+        message: format!("Invalid asset path `{path}`: {err}"),
+        data: None,
+    })
+}
+
+/// Converts a [`ManagedAssetInfo`] into the form reported by `bevy/asset/list`.
+#[cfg(feature = "bevy_asset")]
+fn asset_info_to_response(info: ManagedAssetInfo) -> BrpAssetInfo {
+    let load_state = match &info.load_state {
+        LoadState::NotLoaded => BrpLoadState::NotLoaded,
+        LoadState::Loading => BrpLoadState::Loading,
+        LoadState::Loaded => BrpLoadState::Loaded,
+        LoadState::Failed(_) => BrpLoadState::Failed,
+    };
+    let dependency_load_state = match &info.dependency_load_state {
+        DependencyLoadState::NotLoaded => BrpLoadState::NotLoaded,
+        DependencyLoadState::Loading => BrpLoadState::Loading,
+        DependencyLoadState::Loaded => BrpLoadState::Loaded,
+        DependencyLoadState::Failed(_) => BrpLoadState::Failed,
+    };
+    let recursive_dependency_load_state = match &info.recursive_dependency_load_state {
+        RecursiveDependencyLoadState::NotLoaded => BrpLoadState::NotLoaded,
+        RecursiveDependencyLoadState::Loading => BrpLoadState::Loading,
+        RecursiveDependencyLoadState::Loaded => BrpLoadState::Loaded,
+        RecursiveDependencyLoadState::Failed(_) => BrpLoadState::Failed,
+    };
+    let error = match (&info.load_state, &info.recursive_dependency_load_state) {
+        (LoadState::Failed(err), _) | (_, RecursiveDependencyLoadState::Failed(err)) => {
+            Some(err.to_string())
+        }
+        _ => None,
+    };
+
+    BrpAssetInfo {
+        id: info.id.to_string(),
+        path: info.path.as_ref().map(ToString::to_string),
+        load_state,
+        dependency_load_state,
+        recursive_dependency_load_state,
+        error,
+        strong_handles: info.strong_handle_count,
+    }
+}
+
 /// Immutably retrieves an entity from the [`World`], returning an error if the
 /// entity isn't present.
 fn get_entity(world: &World, entity: Entity) -> Result<EntityRef<'_>, BrpError> {
@@ -1387,6 +2777,30 @@ fn build_components_map<'a>(
     Ok(serialized_components_map)
 }
 
+/// Returns `true` if every one of the `value_filters` holds for the entity (`entity_ref`).
+fn matches_value_filters(
+    entity_ref: &FilteredEntityRef,
+    value_filters: &[(ComponentId, &ReflectComponent, BrpValueFilter)],
+    type_registry: &TypeRegistry,
+) -> AnyhowResult<bool> {
+    for (_, reflect_component, filter) in value_filters {
+        let Some(reflected) = reflect_component.reflect(entity_ref.clone()) else {
+            return Ok(false);
+        };
+        let field = reflected
+            .reflect_path(filter.path.as_str())
+            .map_err(|err| anyhow!("{}: {err}", filter.component))?;
+
+        let reflect_serializer = TypedReflectSerializer::new(field, type_registry);
+        let field = serde_json::to_value(&reflect_serializer)?;
+        if !filter.op.holds(&field, &filter.value) {
+            return Ok(false);
+        }
+    }
+
+    Ok(true)
+}
+
 /// Given an entity (`entity_ref`) and list of reflected component information
 /// (`paths_and_reflect_components`), return a map which associates each component to
 /// a boolean value indicating whether or not that component is present on the entity.
@@ -1491,6 +2905,64 @@ fn get_component_type_registration<'r>(
         .ok_or_else(|| anyhow!("Unknown component type: `{}`", component_path))
 }
 
+/// Given an event's type path and its serialized value, return the associated [`ReflectEvent`]
+/// along with the deserialized reflected value.
+fn deserialize_event<'r>(
+    type_registry: &'r TypeRegistry,
+    event_path: &str,
+    value: Value,
+) -> AnyhowResult<(&'r ReflectEvent, Box<dyn PartialReflect>)> {
+    let Some(event_registration) = type_registry.get_with_type_path(event_path) else {
+        return Err(anyhow!("Unknown event type: `{}`", event_path));
+    };
+    let Some(reflect_event) = event_registration.data::<ReflectEvent>() else {
+        return Err(anyhow!("Event `{}` isn't reflectable", event_path));
+    };
+
+    let reflected = TypedReflectDeserializer::new(event_registration, type_registry)
+        .deserialize(&value)
+        .map_err(|err| anyhow!("{event_path} is invalid: {err}"))?;
+
+    Ok((reflect_event, reflected))
+}
+
+/// Given a resource's type path and its serialized value, return the deserialized
+/// reflected value.
+fn deserialize_resource(
+    type_registry: &TypeRegistry,
+    resource_path: &str,
+    value: Value,
+) -> AnyhowResult<Box<dyn PartialReflect>> {
+    let resource_registration = get_resource_type_registration(type_registry, resource_path)?;
+    TypedReflectDeserializer::new(resource_registration, type_registry)
+        .deserialize(&value)
+        .map_err(|err| anyhow!("{resource_path} is invalid: {err}"))
+}
+
+/// Given a resource's type path, return the associated [`ReflectResource`] from the given
+/// `type_registry` if possible.
+fn get_reflect_resource<'r>(
+    type_registry: &'r TypeRegistry,
+    resource_path: &str,
+) -> AnyhowResult<&'r ReflectResource> {
+    let resource_registration = get_resource_type_registration(type_registry, resource_path)?;
+
+    resource_registration
+        .data::<ReflectResource>()
+        .ok_or_else(|| anyhow!("Resource `{}` isn't reflectable", resource_path))
+}
+
+/// Given a resource's type path, return the associated [`TypeRegistration`] from the given
+/// `type_registry` if possible.
+fn get_resource_type_registration<'r>(
+    type_registry: &'r TypeRegistry,
+    resource_path: &str,
+) -> AnyhowResult<&'r TypeRegistration> {
+    type_registry
+        .get_with_type_path(resource_path)
+        .ok_or_else(|| anyhow!("Unknown resource type: `{}`", resource_path))
+}
+
 #[cfg(test)]
 mod tests {
     /// A generic function that tests serialization and deserialization of any type
@@ -1512,7 +2984,12 @@ mod tests {
         );
     }
     use super::*;
-    use bevy_ecs::{component::Component, resource::Resource};
+    use bevy_ecs::{
+        component::Component,
+        event::{Event, Events},
+        resource::Resource,
+        schedule::{IntoSystemConfigs, ScheduleLabel},
+    };
     use bevy_reflect::Reflect;
 
     #[test]
@@ -1725,4 +3202,384 @@ mod tests {
         });
         assert_eq!(schema_as_value, value);
     }
+
+    #[test]
+    fn resource_round_trip() {
+        #[derive(Reflect, Resource, Default, Debug, PartialEq)]
+        #[reflect(Resource, Default)]
+        struct Settings {
+            volume: f32,
+            muted: bool,
+        }
+
+        let mut world = World::new();
+        let app_type_registry = AppTypeRegistry::default();
+        app_type_registry.write().register::<Settings>();
+        world.insert_resource(app_type_registry);
+
+        let resource = "bevy_remote::builtin_methods::tests::Settings";
+
+        let listed = process_remote_list_resources_request(In(None), &world).unwrap();
+        assert_eq!(listed, json!([resource]));
+
+        let error =
+            process_remote_get_resource_request(In(Some(json!({ "resource": resource }))), &world)
+                .unwrap_err();
+        assert_eq!(error.code, error_codes::RESOURCE_NOT_PRESENT);
+
+        process_remote_insert_resource_request(
+            In(Some(json!({
+                "resource": resource,
+                "value": { "volume": 0.5, "muted": false },
+            }))),
+            &mut world,
+        )
+        .unwrap();
+        process_remote_mutate_resource_request(
+            In(Some(
+                json!({ "resource": resource, "path": "muted", "value": true }),
+            )),
+            &mut world,
+        )
+        .unwrap();
+        assert_eq!(
+            world.resource::<Settings>(),
+            &Settings {
+                volume: 0.5,
+                muted: true
+            }
+        );
+
+        let value =
+            process_remote_get_resource_request(In(Some(json!({ "resource": resource }))), &world)
+                .unwrap();
+        assert_eq!(value, json!({ "value": { "volume": 0.5, "muted": true } }));
+
+        process_remote_remove_resource_request(
+            In(Some(json!({ "resource": resource }))),
+            &mut world,
+        )
+        .unwrap();
+        assert!(!world.contains_resource::<Settings>());
+    }
+
+    #[test]
+    fn query_value_filters_and_pagination() {
+        #[derive(Component, Reflect)]
+        #[reflect(Component)]
+        struct Height(f32);
+
+        let mut world = World::new();
+        let app_type_registry = AppTypeRegistry::default();
+        app_type_registry.write().register::<Height>();
+        world.insert_resource(app_type_registry);
+
+        let entities: Vec<Entity> = (0..10)
+            .map(|height| world.spawn(Height(height as f32)).id())
+            .collect();
+
+        let component = "bevy_remote::builtin_methods::tests::Height";
+        let mut query = |params: Value| {
+            let response = process_remote_query_request(In(Some(params)), &mut world).unwrap();
+            serde_json::from_value::<BrpQueryResponse>(response)
+                .unwrap()
+                .into_iter()
+                .map(|row| row.entity)
+                .collect::<Vec<_>>()
+        };
+
+        let filter = json!({
+            "values": [{ "component": component, "path": ".0", "op": "ge", "value": 5 }]
+        });
+        let page = query(json!({ "data": {}, "filter": filter, "limit": 3 }));
+        assert_eq!(page, entities[5..8]);
+        let page = query(json!({ "data": {}, "filter": filter, "limit": 3, "after": page[2] }));
+        assert_eq!(page, entities[8..]);
+
+        let page = query(json!({
+            "data": {},
+            "filter": { "values": [{ "component": component, "path": ".0", "op": "eq", "value": 2.0 }] },
+        }));
+        assert_eq!(page, [entities[2]]);
+    }
+
+    #[test]
+    fn schedule_graph() {
+        #[derive(ScheduleLabel, Clone, Debug, PartialEq, Eq, Hash)]
+        struct Update;
+
+        fn first() {}
+        fn second() {}
+
+        let mut world = World::new();
+        let mut schedule = Schedule::new(Update);
+        schedule.add_systems((first, second).chain());
+        world.add_schedule(schedule);
+        world.run_schedule(Update);
+
+        let listed = process_remote_schedule_list_request(In(None), &world).unwrap();
+        assert_eq!(listed, json!(["Update"]));
+
+        let error = process_remote_schedule_graph_request(
+            In(Some(json!({ "schedule": "Missing" }))),
+            &world,
+        )
+        .unwrap_err();
+        assert_eq!(error.code, error_codes::SCHEDULE_NOT_FOUND);
+
+        let graph = process_remote_schedule_graph_request(
+            In(Some(json!({ "schedule": "Update" }))),
+            &world,
+        )
+        .unwrap();
+        let graph: BrpScheduleGraphResponse = serde_json::from_value(graph).unwrap();
+        assert_eq!(graph.systems.len(), 2);
+        assert!(graph.systems.iter().all(|system| system.ran_last_frame));
+
+        let first = graph
+            .systems
+            .iter()
+            .find(|system| system.name.ends_with("first"))
+            .unwrap();
+        let second = graph
+            .systems
+            .iter()
+            .find(|system| system.name.ends_with("second"))
+            .unwrap();
+        assert!(graph.dependencies.contains(&BrpScheduleEdge {
+            from: BrpScheduleNode::System(first.id),
+            to: BrpScheduleNode::System(second.id),
+        }));
+
+        process_remote_stepping_set_breakpoint_request(
+            In(Some(json!({ "schedule": "Update", "system": second.id }))),
+            &mut world,
+        )
+        .unwrap();
+        let error = process_remote_stepping_set_breakpoint_request(
+            In(Some(json!({ "schedule": "Update", "system": 42 }))),
+            &mut world,
+        )
+        .unwrap_err();
+        assert_eq!(error.code, error_codes::SYSTEM_NOT_FOUND);
+        let error = process_remote_stepping_clear_breakpoint_request(
+            In(Some(json!({ "schedule": "Update", "system": 42 }))),
+            &mut world,
+        )
+        .unwrap_err();
+        assert_eq!(error.code, error_codes::SYSTEM_NOT_FOUND);
+    }
+
+    #[test]
+    fn send_event() {
+        #[derive(Event, Reflect, Clone)]
+        #[reflect(Event)]
+        struct Jump {
+            height: f32,
+        }
+
+        let mut world = World::new();
+        let app_type_registry = AppTypeRegistry::default();
+        app_type_registry.write().register::<Jump>();
+        world.insert_resource(app_type_registry);
+
+        let params = json!({
+            "event": "bevy_remote::builtin_methods::tests::Jump",
+            "value": { "height": 2.0 },
+        });
+
+        let error =
+            process_remote_send_event_request(In(Some(params.clone())), &mut world).unwrap_err();
+        assert_eq!(error.code, error_codes::EVENT_ERROR);
+
+        world.init_resource::<Events<Jump>>();
+        process_remote_send_event_request(In(Some(params)), &mut world).unwrap();
+        assert_eq!(world.resource::<Events<Jump>>().len(), 1);
+
+        let error = process_remote_trigger_event_request(
+            In(Some(json!({
+                "event": "bevy_remote::builtin_methods::tests::Jump",
+                "value": { "height": 2.0 },
+                "targets": [Entity::from_raw(42)],
+            }))),
+            &mut world,
+        )
+        .unwrap_err();
+        assert_eq!(error.code, error_codes::ENTITY_NOT_FOUND);
+    }
+
+    #[cfg(feature = "bevy_asset")]
+    #[test]
+    fn asset_methods() {
+        use bevy_app::{App, TaskPoolPlugin};
+        use bevy_asset::{
+            io::{
+                memory::{Dir, MemoryAssetReader},
+                AssetSource, AssetSourceId, Reader,
+            },
+            Asset, AssetApp, AssetLoader, AssetPlugin, LoadContext,
+        };
+        use std::path::Path;
+
+        #[derive(Asset, Reflect)]
+        struct Text(String);
+
+        struct TextLoader;
+
+        impl AssetLoader for TextLoader {
+            type Asset = Text;
+            type Settings = ();
+            type Error = std::io::Error;
+
+            async fn load(
+                &self,
+                reader: &mut dyn Reader,
+                _settings: &Self::Settings,
+                _load_context: &mut LoadContext<'_>,
+            ) -> Result<Self::Asset, Self::Error> {
+                let mut bytes = Vec::new();
+                reader.read_to_end(&mut bytes).await?;
+                Ok(Text(String::from_utf8_lossy(&bytes).into_owned()))
+            }
+
+            fn extensions(&self) -> &[&str] {
+                &["txt"]
+            }
+        }
+
+        let dir = Dir::default();
+        dir.insert_asset_text(Path::new("hello.txt"), "hello");
+
+        let mut app = App::new();
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build()
+                .with_reader(move || Box::new(MemoryAssetReader { root: dir.clone() })),
+        )
+        .add_plugins((TaskPoolPlugin::default(), AssetPlugin::default()))
+        .init_asset::<Text>()
+        .register_type::<Text>()
+        .register_asset_loader(TextLoader);
+
+        let text_type = "bevy_remote::builtin_methods::tests::Text";
+        let list = |world: &World, params: Option<Value>| -> BrpAssetListResponse {
+            serde_json::from_value(process_remote_asset_list_request(In(params), world).unwrap())
+                .unwrap()
+        };
+
+        for path in ["hello.txt", "missing.txt"] {
+            process_remote_asset_load_request(In(Some(json!({ "path": path }))), app.world_mut())
+                .unwrap();
+        }
+        for _ in 0..1000 {
+            app.update();
+            let assets = list(app.world(), None);
+            let settled = assets.values().flatten().all(|asset| {
+                asset.load_state != BrpLoadState::Loading
+                    && asset.recursive_dependency_load_state != BrpLoadState::Loading
+            });
+            if settled {
+                break;
+            }
+            std::thread::sleep(core::time::Duration::from_millis(1));
+        }
+
+        let assets = list(app.world(), Some(json!({ "asset_type": text_type })));
+        assert_eq!(assets.len(), 1);
+        let texts = &assets[text_type];
+        assert_eq!(texts.len(), 1);
+        assert_eq!(texts[0].path.as_deref(), Some("hello.txt"));
+        assert_eq!(texts[0].load_state, BrpLoadState::Loaded);
+        assert_eq!(texts[0].strong_handles, 1);
+
+        // The handles returned by `load_untyped` point to assets of a type which isn't
+        // registered.
+        let assets = list(app.world(), None);
+        let untyped = &assets[UNREGISTERED_ASSET_TYPE];
+        let missing = untyped
+            .iter()
+            .find(|asset| {
+                asset
+                    .path
+                    .as_ref()
+                    .is_some_and(|path| path.ends_with("missing.txt"))
+            })
+            .unwrap();
+        assert_eq!(missing.load_state, BrpLoadState::Failed);
+        assert!(missing.error.is_some());
+
+        process_remote_asset_reload_request(In(Some(json!({ "path": "hello.txt" }))), app.world())
+            .unwrap();
+
+        let error = process_remote_asset_load_request(
+            In(Some(json!({ "path": "invalid://#" }))),
+            app.world_mut(),
+        )
+        .unwrap_err();
+        assert_eq!(error.code, error_codes::INVALID_PARAMS);
+
+        process_remote_asset_unload_request(
+            In(Some(json!({ "path": "hello.txt" }))),
+            app.world_mut(),
+        )
+        .unwrap();
+        let error = process_remote_asset_unload_request(
+            In(Some(json!({ "path": "hello.txt" }))),
+            app.world_mut(),
+        )
+        .unwrap_err();
+        assert_eq!(error.code, error_codes::ASSET_NOT_LOADED);
+
+        // Once BRP releases its handle, nothing keeps the asset alive.
+        app.update();
+        let assets = list(app.world(), None);
+        assert!(assets
+            .get(text_type)
+            .is_none_or(|texts| texts.iter().all(|text| text.strong_handles == 0)));
+    }
+
+    #[cfg(feature = "reflect_functions")]
+    #[test]
+    fn call_registered_function() {
+        fn add(a: i32, b: &i32) -> i32 {
+            a + *b
+        }
+
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        let function_registry = AppFunctionRegistry::default();
+        function_registry
+            .write()
+            .register_with_name("add", add)
+            .unwrap();
+        world.insert_resource(function_registry);
+
+        let listed = process_remote_list_functions_request(In(None), &world).unwrap();
+        let listed: BrpListFunctionsResponse = serde_json::from_value(listed).unwrap();
+        assert_eq!(listed.len(), 1);
+        assert_eq!(listed[0].name, "add");
+        assert_eq!(listed[0].signatures[0].args[1].type_path, "&i32");
+        assert_eq!(listed[0].signatures[0].return_type, "i32");
+
+        let result = process_remote_call_function_request(
+            In(Some(json!({ "function": "add", "args": [2, 3] }))),
+            &world,
+        )
+        .unwrap();
+        assert_eq!(result, json!(5));
+
+        let error = process_remote_call_function_request(
+            In(Some(json!({ "function": "add", "args": [2] }))),
+            &world,
+        )
+        .unwrap_err();
+        assert_eq!(error.code, error_codes::FUNCTION_ERROR);
+
+        let error = process_remote_call_function_request(
+            In(Some(json!({ "function": "sub", "args": [] }))),
+            &world,
+        )
+        .unwrap_err();
+        assert_eq!(error.code, error_codes::FUNCTION_NOT_FOUND);
+    }
 }
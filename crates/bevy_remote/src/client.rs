@@ -0,0 +1,865 @@
+//! A typed client for the Bevy Remote Protocol.
+//!
+//! A [`BrpClient`] builds requests for the built-in methods out of their params types, such as
+//! [`BrpGetParams`] and [`BrpQueryParams`], sends them over a [`BrpTransport`], and parses the
+//! responses into their response types. Custom methods can be called with [`BrpClient::call`].
+//!
+//! Two transports are provided:
+//! - [`ChannelTransport`] sends requests straight into the [`BrpReceiver`](crate::BrpReceiver)
+//!   of an app in the same process, which is useful for integration tests.
+//! - `HttpTransport` (behind the `http` feature) sends requests to a
+//!   [`RemoteHttpPlugin`](crate::http::RemoteHttpPlugin) server.
+//!
+//! Watching methods such as `bevy/get+watch` stream their responses, which are received
+//! through a [`BrpWatch`].
+//!
+//! ## Example
+//!
+//! ```ignore
+//! let client = BrpClient::new(HttpTransport::default());
+//! let entities = client
+//!     .query(BrpQueryParams {
+//!         data: BrpQuery {
+//!             components: vec!["bevy_transform::components::transform::Transform".into()],
+//!             ..default()
+//!         },
+//!         filter: default(),
+//!         strict: false,
+//!         limit: Some(100),
+//!         after: None,
+//!     })
+//!     .await?;
+//! ```
+
+use core::{
+    future::Future,
+    marker::PhantomData,
+    pin::Pin,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use async_channel::Sender;
+use bevy_platform_support::collections::HashMap;
+use bevy_tasks::futures_lite::{Stream, StreamExt as _};
+use serde::{de::DeserializeOwned, Serialize};
+use serde_json::Value;
+use thiserror::Error;
+
+use crate::{builtin_methods::*, BrpError, BrpMessage, BrpRequest, BrpResult, BrpSender};
+
+#[cfg(all(feature = "http", not(target_family = "wasm")))]
+pub use http_transport::HttpTransport;
+
+/// An error that occurred while making a request with a [`BrpClient`].
+#[derive(Debug, Error)]
+pub enum ClientError {
+    /// The server responded with an error.
+    #[error("the server responded with an error: {} ({})", .0.message, .0.code)]
+    Remote(BrpError),
+    /// The params couldn't be serialized, or the response couldn't be parsed.
+    #[error("couldn't serialize the request or parse the response: {0}")]
+    Serialization(#[from] serde_json::Error),
+    /// The server responded with an HTTP status other than success, e.g. because the client
+    /// isn't authorized.
+    #[error("the server responded with HTTP status {0}")]
+    Status(u16),
+    /// The request couldn't be sent, or the connection closed before a response arrived.
+    #[error("transport error: {0}")]
+    Transport(anyhow::Error),
+}
+
+/// The results of a watching request, as received by a [`BrpTransport`].
+pub type BrpResultStream = Pin<Box<dyn Stream<Item = Result<BrpResult, ClientError>> + Send>>;
+
+/// A way of sending requests to a Bevy Remote Protocol server.
+pub trait BrpTransport {
+    /// Sends `request` to the server and waits for its result.
+    fn send(
+        &self,
+        request: BrpRequest,
+    ) -> impl Future<Output = Result<BrpResult, ClientError>> + Send;
+
+    /// Sends a watching `request` to the server, returning a stream of every result it
+    /// produces.
+    ///
+    /// Dropping the stream cancels the watching request.
+    fn watch(
+        &self,
+        request: BrpRequest,
+    ) -> impl Future<Output = Result<BrpResultStream, ClientError>> + Send;
+}
+
+/// The results of a watching request made with a [`BrpClient`], parsed as `R`.
+///
+/// Dropping this cancels the watching request.
+pub struct BrpWatch<R> {
+    stream: BrpResultStream,
+    _marker: PhantomData<fn() -> R>,
+}
+
+impl<R: DeserializeOwned> BrpWatch<R> {
+    /// Waits for the next result of the watching request.
+    ///
+    /// Returns [`None`] once the server stops sending results, e.g. because the connection
+    /// closed.
+    pub async fn next(&mut self) -> Option<Result<R, ClientError>> {
+        let result = self.stream.next().await?;
+        Some(
+            result
+                .and_then(|result| result.map_err(ClientError::Remote))
+                .and_then(|value| Ok(serde_json::from_value(value)?)),
+        )
+    }
+}
+
+impl<R> core::fmt::Debug for BrpWatch<R> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("BrpWatch").finish_non_exhaustive()
+    }
+}
+
+/// A [`BrpTransport`] that sends requests to an app in the same process through its
+/// [`BrpSender`].
+///
+/// Requests are only answered while the app updates, so a request has to be sent before the
+/// update that processes it:
+///
+/// ```ignore
+/// let client = BrpClient::new(ChannelTransport::from(app.world().resource::<BrpSender>()));
+/// let mut response = Box::pin(client.list_resources());
+/// // Send the request, then update the app to answer it.
+/// assert!(block_on(poll_once(&mut response)).is_none());
+/// app.update();
+/// let resources = block_on(response)?;
+/// ```
+///
+/// The [`BrpSender`] is only inserted once the app has started up.
+#[derive(Debug, Clone)]
+pub struct ChannelTransport {
+    sender: Sender<BrpMessage>,
+}
+
+impl ChannelTransport {
+    /// Creates a transport sending requests to the given channel.
+    pub fn new(sender: Sender<BrpMessage>) -> Self {
+        Self { sender }
+    }
+}
+
+impl From<&BrpSender> for ChannelTransport {
+    fn from(sender: &BrpSender) -> Self {
+        Self::new((**sender).clone())
+    }
+}
+
+impl BrpTransport for ChannelTransport {
+    async fn send(&self, request: BrpRequest) -> Result<BrpResult, ClientError> {
+        let (result_sender, result_receiver) = async_channel::bounded(1);
+        self.sender
+            .send(BrpMessage {
+                method: request.method,
+                params: request.params,
+                sender: result_sender,
+            })
+            .await
+            .map_err(|err| ClientError::Transport(err.into()))?;
+
+        result_receiver
+            .recv()
+            .await
+            .map_err(|err| ClientError::Transport(err.into()))
+    }
+
+    async fn watch(&self, request: BrpRequest) -> Result<BrpResultStream, ClientError> {
+        // This matches the capacity the transports give watching requests.
+        let (result_sender, result_receiver) = async_channel::bounded(8);
+        self.sender
+            .send(BrpMessage {
+                method: request.method,
+                params: request.params,
+                sender: result_sender,
+            })
+            .await
+            .map_err(|err| ClientError::Transport(err.into()))?;
+
+        Ok(Box::pin(result_receiver.map(Ok)))
+    }
+}
+
+#[cfg(all(feature = "http", not(target_family = "wasm")))]
+mod http_transport {
+    use alloc::boxed::Box;
+    use core::{
+        net::{IpAddr, SocketAddr},
+        pin::Pin,
+    };
+    use std::net::TcpStream;
+
+    use async_io::Async;
+    use bevy_tasks::futures_lite::{future, stream};
+    use http_body_util::{BodyExt as _, Collected, Full};
+    use hyper::{
+        body::{Bytes, Incoming},
+        client::conn::http1,
+        header::{self, HeaderMap, HeaderName, HeaderValue},
+        Request, Response,
+    };
+    use serde::Deserialize;
+    use smol_hyper::rt::FuturesIo;
+
+    use super::{BrpResultStream, BrpTransport, ClientError};
+    use crate::{
+        http::{DEFAULT_ADDR, DEFAULT_PORT},
+        BrpPayload, BrpRequest, BrpResult,
+    };
+
+    /// A response as received by a client.
+    ///
+    /// Unlike [`BrpResponse`](crate::BrpResponse), this doesn't need to outlive the data
+    /// it's parsed from.
+    #[derive(Debug, Deserialize)]
+    struct ClientResponse {
+        #[serde(flatten)]
+        payload: BrpPayload,
+    }
+
+    /// A [`BrpTransport`] that sends requests to a
+    /// [`RemoteHttpPlugin`](crate::http::RemoteHttpPlugin) server.
+    ///
+    /// The defaults are:
+    /// - [`DEFAULT_ADDR`] : 127.0.0.1.
+    /// - [`DEFAULT_PORT`] : 15702.
+    #[derive(Debug, Clone)]
+    pub struct HttpTransport {
+        /// The address of the server.
+        address: IpAddr,
+        /// The port the server listens on.
+        port: u16,
+        /// The headers to include in each request.
+        headers: HeaderMap,
+    }
+
+    impl Default for HttpTransport {
+        fn default() -> Self {
+            Self {
+                address: DEFAULT_ADDR,
+                port: DEFAULT_PORT,
+                headers: HeaderMap::new(),
+            }
+        }
+    }
+
+    impl HttpTransport {
+        /// Set the IP address of the server.
+        #[must_use]
+        pub fn with_address(mut self, address: impl Into<IpAddr>) -> Self {
+            self.address = address.into();
+            self
+        }
+        /// Set the port the server listens on.
+        #[must_use]
+        pub fn with_port(mut self, port: u16) -> Self {
+            self.port = port;
+            self
+        }
+        /// Add a header to each request.
+        #[must_use]
+        pub fn with_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+            self.headers.insert(name, value);
+            self
+        }
+        /// Authenticate each request with the given bearer token.
+        ///
+        /// # Panics
+        ///
+        /// Panics if the token contains characters that aren't allowed in a header.
+        #[must_use]
+        pub fn with_bearer_token(self, token: &str) -> Self {
+            let Ok(value) = HeaderValue::try_from(format!("Bearer {token}")) else {
+                panic!("Invalid bearer token")
+            };
+            self.with_header(header::AUTHORIZATION, value)
+        }
+    }
+
+    /// The connection of a single request, which has to be driven while the request is in
+    /// flight.
+    type Connection = Pin<Box<http1::Connection<FuturesIo<Async<TcpStream>>, Full<Bytes>>>>;
+
+    impl HttpTransport {
+        /// Connects to the server and sends `request`, returning the response once its head
+        /// has arrived, along with the connection that delivers its body.
+        async fn open(
+            &self,
+            request: &BrpRequest,
+        ) -> Result<(Response<Incoming>, Option<Connection>), ClientError> {
+            let body = serde_json::to_vec(request)?;
+
+            let stream = Async::<TcpStream>::connect(SocketAddr::new(self.address, self.port))
+                .await
+                .map_err(|err| ClientError::Transport(err.into()))?;
+            let (mut sender, connection) = http1::handshake(FuturesIo::new(stream))
+                .await
+                .map_err(|err| ClientError::Transport(err.into()))?;
+            let mut connection = Some(Box::pin(connection));
+
+            let mut http_request = Request::post("/")
+                .header(header::HOST, format!("{}:{}", self.address, self.port))
+                .header(header::CONTENT_TYPE, "application/json")
+                .body(Full::new(Bytes::from(body)))
+                .map_err(|err| ClientError::Transport(err.into()))?;
+            http_request.headers_mut().extend(self.headers.clone());
+
+            let response = future::or(
+                async {
+                    sender
+                        .send_request(http_request)
+                        .await
+                        .map_err(|err| ClientError::Transport(err.into()))
+                },
+                drive_connection(&mut connection),
+            )
+            .await?;
+
+            if !response.status().is_success() {
+                return Err(ClientError::Status(response.status().as_u16()));
+            }
+            Ok((response, connection))
+        }
+    }
+
+    /// Drives the connection until it closes, and then never completes, leaving it to the
+    /// response and its body to report whether the request succeeded.
+    async fn drive_connection<T>(connection: &mut Option<Connection>) -> T {
+        if let Some(open) = connection {
+            let _ = open.await;
+            *connection = None;
+        }
+        future::pending().await
+    }
+
+    /// Parses the result out of a serialized response.
+    fn parse_response(bytes: &[u8]) -> Result<BrpResult, ClientError> {
+        let response: ClientResponse = serde_json::from_slice(bytes)?;
+        Ok(match response.payload {
+            BrpPayload::Result(value) => Ok(value),
+            BrpPayload::Error(err) => Err(err),
+        })
+    }
+
+    /// Removes the first complete server-sent event from `buffer`, returning its data.
+    fn take_event(buffer: &mut Vec<u8>) -> Option<Vec<u8>> {
+        let end = buffer.windows(2).position(|window| window == b"\n\n")?;
+        let event: Vec<u8> = buffer.drain(..end + 2).collect();
+        let data = event[..end]
+            .split(|&byte| byte == b'\n')
+            .filter_map(|line| line.strip_prefix(b"data:"))
+            .flat_map(|data| data.strip_prefix(b" ").unwrap_or(data).iter().copied())
+            .collect();
+        Some(data)
+    }
+
+    /// The state of the stream of results of a watching request.
+    struct EventStream {
+        body: Incoming,
+        /// The connection, until it closes.
+        connection: Option<Connection>,
+        /// The bytes received which don't yet make up a complete event.
+        buffer: Vec<u8>,
+    }
+
+    impl BrpTransport for HttpTransport {
+        async fn send(&self, request: BrpRequest) -> Result<BrpResult, ClientError> {
+            let (response, mut connection) = self.open(&request).await?;
+            let bytes = future::or(
+                async {
+                    response
+                        .into_body()
+                        .collect()
+                        .await
+                        .map(Collected::to_bytes)
+                        .map_err(|err| ClientError::Transport(err.into()))
+                },
+                drive_connection(&mut connection),
+            )
+            .await?;
+
+            parse_response(&bytes)
+        }
+
+        async fn watch(&self, request: BrpRequest) -> Result<BrpResultStream, ClientError> {
+            let (response, connection) = self.open(&request).await?;
+            let state = EventStream {
+                body: response.into_body(),
+                connection,
+                buffer: Vec::new(),
+            };
+
+            Ok(Box::pin(stream::unfold(state, |mut state| async move {
+                loop {
+                    if let Some(data) = take_event(&mut state.buffer) {
+                        return Some((parse_response(&data), state));
+                    }
+
+                    let EventStream {
+                        body, connection, ..
+                    } = &mut state;
+                    let frame =
+                        future::or(async { body.frame().await }, drive_connection(connection))
+                            .await;
+
+                    match frame {
+                        Some(Ok(frame)) => {
+                            if let Ok(data) = frame.into_data() {
+                                state.buffer.extend_from_slice(&data);
+                            }
+                        }
+                        Some(Err(err)) => {
+                            return Some((Err(ClientError::Transport(err.into())), state));
+                        }
+                        None => return None,
+                    }
+                }
+            })))
+        }
+    }
+}
+
+/// A client for the Bevy Remote Protocol with typed methods for each built-in method.
+///
+/// See the [module-level documentation](self) for an example.
+#[derive(Debug)]
+pub struct BrpClient<T> {
+    transport: T,
+    next_id: AtomicU64,
+}
+
+impl<T: BrpTransport> BrpClient<T> {
+    /// Creates a client sending requests over the given transport.
+    pub fn new(transport: T) -> Self {
+        Self {
+            transport,
+            next_id: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the transport the client sends requests over.
+    pub fn transport(&self) -> &T {
+        &self.transport
+    }
+
+    /// Builds a request for the given method with a unique `id`.
+    pub fn request(&self, method: impl Into<String>, params: Option<Value>) -> BrpRequest {
+        BrpRequest {
+            jsonrpc: String::from("2.0"),
+            method: method.into(),
+            id: Some(Value::from(self.next_id.fetch_add(1, Ordering::Relaxed))),
+            params,
+        }
+    }
+
+    /// Calls the given method and parses its result.
+    ///
+    /// The params are omitted if they serialize to null, e.g. if they are `()`.
+    pub async fn call<R: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: impl Serialize,
+    ) -> Result<R, ClientError> {
+        let params = match serde_json::to_value(params)? {
+            Value::Null => None,
+            params => Some(params),
+        };
+        let result = self
+            .transport
+            .send(self.request(method, params))
+            .await?
+            .map_err(ClientError::Remote)?;
+        Ok(serde_json::from_value(result)?)
+    }
+
+    /// Calls the given watching method, returning the stream of its results.
+    ///
+    /// The params are omitted if they serialize to null, e.g. if they are `()`.
+    pub async fn watch<R: DeserializeOwned>(
+        &self,
+        method: &str,
+        params: impl Serialize,
+    ) -> Result<BrpWatch<R>, ClientError> {
+        let params = match serde_json::to_value(params)? {
+            Value::Null => None,
+            params => Some(params),
+        };
+        let stream = self.transport.watch(self.request(method, params)).await?;
+        Ok(BrpWatch {
+            stream,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Retrieves components from an entity with `bevy/get`.
+    pub async fn get(&self, params: BrpGetParams) -> Result<BrpGetResponse, ClientError> {
+        self.call(BRP_GET_METHOD, params).await
+    }
+
+    /// Queries the entities of the world with `bevy/query`.
+    pub async fn query(&self, params: BrpQueryParams) -> Result<BrpQueryResponse, ClientError> {
+        self.call(BRP_QUERY_METHOD, params).await
+    }
+
+    /// Spawns an entity with `bevy/spawn`.
+    pub async fn spawn(&self, params: BrpSpawnParams) -> Result<BrpSpawnResponse, ClientError> {
+        self.call(BRP_SPAWN_METHOD, params).await
+    }
+
+    /// Despawns an entity with `bevy/destroy`.
+    pub async fn destroy(&self, params: BrpDestroyParams) -> Result<(), ClientError> {
+        self.call(BRP_DESTROY_METHOD, params).await
+    }
+
+    /// Removes components from an entity with `bevy/remove`.
+    pub async fn remove(&self, params: BrpRemoveParams) -> Result<(), ClientError> {
+        self.call(BRP_REMOVE_METHOD, params).await
+    }
+
+    /// Inserts components into an entity with `bevy/insert`.
+    pub async fn insert(&self, params: BrpInsertParams) -> Result<(), ClientError> {
+        self.call(BRP_INSERT_METHOD, params).await
+    }
+
+    /// Assigns a new parent to entities with `bevy/reparent`.
+    pub async fn reparent(&self, params: BrpReparentParams) -> Result<(), ClientError> {
+        self.call(BRP_REPARENT_METHOD, params).await
+    }
+
+    /// Lists the registered components, or those on an entity, with `bevy/list`.
+    pub async fn list(
+        &self,
+        params: Option<BrpListParams>,
+    ) -> Result<BrpListResponse, ClientError> {
+        self.call(BRP_LIST_METHOD, params).await
+    }
+
+    /// Watches components of an entity for changes with `bevy/get+watch`.
+    pub async fn get_watch(
+        &self,
+        params: BrpGetParams,
+    ) -> Result<BrpWatch<BrpGetWatchingResponse>, ClientError> {
+        self.watch(BRP_GET_AND_WATCH_METHOD, params).await
+    }
+
+    /// Watches the components added to and removed from an entity with `bevy/list+watch`.
+    pub async fn list_watch(
+        &self,
+        params: BrpListParams,
+    ) -> Result<BrpWatch<BrpListWatchingResponse>, ClientError> {
+        self.watch(BRP_LIST_AND_WATCH_METHOD, params).await
+    }
+
+    /// Mutates a field of a component with `bevy/mutate_component`.
+    pub async fn mutate_component(&self, params: BrpMutateParams) -> Result<(), ClientError> {
+        self.call(BRP_MUTATE_COMPONENT_METHOD, params).await
+    }
+
+    /// Retrieves the value of a resource with `bevy/get_resource`.
+    pub async fn get_resource(
+        &self,
+        params: BrpGetResourceParams,
+    ) -> Result<BrpGetResourceResponse, ClientError> {
+        self.call(BRP_GET_RESOURCE_METHOD, params).await
+    }
+
+    /// Watches the value of a resource for changes with `bevy/get_resource+watch`.
+    pub async fn get_resource_watch(
+        &self,
+        params: BrpGetResourceParams,
+    ) -> Result<BrpWatch<BrpGetResourceResponse>, ClientError> {
+        self.watch(BRP_GET_RESOURCE_AND_WATCH_METHOD, params).await
+    }
+
+    /// Inserts a resource with `bevy/insert_resource`.
+    pub async fn insert_resource(
+        &self,
+        params: BrpInsertResourceParams,
+    ) -> Result<(), ClientError> {
+        self.call(BRP_INSERT_RESOURCE_METHOD, params).await
+    }
+
+    /// Removes a resource with `bevy/remove_resource`.
+    pub async fn remove_resource(
+        &self,
+        params: BrpRemoveResourceParams,
+    ) -> Result<(), ClientError> {
+        self.call(BRP_REMOVE_RESOURCE_METHOD, params).await
+    }
+
+    /// Mutates a field of a resource with `bevy/mutate_resource`.
+    pub async fn mutate_resource(
+        &self,
+        params: BrpMutateResourceParams,
+    ) -> Result<(), ClientError> {
+        self.call(BRP_MUTATE_RESOURCE_METHOD, params).await
+    }
+
+    /// Lists the registered resources with `bevy/list_resources`.
+    pub async fn list_resources(&self) -> Result<BrpListResourcesResponse, ClientError> {
+        self.call(BRP_LIST_RESOURCES_METHOD, ()).await
+    }
+
+    /// Sends an event with `bevy/send_event`.
+    pub async fn send_event(&self, params: BrpSendEventParams) -> Result<(), ClientError> {
+        self.call(BRP_SEND_EVENT_METHOD, params).await
+    }
+
+    /// Triggers an event with `bevy/trigger_event`.
+    pub async fn trigger_event(&self, params: BrpTriggerEventParams) -> Result<(), ClientError> {
+        self.call(BRP_TRIGGER_EVENT_METHOD, params).await
+    }
+
+    /// Lists the schedules with `bevy/schedule/list`.
+    pub async fn schedule_list(&self) -> Result<BrpScheduleListResponse, ClientError> {
+        self.call(BRP_SCHEDULE_LIST_METHOD, ()).await
+    }
+
+    /// Retrieves the systems, sets and edges of a schedule with `bevy/schedule/graph`.
+    pub async fn schedule_graph(
+        &self,
+        params: BrpScheduleParams,
+    ) -> Result<BrpScheduleGraphResponse, ClientError> {
+        self.call(BRP_SCHEDULE_GRAPH_METHOD, params).await
+    }
+
+    /// Retrieves the state of stepping with `bevy/stepping/state`.
+    pub async fn stepping_state(&self) -> Result<BrpSteppingStateResponse, ClientError> {
+        self.call(BRP_STEPPING_STATE_METHOD, ()).await
+    }
+
+    /// Enables stepping with `bevy/stepping/enable`.
+    pub async fn stepping_enable(&self) -> Result<(), ClientError> {
+        self.call(BRP_STEPPING_ENABLE_METHOD, ()).await
+    }
+
+    /// Disables stepping with `bevy/stepping/disable`.
+    pub async fn stepping_disable(&self) -> Result<(), ClientError> {
+        self.call(BRP_STEPPING_DISABLE_METHOD, ()).await
+    }
+
+    /// Runs the next system with `bevy/stepping/step_system`.
+    pub async fn stepping_step_system(&self) -> Result<(), ClientError> {
+        self.call(BRP_STEPPING_STEP_SYSTEM_METHOD, ()).await
+    }
+
+    /// Runs the rest of the stepping frame with `bevy/stepping/continue`.
+    pub async fn stepping_continue(&self) -> Result<(), ClientError> {
+        self.call(BRP_STEPPING_CONTINUE_METHOD, ()).await
+    }
+
+    /// Enables stepping for a schedule with `bevy/stepping/add_schedule`.
+    pub async fn stepping_add_schedule(
+        &self,
+        params: BrpScheduleParams,
+    ) -> Result<(), ClientError> {
+        self.call(BRP_STEPPING_ADD_SCHEDULE_METHOD, params).await
+    }
+
+    /// Disables stepping for a schedule with `bevy/stepping/remove_schedule`.
+    pub async fn stepping_remove_schedule(
+        &self,
+        params: BrpScheduleParams,
+    ) -> Result<(), ClientError> {
+        self.call(BRP_STEPPING_REMOVE_SCHEDULE_METHOD, params).await
+    }
+
+    /// Sets a breakpoint on a system with `bevy/stepping/set_breakpoint`.
+    pub async fn stepping_set_breakpoint(
+        &self,
+        params: BrpSteppingSystemParams,
+    ) -> Result<(), ClientError> {
+        self.call(BRP_STEPPING_SET_BREAKPOINT_METHOD, params).await
+    }
+
+    /// Clears a breakpoint on a system with `bevy/stepping/clear_breakpoint`.
+    pub async fn stepping_clear_breakpoint(
+        &self,
+        params: BrpSteppingSystemParams,
+    ) -> Result<(), ClientError> {
+        self.call(BRP_STEPPING_CLEAR_BREAKPOINT_METHOD, params)
+            .await
+    }
+
+    /// Lists the assets tracked by the asset server with `bevy/asset/list`.
+    #[cfg(feature = "bevy_asset")]
+    pub async fn asset_list(
+        &self,
+        params: BrpAssetListParams,
+    ) -> Result<BrpAssetListResponse, ClientError> {
+        self.call(BRP_ASSET_LIST_METHOD, params).await
+    }
+
+    /// Starts loading an asset and keeps it alive with `bevy/asset/load`.
+    #[cfg(feature = "bevy_asset")]
+    pub async fn asset_load(&self, params: BrpAssetPathParams) -> Result<(), ClientError> {
+        self.call(BRP_ASSET_LOAD_METHOD, params).await
+    }
+
+    /// Reloads an asset from its source with `bevy/asset/reload`.
+    #[cfg(feature = "bevy_asset")]
+    pub async fn asset_reload(&self, params: BrpAssetPathParams) -> Result<(), ClientError> {
+        self.call(BRP_ASSET_RELOAD_METHOD, params).await
+    }
+
+    /// Releases an asset loaded with `bevy/asset/load` with `bevy/asset/unload`.
+    #[cfg(feature = "bevy_asset")]
+    pub async fn asset_unload(&self, params: BrpAssetPathParams) -> Result<(), ClientError> {
+        self.call(BRP_ASSET_UNLOAD_METHOD, params).await
+    }
+
+    /// Exports the JSON schemas of the registered types with `bevy/registry/schema`.
+    pub async fn registry_schema(
+        &self,
+        params: BrpJsonSchemaQueryFilter,
+    ) -> Result<HashMap<String, JsonSchemaBevyType>, ClientError> {
+        self.call(BRP_REGISTRY_SCHEMA_METHOD, params).await
+    }
+
+    /// Lists the reflected functions with `bevy/list_functions`.
+    #[cfg(feature = "reflect_functions")]
+    pub async fn list_functions(&self) -> Result<BrpListFunctionsResponse, ClientError> {
+        self.call(BRP_LIST_FUNCTIONS_METHOD, ()).await
+    }
+
+    /// Calls a reflected function with `bevy/call_function`, returning its serialized result.
+    #[cfg(feature = "reflect_functions")]
+    pub async fn call_function(&self, params: BrpCallFunctionParams) -> Result<Value, ClientError> {
+        self.call(BRP_CALL_FUNCTION_METHOD, params).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::RemotePlugin;
+    use bevy_app::App;
+    use bevy_ecs::{reflect::ReflectResource, resource::Resource};
+    use bevy_reflect::Reflect;
+    use bevy_tasks::futures_lite::future::{block_on, poll_once};
+    use serde_json::json;
+
+    #[derive(Resource, Reflect, Default)]
+    #[reflect(Resource)]
+    struct Score(u32);
+
+    /// Updates the app until the given future completes.
+    fn run_until_complete<F: Future>(app: &mut App, future: F) -> F::Output {
+        let mut future = core::pin::pin!(future);
+        for _ in 0..1000 {
+            if let Some(output) = block_on(poll_once(&mut future)) {
+                return output;
+            }
+            app.update();
+            std::thread::sleep(core::time::Duration::from_millis(1));
+        }
+        panic!("The future didn't complete within 1000 updates");
+    }
+
+    /// Checks that a client can read a resource and watch it for changes.
+    fn check_score<T: BrpTransport>(app: &mut App, client: &BrpClient<T>) {
+        let resources = run_until_complete(app, client.list_resources()).unwrap();
+        assert!(resources.contains(&String::from("bevy_remote::client::tests::Score")));
+
+        let params = BrpGetResourceParams {
+            resource: String::from("bevy_remote::client::tests::Score"),
+        };
+        let mut watch = run_until_complete(app, client.get_resource_watch(params)).unwrap();
+        app.world_mut().resource_mut::<Score>().0 = 5;
+        let response = run_until_complete(app, watch.next()).unwrap().unwrap();
+        assert_eq!(response.value, json!(5));
+    }
+
+    #[test]
+    fn in_process_round_trip() {
+        let mut app = App::new();
+        app.add_plugins(RemotePlugin::default())
+            .register_type::<Score>()
+            .init_resource::<Score>();
+        app.update();
+
+        let client = BrpClient::new(ChannelTransport::from(app.world().resource::<BrpSender>()));
+
+        let mut response = Box::pin(client.list_resources());
+        assert!(block_on(poll_once(&mut response)).is_none());
+        app.update();
+        let resources = block_on(response).unwrap();
+        assert!(resources.contains(&String::from("bevy_remote::client::tests::Score")));
+
+        let mut response = Box::pin(client.get_resource(BrpGetResourceParams {
+            resource: String::from("bevy_remote::client::tests::Missing"),
+        }));
+        assert!(block_on(poll_once(&mut response)).is_none());
+        app.update();
+        let Err(ClientError::Remote(err)) = block_on(response) else {
+            panic!("Expected an error for an unregistered resource");
+        };
+        assert_eq!(err.code, crate::error_codes::RESOURCE_ERROR);
+    }
+
+    #[test]
+    fn in_process_watch() {
+        let mut app = App::new();
+        app.add_plugins(RemotePlugin::default())
+            .register_type::<Score>()
+            .init_resource::<Score>();
+        app.update();
+
+        let client = BrpClient::new(ChannelTransport::from(app.world().resource::<BrpSender>()));
+        check_score(&mut app, &client);
+    }
+
+    #[cfg(all(feature = "http", not(target_family = "wasm")))]
+    #[test]
+    fn http_round_trip() {
+        use crate::http::{MethodPermissions, RemoteHttpPlugin, DEFAULT_ADDR};
+        use bevy_app::TaskPoolPlugin;
+
+        let port = std::net::TcpListener::bind((DEFAULT_ADDR, 0))
+            .and_then(|listener| listener.local_addr())
+            .unwrap()
+            .port();
+
+        let mut app = App::new();
+        app.add_plugins((
+            TaskPoolPlugin::default(),
+            RemotePlugin::default(),
+            RemoteHttpPlugin::default()
+                .with_port(port)
+                .with_bearer_token("secret", MethodPermissions::all()),
+        ))
+        .register_type::<Score>()
+        .init_resource::<Score>();
+        app.update();
+
+        let client = BrpClient::new(
+            HttpTransport::default()
+                .with_port(port)
+                .with_bearer_token("secret"),
+        );
+        check_score(&mut app, &client);
+
+        let error = run_until_complete(
+            &mut app,
+            client.get_resource(BrpGetResourceParams {
+                resource: String::from("bevy_remote::client::tests::Missing"),
+            }),
+        );
+        let Err(ClientError::Remote(err)) = error else {
+            panic!("Expected an error for an unregistered resource");
+        };
+        assert_eq!(err.code, crate::error_codes::RESOURCE_ERROR);
+
+        let unauthorized = BrpClient::new(HttpTransport::default().with_port(port));
+        assert!(matches!(
+            run_until_complete(&mut app, unauthorized.list_resources()),
+            Err(ClientError::Status(401))
+        ));
+    }
+}
@@ -0,0 +1,267 @@
+//! Request handling shared by the transports which keep a connection open, and so can have
+//! several requests in flight at once and stream the responses of watching requests.
+
+#![cfg(not(target_family = "wasm"))]
+
+use crate::{error_codes, BrpBatch, BrpError, BrpMessage, BrpRequest, BrpResponse, BrpResult};
+use async_channel::{Receiver, Sender};
+use bevy_platform_support::collections::HashMap;
+use bevy_tasks::IoTaskPool;
+use serde_json::Value;
+
+#[cfg(feature = "stream")]
+use bevy_tasks::futures_lite::{Stream, StreamExt as _};
+
+/// The method path for an `rpc/unwatch` request.
+///
+/// This method is handled by the transport itself and cancels an ongoing
+/// watching request made on the same connection.
+pub const BRP_UNWATCH_METHOD: &str = "rpc/unwatch";
+
+/// The number of responses that can be queued for a single watching request before
+/// new ones are dropped.
+const WATCH_CHANNEL_SIZE: usize = 8;
+
+/// Serves the requests of a single connection until `messages` ends.
+///
+/// Each message is a single request or a batch of requests. Serialized responses are sent to
+/// `response_sender` as they complete, and it's up to the transport to write them to the client.
+#[cfg(feature = "stream")]
+pub(crate) async fn serve_messages(
+    mut messages: impl Stream<Item = Vec<u8>> + Unpin,
+    request_sender: &Sender<BrpMessage>,
+    response_sender: &Sender<String>,
+) {
+    // The ongoing watching requests of this connection, keyed by their serialized `id`.
+    let mut watchers = <HashMap<String, Receiver<BrpResult>>>::default();
+
+    while let Some(message) = messages.next().await {
+        process_message(&message, request_sender, response_sender, &mut watchers).await;
+        watchers.retain(|_, receiver| !receiver.is_closed());
+    }
+
+    // Closing the channels lets the `RemotePlugin` clean up the watching requests.
+    for receiver in watchers.values() {
+        receiver.close();
+    }
+}
+
+/// Parses a message sent by the client and dispatches the requests within it.
+///
+/// Instant requests are processed on their own tasks so that slow requests don't block the
+/// connection. Watching requests are registered with the connection so that they can be
+/// cancelled later.
+pub(crate) async fn process_message(
+    message: &[u8],
+    request_sender: &Sender<BrpMessage>,
+    response_sender: &Sender<String>,
+    watchers: &mut HashMap<String, Receiver<BrpResult>>,
+) {
+    match serde_json::from_slice::<BrpBatch>(message) {
+        Ok(BrpBatch::Single(request)) => {
+            let request = match parse_request(request) {
+                Ok(request) => request,
+                Err(response) => {
+                    send_response(response_sender, &response);
+                    return;
+                }
+            };
+
+            if request.method == BRP_UNWATCH_METHOD {
+                let response = process_unwatch_request(request, watchers);
+                send_response(response_sender, &response);
+            } else if request.method.contains("+watch") {
+                start_watching(request, request_sender, response_sender, watchers).await;
+            } else {
+                let request_sender = request_sender.clone();
+                let response_sender = response_sender.clone();
+                IoTaskPool::get()
+                    .spawn(async move {
+                        let response = process_instant_request(request, &request_sender).await;
+                        send_response(&response_sender, &response);
+                    })
+                    .detach();
+            }
+        }
+        Ok(BrpBatch::Batch(requests)) => {
+            let request_sender = request_sender.clone();
+            let response_sender = response_sender.clone();
+            IoTaskPool::get()
+                .spawn(async move {
+                    let mut responses = Vec::new();
+                    for request in requests {
+                        let response = match parse_request(request) {
+                            Ok(request) if request.method.contains("+watch") => BrpResponse::new(
+                                request.id,
+                                Err(BrpError {
+                                    code: error_codes::INVALID_REQUEST,
+                                    message: "Watching can not be used in batch requests"
+                                        .to_string(),
+                                    data: None,
+                                }),
+                            ),
+                            Ok(request) => process_instant_request(request, &request_sender).await,
+                            Err(response) => response,
+                        };
+                        responses.push(response);
+                    }
+                    send_response(&response_sender, &responses);
+                })
+                .detach();
+        }
+        Err(err) => {
+            let response = BrpResponse::new(
+                None,
+                Err(BrpError {
+                    code: error_codes::INVALID_REQUEST,
+                    message: err.to_string(),
+                    data: None,
+                }),
+            );
+            send_response(response_sender, &response);
+        }
+    }
+}
+
+/// Parses a single request, returning the error response to send if it is invalid.
+fn parse_request(request: Value) -> Result<BrpRequest, BrpResponse> {
+    // Reach in and get the request ID early so that we can report it even when parsing fails.
+    let id = request.as_object().and_then(|map| map.get("id")).cloned();
+
+    let request: BrpRequest = serde_json::from_value(request).map_err(|err| {
+        BrpResponse::new(
+            id.clone(),
+            Err(BrpError {
+                code: error_codes::INVALID_REQUEST,
+                message: err.to_string(),
+                data: None,
+            }),
+        )
+    })?;
+
+    if request.jsonrpc != "2.0" {
+        return Err(BrpResponse::new(
+            id,
+            Err(BrpError {
+                code: error_codes::INVALID_REQUEST,
+                message: String::from("JSON-RPC request requires `\"jsonrpc\": \"2.0\"`"),
+                data: None,
+            }),
+        ));
+    }
+
+    Ok(request)
+}
+
+/// Sends an instant request to the main world and waits for its response.
+async fn process_instant_request(
+    request: BrpRequest,
+    request_sender: &Sender<BrpMessage>,
+) -> BrpResponse {
+    let (result_sender, result_receiver) = async_channel::bounded(1);
+
+    let _ = request_sender
+        .send(BrpMessage {
+            method: request.method,
+            params: request.params,
+            sender: result_sender,
+        })
+        .await;
+
+    let result = result_receiver.recv().await.unwrap_or_else(|err| {
+        Err(BrpError {
+            code: error_codes::INTERNAL_ERROR,
+            message: err.to_string(),
+            data: None,
+        })
+    });
+    BrpResponse::new(request.id, result)
+}
+
+/// Sends a watching request to the main world and forwards every response it produces
+/// to the client.
+async fn start_watching(
+    request: BrpRequest,
+    request_sender: &Sender<BrpMessage>,
+    response_sender: &Sender<String>,
+    watchers: &mut HashMap<String, Receiver<BrpResult>>,
+) {
+    let key = watcher_key(request.id.as_ref());
+    if watchers.contains_key(&key) {
+        let response = BrpResponse::new(
+            request.id,
+            Err(BrpError {
+                code: error_codes::INVALID_REQUEST,
+                message: format!("A watching request with id {key} is already active"),
+                data: None,
+            }),
+        );
+        send_response(response_sender, &response);
+        return;
+    }
+
+    let (result_sender, result_receiver) = async_channel::bounded(WATCH_CHANNEL_SIZE);
+    let _ = request_sender
+        .send(BrpMessage {
+            method: request.method,
+            params: request.params,
+            sender: result_sender,
+        })
+        .await;
+    watchers.insert(key, result_receiver.clone());
+
+    let id = request.id;
+    let response_sender = response_sender.clone();
+    IoTaskPool::get()
+        .spawn(async move {
+            while let Ok(result) = result_receiver.recv().await {
+                let response = BrpResponse::new(id.clone(), result);
+                if !send_response(&response_sender, &response) {
+                    break;
+                }
+            }
+            result_receiver.close();
+        })
+        .detach();
+}
+
+/// Cancels the watching request whose `id` is given in the params of an `rpc/unwatch` request.
+fn process_unwatch_request(
+    request: BrpRequest,
+    watchers: &mut HashMap<String, Receiver<BrpResult>>,
+) -> BrpResponse {
+    let watched_id = request
+        .params
+        .as_ref()
+        .and_then(|params| params.as_object())
+        .and_then(|params| params.get("id"));
+    let Some(receiver) = watchers.remove(&watcher_key(watched_id)) else {
+        return BrpResponse::new(
+            request.id,
+            Err(BrpError {
+                code: error_codes::INVALID_PARAMS,
+                message: String::from("No active watching request with the given `id`"),
+                data: None,
+            }),
+        );
+    };
+
+    receiver.close();
+    BrpResponse::new(request.id, Ok(Value::Null))
+}
+
+/// The key identifying a watching request within a connection.
+fn watcher_key(id: Option<&Value>) -> String {
+    id.map(Value::to_string).unwrap_or_default()
+}
+
+/// Serializes a response and queues it to be sent to the client.
+///
+/// Returns `false` if the connection has been closed.
+fn send_response(response_sender: &Sender<String>, response: &impl serde::Serialize) -> bool {
+    match serde_json::to_string(response) {
+        Ok(serialized) => response_sender.try_send(serialized).is_ok(),
+        // Responses only contain JSON values, so serialization can't fail.
+        Err(_) => true,
+    }
+}
@@ -5,11 +5,16 @@
 //!
 //! Clients are expected to `POST` JSON requests to the root URL; see the `client`
 //! example for a trivial example of use.
+//!
+//! By default, anyone who can reach the server may call any method. Access can be restricted
+//! with bearer tokens, an allow-list of origins, and [`MethodPermissions`]; see
+//! [`RemoteHttpPlugin::with_bearer_token`] and [`RemoteHttpPlugin::with_allowed_origin`].
 
 #![cfg(not(target_family = "wasm"))]
 
 use crate::{
-    error_codes, BrpBatch, BrpError, BrpMessage, BrpRequest, BrpResponse, BrpResult, BrpSender,
+    builtin_methods, error_codes, BrpBatch, BrpError, BrpMessage, BrpRequest, BrpResponse,
+    BrpResult, BrpSender,
 };
 use anyhow::Result as AnyhowResult;
 use async_channel::{Receiver, Sender};
@@ -27,14 +32,14 @@ use core::{
 use http_body_util::{BodyExt as _, Full};
 use hyper::{
     body::{Body, Bytes, Frame, Incoming},
-    header::{HeaderName, HeaderValue},
+    header::{self, HeaderName, HeaderValue},
     server::conn::http1,
-    service, Request, Response,
+    service, Method, Request, Response, StatusCode,
 };
 use serde_json::Value;
 use smol_hyper::rt::{FuturesIo, SmolTimer};
 use std::{
-    collections::HashMap,
+    collections::{HashMap, HashSet},
     net::{TcpListener, TcpStream},
 };
 
@@ -87,6 +92,157 @@ impl Default for Headers {
     }
 }
 
+/// The set of methods that a client is permitted to call.
+///
+/// Requests for any other method are answered with a
+/// [`METHOD_NOT_PERMITTED`](error_codes::METHOD_NOT_PERMITTED) error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum MethodPermissions {
+    /// Every method may be called.
+    All,
+    /// Only the listed methods may be called.
+    Only(HashSet<String>),
+}
+
+impl MethodPermissions {
+    /// Permits every method.
+    pub fn all() -> Self {
+        Self::All
+    }
+
+    /// Permits no methods.
+    pub fn none() -> Self {
+        Self::Only(HashSet::new())
+    }
+
+    /// Permits the built-in methods which only read from the world and can't modify it.
+    ///
+    /// Custom methods can be permitted as well with [`MethodPermissions::with_method`].
+    pub fn read_only() -> Self {
+        Self::Only(
+            [
+                builtin_methods::BRP_GET_METHOD,
+                builtin_methods::BRP_QUERY_METHOD,
+                builtin_methods::BRP_LIST_METHOD,
+                builtin_methods::BRP_GET_AND_WATCH_METHOD,
+                builtin_methods::BRP_LIST_AND_WATCH_METHOD,
+                builtin_methods::BRP_GET_RESOURCE_METHOD,
+                builtin_methods::BRP_LIST_RESOURCES_METHOD,
+                builtin_methods::BRP_GET_RESOURCE_AND_WATCH_METHOD,
+                builtin_methods::BRP_SCHEDULE_LIST_METHOD,
+                builtin_methods::BRP_SCHEDULE_GRAPH_METHOD,
+                builtin_methods::BRP_STEPPING_STATE_METHOD,
+                builtin_methods::BRP_REGISTRY_SCHEMA_METHOD,
+                #[cfg(feature = "reflect_functions")]
+                builtin_methods::BRP_LIST_FUNCTIONS_METHOD,
+                #[cfg(feature = "bevy_asset")]
+                builtin_methods::BRP_ASSET_LIST_METHOD,
+            ]
+            .into_iter()
+            .map(String::from)
+            .collect(),
+        )
+    }
+
+    /// Additionally permits the given method. This has no effect on [`MethodPermissions::All`].
+    #[must_use]
+    pub fn with_method(mut self, method: impl Into<String>) -> Self {
+        if let Self::Only(methods) = &mut self {
+            methods.insert(method.into());
+        }
+        self
+    }
+
+    /// Returns `true` if the given method may be called.
+    pub fn allows(&self, method: &str) -> bool {
+        match self {
+            Self::All => true,
+            Self::Only(methods) => methods.contains(method),
+        }
+    }
+}
+
+/// The access rules that the server enforces for each incoming request.
+#[derive(Debug, Clone, Default)]
+struct AccessControl {
+    /// The permissions granted by each bearer token.
+    tokens: HashMap<String, MethodPermissions>,
+    /// The permissions granted to requests without a token.
+    ///
+    /// If this isn't set, requests without a token may call every method unless a
+    /// token was configured, in which case they may call none.
+    anonymous: Option<MethodPermissions>,
+    /// The origins that browsers may send requests from. An empty list disables
+    /// origin checks and CORS handling.
+    allowed_origins: Vec<String>,
+}
+
+impl AccessControl {
+    /// Returns the permissions granted to a request with the given `Authorization` header,
+    /// or [`None`] if the request isn't authorized.
+    fn permissions(&self, authorization: Option<&HeaderValue>) -> Option<&MethodPermissions> {
+        let Some(authorization) = authorization else {
+            return match &self.anonymous {
+                Some(permissions) => Some(permissions),
+                None if self.tokens.is_empty() => Some(&MethodPermissions::All),
+                None => None,
+            };
+        };
+
+        let token = authorization.to_str().ok()?.strip_prefix("Bearer ")?.trim();
+        self.tokens
+            .iter()
+            .find(|(candidate, _)| constant_time_eq(candidate.as_bytes(), token.as_bytes()))
+            .map(|(_, permissions)| permissions)
+    }
+
+    /// Returns `true` if browsers may send requests from the given origin.
+    fn allows_origin(&self, origin: &HeaderValue) -> bool {
+        self.allowed_origins.is_empty()
+            || self
+                .allowed_origins
+                .iter()
+                .any(|allowed| allowed == "*" || allowed.as_bytes() == origin.as_bytes())
+    }
+
+    /// Adds the CORS headers for a request from the given origin to the response.
+    ///
+    /// When origins are restricted, this replaces any `Access-Control-Allow-Origin` header
+    /// that was already set, so that other headers can't allow a rejected origin.
+    fn insert_cors_headers<B>(&self, response: &mut Response<B>, origin: Option<&HeaderValue>) {
+        if self.allowed_origins.is_empty() {
+            return;
+        }
+        let headers = response.headers_mut();
+        headers.remove(header::ACCESS_CONTROL_ALLOW_ORIGIN);
+        let Some(origin) = origin else {
+            return;
+        };
+
+        headers.insert(header::ACCESS_CONTROL_ALLOW_ORIGIN, origin.clone());
+        headers.insert(header::VARY, HeaderValue::from_static("Origin"));
+        headers.insert(
+            header::ACCESS_CONTROL_ALLOW_METHODS,
+            HeaderValue::from_static("POST, OPTIONS"),
+        );
+        headers.insert(
+            header::ACCESS_CONTROL_ALLOW_HEADERS,
+            HeaderValue::from_static("Content-Type, Authorization"),
+        );
+    }
+}
+
+/// Compares a secret byte string with a given one in time that only depends on the length of
+/// the secret, so that neither the secret nor its length can be guessed by timing the
+/// server's responses.
+fn constant_time_eq(secret: &[u8], given: &[u8]) -> bool {
+    let mut diff = secret.len() ^ given.len();
+    for (i, byte) in secret.iter().enumerate() {
+        diff |= usize::from(byte ^ given.get(i).copied().unwrap_or_default());
+    }
+    diff == 0
+}
+
 /// Add this plugin to your [`App`] to allow remote connections over HTTP to inspect and modify entities.
 /// It requires the [`RemotePlugin`](super::RemotePlugin).
 ///
@@ -96,6 +252,10 @@ impl Default for Headers {
 /// - [`DEFAULT_ADDR`] : 127.0.0.1.
 /// - [`DEFAULT_PORT`] : 15702.
 ///
+/// No authentication is required by default, so anyone who can reach the address may call
+/// any method. When exposing the server beyond the local machine, require a token with
+/// [`RemoteHttpPlugin::with_bearer_token`].
+///
 pub struct RemoteHttpPlugin {
     /// The address that Bevy will bind to.
     address: IpAddr,
@@ -103,6 +263,8 @@ pub struct RemoteHttpPlugin {
     port: u16,
     /// The headers that Bevy will include in its HTTP responses
     headers: Headers,
+    /// The rules deciding who may call which methods.
+    access: AccessControl,
 }
 
 impl Default for RemoteHttpPlugin {
@@ -111,6 +273,7 @@ impl Default for RemoteHttpPlugin {
             address: DEFAULT_ADDR,
             port: DEFAULT_PORT,
             headers: Headers::new(),
+            access: AccessControl::default(),
         }
     }
 }
@@ -120,6 +283,7 @@ impl Plugin for RemoteHttpPlugin {
         app.insert_resource(HostAddress(self.address))
             .insert_resource(HostPort(self.port))
             .insert_resource(HostHeaders(self.headers.clone()))
+            .insert_resource(HostAccessControl(self.access.clone()))
             .add_systems(Startup, start_http_server);
     }
 }
@@ -139,6 +303,8 @@ impl RemoteHttpPlugin {
     }
     /// Set the extra headers that the response will include.
     ///
+    /// These are included in every response, including those rejecting a request.
+    ///
     /// ////// /// # Example
     ///
     /// ```ignore
@@ -173,6 +339,53 @@ impl RemoteHttpPlugin {
         self.headers = self.headers.insert(name, value);
         self
     }
+    /// Accept requests carrying the given bearer token in their `Authorization` header,
+    /// permitting them to call the given methods.
+    ///
+    /// Once a token has been added, requests without a token are rejected with
+    /// `401 Unauthorized`, unless permissions for them are set with
+    /// [`RemoteHttpPlugin::with_anonymous_permissions`]. Requests with an unknown token are
+    /// always rejected.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // QA tools may inspect the game, while the developer may also modify it.
+    /// RemoteHttpPlugin::default()
+    ///     .with_address([0, 0, 0, 0])
+    ///     .with_bearer_token("qa-token", MethodPermissions::read_only())
+    ///     .with_bearer_token("dev-token", MethodPermissions::all())
+    /// ```
+    #[must_use]
+    pub fn with_bearer_token(
+        mut self,
+        token: impl Into<String>,
+        permissions: MethodPermissions,
+    ) -> Self {
+        self.access.tokens.insert(token.into(), permissions);
+        self
+    }
+    /// Set the methods that requests without a bearer token are permitted to call.
+    #[must_use]
+    pub fn with_anonymous_permissions(mut self, permissions: MethodPermissions) -> Self {
+        self.access.anonymous = Some(permissions);
+        self
+    }
+    /// Allow browsers to send requests from the given origin, e.g. `http://localhost:8080`.
+    /// The origin `*` allows any origin.
+    ///
+    /// Once an origin has been allowed, requests whose `Origin` header doesn't match an
+    /// allowed origin are rejected with `403 Forbidden`, and CORS preflight requests are
+    /// answered for the allowed ones. Requests without an `Origin` header, such as those
+    /// from command-line tools, are unaffected.
+    ///
+    /// The CORS headers set by the server replace any with the same name set through
+    /// [`RemoteHttpPlugin::with_headers`].
+    #[must_use]
+    pub fn with_allowed_origin(mut self, origin: impl Into<String>) -> Self {
+        self.access.allowed_origins.push(origin.into());
+        self
+    }
 }
 
 /// A resource containing the IP address that Bevy will host on.
@@ -194,12 +407,17 @@ pub struct HostPort(pub u16);
 #[derive(Debug, Resource)]
 struct HostHeaders(pub Headers);
 
+/// A resource containing the access rules that the server enforces.
+#[derive(Debug, Resource)]
+struct HostAccessControl(AccessControl);
+
 /// A system that starts up the Bevy Remote Protocol HTTP server.
 fn start_http_server(
     request_sender: Res<BrpSender>,
     address: Res<HostAddress>,
     remote_port: Res<HostPort>,
     headers: Res<HostHeaders>,
+    access: Res<HostAccessControl>,
 ) {
     IoTaskPool::get()
         .spawn(server_main(
@@ -207,6 +425,7 @@ fn start_http_server(
             remote_port.0,
             request_sender.clone(),
             headers.0.clone(),
+            access.0.clone(),
         ))
         .detach();
 }
@@ -217,11 +436,13 @@ async fn server_main(
     port: u16,
     request_sender: Sender<BrpMessage>,
     headers: Headers,
+    access: AccessControl,
 ) -> AnyhowResult<()> {
     listen(
         Async::<TcpListener>::bind((address, port))?,
         &request_sender,
         &headers,
+        &access,
     )
     .await
 }
@@ -230,15 +451,17 @@ async fn listen(
     listener: Async<TcpListener>,
     request_sender: &Sender<BrpMessage>,
     headers: &Headers,
+    access: &AccessControl,
 ) -> AnyhowResult<()> {
     loop {
         let (client, _) = listener.accept().await?;
 
         let request_sender = request_sender.clone();
         let headers = headers.clone();
+        let access = access.clone();
         IoTaskPool::get()
             .spawn(async move {
-                let _ = handle_client(client, request_sender, headers).await;
+                let _ = handle_client(client, request_sender, headers, access).await;
             })
             .detach();
     }
@@ -248,13 +471,14 @@ async fn handle_client(
     client: Async<TcpStream>,
     request_sender: Sender<BrpMessage>,
     headers: Headers,
+    access: AccessControl,
 ) -> AnyhowResult<()> {
     http1::Builder::new()
         .timer(SmolTimer::new())
         .serve_connection(
             FuturesIo::new(client),
             service::service_fn(|request| {
-                process_request_batch(request, &request_sender, &headers)
+                process_request_batch(request, &request_sender, &headers, &access)
             }),
         )
         .await?;
@@ -268,13 +492,37 @@ async fn process_request_batch(
     request: Request<Incoming>,
     request_sender: &Sender<BrpMessage>,
     headers: &Headers,
+    access: &AccessControl,
 ) -> AnyhowResult<Response<BrpHttpBody>> {
+    let origin = request.headers().get(header::ORIGIN).cloned();
+    if let Some(origin) = &origin {
+        if !access.allows_origin(origin) {
+            let response = status_response(StatusCode::FORBIDDEN);
+            return Ok(finish_response(response, headers, access, None));
+        }
+    }
+
+    // Browsers don't send credentials with preflight requests, so these are answered
+    // before checking authorization.
+    if request.method() == Method::OPTIONS && !access.allowed_origins.is_empty() {
+        let response = status_response(StatusCode::NO_CONTENT);
+        return Ok(finish_response(response, headers, access, origin.as_ref()));
+    }
+
+    let Some(permissions) = access.permissions(request.headers().get(header::AUTHORIZATION)) else {
+        let mut response = status_response(StatusCode::UNAUTHORIZED);
+        response
+            .headers_mut()
+            .insert(header::WWW_AUTHENTICATE, HeaderValue::from_static("Bearer"));
+        return Ok(finish_response(response, headers, access, origin.as_ref()));
+    };
+
     let batch_bytes = request.into_body().collect().await?.to_bytes();
     let batch: Result<BrpBatch, _> = serde_json::from_slice(&batch_bytes);
 
     let result = match batch {
         Ok(BrpBatch::Single(request)) => {
-            let response = process_single_request(request, request_sender).await?;
+            let response = process_single_request(request, request_sender, permissions).await?;
             match response {
                 BrpHttpResponse::Complete(res) => {
                     BrpHttpResponse::Complete(serde_json::to_string(&res)?)
@@ -286,7 +534,7 @@ async fn process_request_batch(
             let mut responses = Vec::new();
 
             for request in requests {
-                let response = process_single_request(request, request_sender).await?;
+                let response = process_single_request(request, request_sender, permissions).await?;
                 match response {
                     BrpHttpResponse::Complete(res) => responses.push(res),
                     BrpHttpResponse::Stream(BrpStream { id, .. }) => {
@@ -318,13 +566,13 @@ async fn process_request_batch(
         }
     };
 
-    let mut response = match result {
+    let response = match result {
         BrpHttpResponse::Complete(serialized) => {
             let mut response = Response::new(BrpHttpBody::Complete(Full::new(Bytes::from(
                 serialized.as_bytes().to_owned(),
             ))));
             response.headers_mut().insert(
-                hyper::header::CONTENT_TYPE,
+                header::CONTENT_TYPE,
                 HeaderValue::from_static("application/json"),
             );
             response
@@ -332,16 +580,35 @@ async fn process_request_batch(
         BrpHttpResponse::Stream(stream) => {
             let mut response = Response::new(BrpHttpBody::Stream(stream));
             response.headers_mut().insert(
-                hyper::header::CONTENT_TYPE,
+                header::CONTENT_TYPE,
                 HeaderValue::from_static("text/event-stream"),
             );
             response
         }
     };
+    Ok(finish_response(response, headers, access, origin.as_ref()))
+}
+
+/// Adds the user-configured headers and then the CORS headers to a response, so that the
+/// CORS headers take precedence when origins are restricted.
+fn finish_response(
+    mut response: Response<BrpHttpBody>,
+    headers: &Headers,
+    access: &AccessControl,
+    origin: Option<&HeaderValue>,
+) -> Response<BrpHttpBody> {
     for (key, value) in &headers.headers {
         response.headers_mut().insert(key, value.clone());
     }
-    Ok(response)
+    access.insert_cors_headers(&mut response, origin);
+    response
+}
+
+/// Creates an empty response with the given status code.
+fn status_response(status: StatusCode) -> Response<BrpHttpBody> {
+    let mut response = Response::new(BrpHttpBody::Complete(Full::new(Bytes::new())));
+    *response.status_mut() = status;
+    response
 }
 
 /// A helper function for the Bevy Remote Protocol server that processes a single
@@ -349,6 +616,7 @@ async fn process_request_batch(
 async fn process_single_request(
     request: Value,
     request_sender: &Sender<BrpMessage>,
+    permissions: &MethodPermissions,
 ) -> AnyhowResult<BrpHttpResponse<BrpResponse, BrpStream>> {
     // Reach in and get the request ID early so that we can report it even when parsing fails.
     let id = request.as_object().and_then(|map| map.get("id")).cloned();
@@ -378,6 +646,13 @@ async fn process_single_request(
         )));
     }
 
+    if !permissions.allows(&request.method) {
+        return Ok(BrpHttpResponse::Complete(BrpResponse::new(
+            id,
+            Err(BrpError::method_not_permitted(&request.method)),
+        )));
+    }
+
     let watch = request.method.contains("+watch");
     let size = if watch { 8 } else { 1 };
     let (result_sender, result_receiver) = async_channel::bounded(size);
@@ -461,3 +736,215 @@ impl Body for BrpHttpBody {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_tasks::{futures_lite::future, TaskPool};
+    use core::net::SocketAddr;
+    use std::io::{Read, Write};
+
+    /// Starts a server with the settings of the given plugin, answering every request with
+    /// the name of its method.
+    fn serve(plugin: RemoteHttpPlugin) -> SocketAddr {
+        IoTaskPool::get_or_init(TaskPool::new);
+        let listener = Async::<TcpListener>::bind((DEFAULT_ADDR, 0)).unwrap();
+        let address = listener.get_ref().local_addr().unwrap();
+        let (request_sender, request_receiver) = async_channel::unbounded::<BrpMessage>();
+
+        std::thread::spawn(move || {
+            while let Ok(message) = request_receiver.recv_blocking() {
+                let _ = message
+                    .sender
+                    .send_blocking(Ok(Value::String(message.method)));
+            }
+        });
+        std::thread::spawn(move || {
+            IoTaskPool::get().with_local_executor(|executor| {
+                let _ = future::block_on(executor.run(listen(
+                    listener,
+                    &request_sender,
+                    &plugin.headers,
+                    &plugin.access,
+                )));
+            });
+        });
+
+        address
+    }
+
+    /// Sends a request for the given method with the given extra headers, returning the raw
+    /// response.
+    fn send(address: SocketAddr, http_method: &str, method: &str, headers: &[&str]) -> String {
+        let body = serde_json::json!({ "jsonrpc": "2.0", "method": method, "id": 0 }).to_string();
+        let mut request = format!(
+            "{http_method} / HTTP/1.1\r\nHost: {address}\r\nConnection: close\r\nContent-Length: {}\r\n",
+            body.len()
+        );
+        for header in headers {
+            request.push_str(header);
+            request.push_str("\r\n");
+        }
+        request.push_str("\r\n");
+        request.push_str(&body);
+
+        let mut stream = TcpStream::connect(address).unwrap();
+        stream.write_all(request.as_bytes()).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn constant_time_eq_compares_lengths() {
+        assert!(constant_time_eq(b"secret", b"secret"));
+        assert!(!constant_time_eq(b"secret", b"secreT"));
+        assert!(!constant_time_eq(b"secret", b"secret\0"));
+        assert!(!constant_time_eq(b"secret", b"secre"));
+        assert!(!constant_time_eq(b"secret", b""));
+    }
+
+    #[test]
+    fn bearer_tokens() {
+        let address = serve(
+            RemoteHttpPlugin::default()
+                .with_bearer_token("secret", MethodPermissions::read_only())
+                .with_header("X-Custom", "1"),
+        );
+
+        let response = send(address, "POST", "bevy/list", &[]);
+        assert!(response.starts_with("HTTP/1.1 401"), "{response}");
+        assert!(response.contains("www-authenticate: Bearer"), "{response}");
+        assert!(response.contains("x-custom: 1"), "{response}");
+
+        let response = send(
+            address,
+            "POST",
+            "bevy/list",
+            &["Authorization: Bearer wrong"],
+        );
+        assert!(response.starts_with("HTTP/1.1 401"), "{response}");
+        let response = send(
+            address,
+            "POST",
+            "bevy/list",
+            &["Authorization: Bearer secretsecret"],
+        );
+        assert!(response.starts_with("HTTP/1.1 401"), "{response}");
+
+        let response = send(
+            address,
+            "POST",
+            "bevy/list",
+            &["Authorization: Bearer secret"],
+        );
+        assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+        assert!(response.contains(r#""result":"bevy/list""#), "{response}");
+        assert!(response.contains("x-custom: 1"), "{response}");
+
+        #[cfg(feature = "reflect_functions")]
+        {
+            let response = send(
+                address,
+                "POST",
+                "bevy/list_functions",
+                &["Authorization: Bearer secret"],
+            );
+            assert!(
+                response.contains(r#""result":"bevy/list_functions""#),
+                "{response}"
+            );
+        }
+        #[cfg(feature = "bevy_asset")]
+        {
+            let response = send(
+                address,
+                "POST",
+                "bevy/asset/list",
+                &["Authorization: Bearer secret"],
+            );
+            assert!(
+                response.contains(r#""result":"bevy/asset/list""#),
+                "{response}"
+            );
+        }
+
+        // The token only permits reading from the world.
+        let response = send(
+            address,
+            "POST",
+            "bevy/insert",
+            &["Authorization: Bearer secret"],
+        );
+        assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+        assert!(
+            response.contains(&format!(r#""code":{}"#, error_codes::METHOD_NOT_PERMITTED)),
+            "{response}"
+        );
+    }
+
+    #[test]
+    fn anonymous_permissions() {
+        let address = serve(
+            RemoteHttpPlugin::default()
+                .with_anonymous_permissions(MethodPermissions::none().with_method("bevy/list")),
+        );
+
+        let response = send(address, "POST", "bevy/list", &[]);
+        assert!(response.contains(r#""result":"bevy/list""#), "{response}");
+        let response = send(address, "POST", "bevy/get", &[]);
+        assert!(
+            response.contains(&format!(r#""code":{}"#, error_codes::METHOD_NOT_PERMITTED)),
+            "{response}"
+        );
+    }
+
+    #[test]
+    fn allowed_origins() {
+        let address = serve(
+            RemoteHttpPlugin::default()
+                .with_allowed_origin("http://localhost:8080")
+                .with_header("Access-Control-Allow-Origin", "*"),
+        );
+
+        let response = send(
+            address,
+            "POST",
+            "bevy/list",
+            &["Origin: http://example.com"],
+        );
+        assert!(response.starts_with("HTTP/1.1 403"), "{response}");
+        assert!(
+            !response.contains("access-control-allow-origin"),
+            "{response}"
+        );
+
+        let response = send(
+            address,
+            "POST",
+            "bevy/list",
+            &["Origin: http://localhost:8080"],
+        );
+        assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+        assert!(
+            response.contains("access-control-allow-origin: http://localhost:8080"),
+            "{response}"
+        );
+
+        let response = send(
+            address,
+            "OPTIONS",
+            "bevy/list",
+            &["Origin: http://localhost:8080"],
+        );
+        assert!(response.starts_with("HTTP/1.1 204"), "{response}");
+        assert!(
+            response.contains("access-control-allow-headers: Content-Type, Authorization"),
+            "{response}"
+        );
+
+        // Requests without an origin, such as those from command-line tools, are unaffected.
+        let response = send(address, "POST", "bevy/list", &[]);
+        assert!(response.starts_with("HTTP/1.1 200"), "{response}");
+    }
+}
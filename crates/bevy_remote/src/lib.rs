@@ -3,8 +3,13 @@
 //! Adding the [`RemotePlugin`] to your [`App`] will setup everything needed without
 //! starting any transports. To start accepting remote connections you will need to
 //! add a second plugin like the [`RemoteHttpPlugin`](http::RemoteHttpPlugin) to enable communication
-//! over HTTP. These *remote clients* can inspect and alter the state of the
-//! entity-component system.
+//! over HTTP, the `RemoteWebSocketPlugin` (behind the `websocket` feature) to enable
+//! communication over a WebSocket connection, or the plugins in the `stream` module (behind the
+//! `stream` feature) to communicate over a Unix domain socket or stdin and stdout. These
+//! *remote clients* can inspect and alter the state of the entity-component system.
+//!
+//! Requests can be made from Rust with the typed client in the `client` module, behind the
+//! `client` feature.
 //!
 //! The Bevy Remote Protocol is based on the JSON-RPC 2.0 protocol.
 //!
@@ -143,8 +148,20 @@
 //!     on entities in order for them to be included in results.
 //!   - `without` (optional): An array of fully-qualified type names of components that must *not* be
 //!     present on entities in order for them to be included in results.
+//!   - `values` (optional): An array of predicates on component values that must all hold for
+//!     entities to be included in results. Each is an object containing:
+//!     - `component`: The fully-qualified type name of the component.
+//!     - `path` (optional): The path of a field within the component, e.g. `.translation.y`.
+//!       If omitted, the whole component is compared.
+//!     - `op`: One of `eq`, `ne`, `lt`, `le`, `gt` or `ge`. The ordering comparisons only hold
+//!       between two numbers or two strings.
+//!     - `value`: The value to compare the field against.
 //!   - `strict` (optional): A flag to enable strict mode which will fail if any one of the
 //!     components is not present or can not be reflected. Defaults to false.
+//! - `limit` (optional): The maximum number of entities to return.
+//! - `after` (optional): Only return entities ordered after this entity ID. When `limit` or
+//!   `after` is given, results are ordered by entity ID, so the last entity of a page can be
+//!   passed as `after` to fetch the next one.
 //!
 //! `result`: An array, each of which is an object containing:
 //! - `entity`: The ID of a query-matching entity.
@@ -274,6 +291,241 @@
 //! - `removed`: An array of fully-qualified type names of components removed from the entity
 //!   in the last tick.
 //!
+//! ### `bevy/get_resource`
+//!
+//! Extract the value of a given resource from the world.
+//!
+//! `params`:
+//! - `resource`: The [fully-qualified type name] of the resource to get.
+//!
+//! `result`:
+//! - `value`: The value of the resource in the world.
+//!
+//! ### `bevy/insert_resource`
+//!
+//! Insert the given resource into the world with the given value, replacing any
+//! existing value.
+//!
+//! `params`:
+//! - `resource`: The [fully-qualified type name] of the resource to insert.
+//! - `value`: The value of the resource to be inserted.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/remove_resource`
+//!
+//! Remove the given resource from the world.
+//!
+//! `params`
+//! - `resource`: The [fully-qualified type name] of the resource to remove.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/mutate_resource`
+//!
+//! Mutate a field in a resource.
+//!
+//! `params`:
+//! - `resource`: The [fully-qualified type name] of the resource to mutate.
+//! - `path`: The path of the field within the resource. See
+//!   [`GetPath`](bevy_reflect::GetPath#syntax) for more information on formatting this string.
+//! - `value`: The value to be inserted at `path`.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/list_resources`
+//!
+//! List all reflectable registered resource types. This method has no parameters.
+//!
+//! `result`: An array of [fully-qualified type names] of registered resource types.
+//!
+//! ### `bevy/get_resource+watch`
+//!
+//! Watch the value of a given resource.
+//!
+//! `params`:
+//! - `resource`: The [fully-qualified type name] of the resource to watch.
+//!
+//! `result`:
+//! - `value`: The value of the resource, sent whenever it was added or changed in the last tick.
+//!
+//! ### `bevy/send_event`
+//!
+//! Send an event into its `Events` resource, so that it is read by `EventReader`s.
+//! The event type must be registered with `#[reflect(Event)]` and added to the app.
+//!
+//! `params`:
+//! - `event`: The [fully-qualified type name] of the event to send.
+//! - `value`: The value of the event.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/trigger_event`
+//!
+//! Trigger an event, running the observers watching for it. The event type must be
+//! registered with `#[reflect(Event)]`.
+//!
+//! `params`:
+//! - `event`: The [fully-qualified type name] of the event to trigger.
+//! - `value`: The value of the event.
+//! - `targets` (optional): An array of entity IDs to trigger the event for. If omitted or
+//!   empty, the event is triggered without targets and only global observers run.
+//!
+//! `result`: null.
+//!
+//! ### bevy/schedule/list
+//!
+//! List the labels of all schedules in the app. Schedules which are running while the request
+//! is processed, such as `Main`, are not listed.
+//!
+//! `params`: None.
+//!
+//! `result`: An array of schedule labels, formatted with `Debug`.
+//!
+//! ### bevy/schedule/graph
+//!
+//! Get the systems and system sets of a schedule, along with the edges between them.
+//!
+//! `params`:
+//! - `schedule`: The label of the schedule, as returned by `bevy/schedule/list`.
+//!
+//! `result`:
+//! - `systems`: An array of objects, each containing the `id` and `name` of a system, as well
+//!   as `ran_last_frame`: whether the system ran during the last frame rather than being
+//!   skipped by its run conditions or stepping, and `last_run_time` (optional): how long the
+//!   system took the last time it ran, in seconds. Timings are only recorded with the
+//!   `system_timing` feature.
+//! - `sets`: An array of objects, each containing the `id` and `name` of a system set.
+//! - `hierarchy`: An array of edges from each system set to its members.
+//! - `dependencies`: An array of edges from each system or set to those that run after it.
+//!
+//! Each edge is an object with a `from` and a `to` node, which are either `{"system": id}` or
+//! `{"set": id}`.
+//!
+//! ### bevy/stepping/state
+//!
+//! Get the state of the `Stepping` resource.
+//!
+//! `params`: None.
+//!
+//! `result`:
+//! - `enabled`: Whether stepping is enabled.
+//! - `schedules`: An array of the labels of the schedules with stepping enabled.
+//! - `cursor` (optional): The next system to run, as an object containing its `schedule` and
+//!   `system` id.
+//!
+//! ### bevy/stepping/enable, bevy/stepping/disable
+//!
+//! Enable or disable stepping. Enabling stepping requires the `bevy_debug_stepping` feature.
+//!
+//! `params`: None.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/stepping/step_system`, `bevy/stepping/continue`
+//!
+//! Run the next system, or all remaining systems in the stepping frame, during the next frame.
+//!
+//! `params`: None.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/stepping/add_schedule`, `bevy/stepping/remove_schedule`
+//!
+//! Enable or disable stepping for a schedule.
+//!
+//! `params`:
+//! - `schedule`: The label of the schedule, as returned by `bevy/schedule/list`.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/stepping/set_breakpoint`, `bevy/stepping/clear_breakpoint`
+//!
+//! Set or clear a breakpoint on a system, stopping execution before it runs.
+//!
+//! `params`:
+//! - `schedule`: The label of the schedule, as returned by `bevy/schedule/list`.
+//! - `system`: The id of the system, as returned by `bevy/schedule/graph`. An error is returned
+//!   if the schedule has no system with this id.
+//!
+//! `result`: null.
+//!
+//! ### bevy/asset/list
+//!
+//! List the assets tracked by the `AssetServer`, grouped by asset type. Requires the
+//! `bevy_asset` feature.
+//!
+//! `params` (optional):
+//! - `asset_type`: The [fully-qualified type name] of an asset type to restrict the listing to.
+//!
+//! `result`: A map associating the fully-qualified type name of each asset type with an array
+//! of objects. Assets whose type isn't registered in the type registry are listed under
+//! `unregistered`. Each object contains:
+//! - `id`: The id of the asset.
+//! - `path` (optional): The path the asset was loaded from.
+//! - `load_state`, `dependency_load_state`, `recursive_dependency_load_state`: One of
+//!   `not_loaded`, `loading`, `loaded` or `failed`, for the asset itself, its direct
+//!   dependencies and all of its dependencies respectively.
+//! - `error` (optional): Why the asset or one of its dependencies failed to load.
+//! - `strong_handles`: The number of strong handles keeping the asset alive.
+//!
+//! ### bevy/asset/load
+//!
+//! Start loading the asset at the given path. BRP holds a handle to the asset, keeping it
+//! alive until a matching `bevy/asset/unload`. Requires the `bevy_asset` feature.
+//!
+//! `params`:
+//! - `path`: The path of the asset.
+//!
+//! `result`: null.
+//!
+//! ### bevy/asset/reload
+//!
+//! Reload the asset at the given path. Requires the `bevy_asset` feature.
+//!
+//! `params`:
+//! - `path`: The path of the asset.
+//!
+//! `result`: null.
+//!
+//! ### bevy/asset/unload
+//!
+//! Release the handle BRP holds to the asset at the given path, which must have been loaded
+//! with `bevy/asset/load`. The asset is unloaded once no other strong handles to it remain.
+//! Requires the `bevy_asset` feature.
+//!
+//! `params`:
+//! - `path`: The path of the asset.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/list_functions`
+//!
+//! List all functions registered in the `AppFunctionRegistry` along with their signatures.
+//! Requires the `reflect_functions` feature.
+//!
+//! `params`: None.
+//!
+//! `result`: An array, each of which is an object containing:
+//! - `name`: The name the function was registered with.
+//! - `signatures`: An array of the function's signatures, each containing:
+//!   - `args`: An array of objects with the [fully-qualified type name] of each argument in
+//!     `type_path`, as well as its `name` if it was given one.
+//!   - `return`: The fully-qualified type name of the return type.
+//!
+//! ### `bevy/call_function`
+//!
+//! Call a function registered in the `AppFunctionRegistry`. Requires the
+//! `reflect_functions` feature.
+//!
+//! `params`:
+//! - `function`: The name the function was registered with.
+//! - `args` (optional): An array of the function's arguments. Each one is deserialized as the
+//!   type of the corresponding argument in the function's signature. Reference arguments are
+//!   passed as the value they refer to.
+//!
+//! `result`: The value returned by the function, or null if it returns `()`.
+//!
 //!
 //! ## Custom methods
 //!
@@ -318,14 +570,17 @@
 //! [fully-qualified type names]: bevy_reflect::TypePath::type_path
 //! [fully-qualified type name]: bevy_reflect::TypePath::type_path
 
+extern crate alloc;
+
 use async_channel::{Receiver, Sender};
 use bevy_app::{prelude::*, MainScheduleOrder};
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::{
+    component::Tick,
     entity::Entity,
     resource::Resource,
     schedule::{IntoSystemConfigs, IntoSystemSetConfigs, ScheduleLabel, SystemSet},
-    system::{Commands, In, IntoSystem, ResMut, System, SystemId},
+    system::{Commands, In, IntoSystem, ResMut, System, SystemChangeTick, SystemId},
     world::World,
 };
 use bevy_platform_support::collections::HashMap;
@@ -335,8 +590,19 @@ use serde_json::Value;
 use std::sync::RwLock;
 
 pub mod builtin_methods;
+#[cfg(feature = "client")]
+pub mod client;
 #[cfg(feature = "http")]
 pub mod http;
+#[cfg(feature = "stream")]
+pub mod stream;
+#[cfg(feature = "system_timing")]
+pub mod timing;
+#[cfg(feature = "websocket")]
+pub mod websocket;
+
+#[cfg(any(feature = "websocket", feature = "stream"))]
+mod connection;
 
 const CHANNEL_SIZE: usize = 16;
 
@@ -391,7 +657,7 @@ impl RemotePlugin {
 
 impl Default for RemotePlugin {
     fn default() -> Self {
-        Self::empty()
+        let plugin = Self::empty()
             .with_method(
                 builtin_methods::BRP_GET_METHOD,
                 builtin_methods::process_remote_get_request,
@@ -432,6 +698,34 @@ impl Default for RemotePlugin {
                 builtin_methods::BRP_MUTATE_COMPONENT_METHOD,
                 builtin_methods::process_remote_mutate_component_request,
             )
+            .with_method(
+                builtin_methods::BRP_GET_RESOURCE_METHOD,
+                builtin_methods::process_remote_get_resource_request,
+            )
+            .with_method(
+                builtin_methods::BRP_INSERT_RESOURCE_METHOD,
+                builtin_methods::process_remote_insert_resource_request,
+            )
+            .with_method(
+                builtin_methods::BRP_REMOVE_RESOURCE_METHOD,
+                builtin_methods::process_remote_remove_resource_request,
+            )
+            .with_method(
+                builtin_methods::BRP_MUTATE_RESOURCE_METHOD,
+                builtin_methods::process_remote_mutate_resource_request,
+            )
+            .with_method(
+                builtin_methods::BRP_LIST_RESOURCES_METHOD,
+                builtin_methods::process_remote_list_resources_request,
+            )
+            .with_method(
+                builtin_methods::BRP_SEND_EVENT_METHOD,
+                builtin_methods::process_remote_send_event_request,
+            )
+            .with_method(
+                builtin_methods::BRP_TRIGGER_EVENT_METHOD,
+                builtin_methods::process_remote_trigger_event_request,
+            )
             .with_watching_method(
                 builtin_methods::BRP_GET_AND_WATCH_METHOD,
                 builtin_methods::process_remote_get_watching_request,
@@ -440,6 +734,86 @@ impl Default for RemotePlugin {
                 builtin_methods::BRP_LIST_AND_WATCH_METHOD,
                 builtin_methods::process_remote_list_watching_request,
             )
+            .with_watching_method(
+                builtin_methods::BRP_GET_RESOURCE_AND_WATCH_METHOD,
+                builtin_methods::process_remote_get_resource_watching_request,
+            )
+            .with_method(
+                builtin_methods::BRP_SCHEDULE_LIST_METHOD,
+                builtin_methods::process_remote_schedule_list_request,
+            )
+            .with_method(
+                builtin_methods::BRP_SCHEDULE_GRAPH_METHOD,
+                builtin_methods::process_remote_schedule_graph_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_STATE_METHOD,
+                builtin_methods::process_remote_stepping_state_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_ENABLE_METHOD,
+                builtin_methods::process_remote_stepping_enable_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_DISABLE_METHOD,
+                builtin_methods::process_remote_stepping_disable_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_STEP_SYSTEM_METHOD,
+                builtin_methods::process_remote_stepping_step_system_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_CONTINUE_METHOD,
+                builtin_methods::process_remote_stepping_continue_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_ADD_SCHEDULE_METHOD,
+                builtin_methods::process_remote_stepping_add_schedule_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_REMOVE_SCHEDULE_METHOD,
+                builtin_methods::process_remote_stepping_remove_schedule_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_SET_BREAKPOINT_METHOD,
+                builtin_methods::process_remote_stepping_set_breakpoint_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STEPPING_CLEAR_BREAKPOINT_METHOD,
+                builtin_methods::process_remote_stepping_clear_breakpoint_request,
+            );
+
+        #[cfg(feature = "bevy_asset")]
+        let plugin = plugin
+            .with_method(
+                builtin_methods::BRP_ASSET_LIST_METHOD,
+                builtin_methods::process_remote_asset_list_request,
+            )
+            .with_method(
+                builtin_methods::BRP_ASSET_LOAD_METHOD,
+                builtin_methods::process_remote_asset_load_request,
+            )
+            .with_method(
+                builtin_methods::BRP_ASSET_RELOAD_METHOD,
+                builtin_methods::process_remote_asset_reload_request,
+            )
+            .with_method(
+                builtin_methods::BRP_ASSET_UNLOAD_METHOD,
+                builtin_methods::process_remote_asset_unload_request,
+            );
+
+        #[cfg(feature = "reflect_functions")]
+        let plugin = plugin
+            .with_method(
+                builtin_methods::BRP_LIST_FUNCTIONS_METHOD,
+                builtin_methods::process_remote_list_functions_request,
+            )
+            .with_method(
+                builtin_methods::BRP_CALL_FUNCTION_METHOD,
+                builtin_methods::process_remote_call_function_request,
+            );
+
+        plugin
     }
 }
 
@@ -469,6 +843,7 @@ impl Plugin for RemotePlugin {
 
         app.insert_resource(remote_methods)
             .init_resource::<RemoteWatchingRequests>()
+            .init_resource::<RemoteFrameTick>()
             .add_systems(PreStartup, setup_mailbox_channel)
             .configure_sets(
                 RemoteLast,
@@ -480,7 +855,8 @@ impl Plugin for RemotePlugin {
                     (process_remote_requests, process_ongoing_watching_requests)
                         .chain()
                         .in_set(RemoteSet::ProcessRequests),
-                    remove_closed_watching_requests.in_set(RemoteSet::Cleanup),
+                    (remove_closed_watching_requests, record_remote_frame_tick)
+                        .in_set(RemoteSet::Cleanup),
                 ),
             );
     }
@@ -501,6 +877,17 @@ pub enum RemoteSet {
     Cleanup,
 }
 
+/// The change tick at which the [`RemoteLast`] schedule last finished processing requests.
+///
+/// Systems which ran after this tick ran during the current frame.
+#[derive(Debug, Resource, Default)]
+pub(crate) struct RemoteFrameTick(pub(crate) Tick);
+
+/// A system that records the [`RemoteFrameTick`] at the end of each frame.
+fn record_remote_frame_tick(mut frame_tick: ResMut<RemoteFrameTick>, ticks: SystemChangeTick) {
+    frame_tick.0 = ticks.this_run();
+}
+
 /// A type to hold the allowed types of systems to be used as method handlers.
 #[derive(Debug)]
 pub enum RemoteMethodHandler {
@@ -737,6 +1124,96 @@ impl BrpError {
             data: None,
         }
     }
+
+    /// An arbitrary resource error. Possibly related to reflection.
+    #[must_use]
+    pub fn resource_error<E: ToString>(error: E) -> Self {
+        Self {
+            code: error_codes::RESOURCE_ERROR,
+            message: error.to_string(),
+            data: None,
+        }
+    }
+
+    /// Resource wasn't found in the world.
+    #[must_use]
+    pub fn resource_not_present(resource: &str) -> Self {
+        Self {
+            code: error_codes::RESOURCE_NOT_PRESENT,
+            message: format!("Resource `{resource}` not present in the world"),
+            data: None,
+        }
+    }
+
+    /// An arbitrary event error. Possibly related to reflection.
+    #[must_use]
+    pub fn event_error<E: ToString>(error: E) -> Self {
+        Self {
+            code: error_codes::EVENT_ERROR,
+            message: error.to_string(),
+            data: None,
+        }
+    }
+
+    /// No schedule with the given label was found.
+    #[must_use]
+    pub fn schedule_not_found(schedule: &str) -> Self {
+        Self {
+            code: error_codes::SCHEDULE_NOT_FOUND,
+            message: format!("Schedule `{schedule}` not found"),
+            data: None,
+        }
+    }
+
+    /// The client isn't permitted to call the given method.
+    #[must_use]
+    pub fn method_not_permitted(method: &str) -> Self {
+        Self {
+            code: error_codes::METHOD_NOT_PERMITTED,
+            message: format!("Not permitted to call method `{method}`"),
+            data: None,
+        }
+    }
+
+    /// The schedule has no system with the given id.
+    #[must_use]
+    pub fn system_not_found(schedule: &str, system: usize) -> Self {
+        Self {
+            code: error_codes::SYSTEM_NOT_FOUND,
+            message: format!("Schedule `{schedule}` has no system with id {system}"),
+            data: None,
+        }
+    }
+
+    /// BRP holds no handle to the asset at the given path.
+    #[must_use]
+    pub fn asset_not_loaded(path: &str) -> Self {
+        Self {
+            code: error_codes::ASSET_NOT_LOADED,
+            message: format!("Asset `{path}` was not loaded through BRP"),
+            data: None,
+        }
+    }
+
+    /// No function was registered with the given name.
+    #[must_use]
+    pub fn function_not_found(name: &str) -> Self {
+        Self {
+            code: error_codes::FUNCTION_NOT_FOUND,
+            message: format!("Function `{name}` not found"),
+            data: None,
+        }
+    }
+
+    /// An arbitrary error from calling a reflected function.
+    #[must_use]
+    pub fn function_error<E: ToString>(error: E) -> Self {
+        Self {
+            code: error_codes::FUNCTION_ERROR,
+            message: error.to_string(),
+            data: None,
+        }
+    }
 }
 
 /// Error codes used by BRP.
@@ -772,6 +1249,33 @@ pub mod error_codes {
 
     /// Cannot reparent an entity to itself.
     pub const SELF_REPARENT: i16 = -23404;
+
+    /// Could not find a function registered with the given name.
+    pub const FUNCTION_NOT_FOUND: i16 = -23405;
+
+    /// Could not deserialize the arguments of a function, or the function call failed.
+    pub const FUNCTION_ERROR: i16 = -23406;
+
+    /// Could not reflect or find resource.
+    pub const RESOURCE_ERROR: i16 = -23407;
+
+    /// Could not find resource in the world.
+    pub const RESOURCE_NOT_PRESENT: i16 = -23408;
+
+    /// Could not reflect, find or send event.
+    pub const EVENT_ERROR: i16 = -23409;
+
+    /// Could not find a schedule with the given label.
+    pub const SCHEDULE_NOT_FOUND: i16 = -23410;
+
+    /// The client is not permitted to call the method.
+    pub const METHOD_NOT_PERMITTED: i16 = -23411;
+
+    /// The asset was not loaded through BRP.
+    pub const ASSET_NOT_LOADED: i16 = -23412;
+
+    /// Could not find a system with the given id in the schedule.
+    pub const SYSTEM_NOT_FOUND: i16 = -23413;
 }
 
 /// The result of a request.
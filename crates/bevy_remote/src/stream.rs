@@ -0,0 +1,587 @@
+//! BRP transports using JSON-RPC over byte streams: a Unix domain socket, or the standard
+//! input and output of the process.
+//!
+//! Adding the [`RemoteStdioPlugin`] to your [`App`] causes Bevy to read requests from stdin and
+//! write responses to stdout, which suits tools that launch the app as a subprocess, such as
+//! editor integrations. On Unix, the [`RemoteUnixSocketPlugin`] instead accepts connections on a
+//! socket file, so that no TCP port has to be bound.
+//!
+//! Each message is a single request or a batch of requests, delimited according to the
+//! [`Framing`]. As with the WebSocket transport, responses are written as they complete, so
+//! clients should use the `id` field to match responses to requests. Watching requests keep
+//! pushing responses until the client disconnects or cancels them with a
+//! [`BRP_UNWATCH_METHOD`] request.
+
+#![cfg(not(target_family = "wasm"))]
+
+use crate::{connection::serve_messages, BrpMessage, BrpSender};
+use async_channel::{Receiver, Sender};
+use bevy_app::{App, Plugin, Startup};
+use bevy_ecs::{resource::Resource, system::Res};
+use bevy_tasks::{
+    futures_lite::{
+        future,
+        io::{
+            AssertAsync, AsyncBufRead, AsyncBufReadExt as _, AsyncReadExt as _, AsyncWrite,
+            AsyncWriteExt as _, BufReader,
+        },
+        stream,
+    },
+    IoTaskPool,
+};
+use std::{io, thread};
+
+pub use crate::connection::BRP_UNWATCH_METHOD;
+
+#[cfg(unix)]
+pub use unix::*;
+
+/// How messages are delimited within a byte stream.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Framing {
+    /// Each message is a single line of JSON, terminated by `\n`.
+    #[default]
+    NewlineDelimited,
+    /// Each message is preceded by a `Content-Length` header giving its length in bytes and an
+    /// empty line, as in the Language Server Protocol:
+    ///
+    /// ```text
+    /// Content-Length: 45\r\n
+    /// \r\n
+    /// {"jsonrpc":"2.0","method":"bevy/list","id":0}
+    /// ```
+    ContentLength,
+}
+
+/// The largest message, in bytes, that is read from a stream.
+///
+/// A larger message, or a `Content-Length` header announcing one, is rejected with an
+/// [`io::ErrorKind::InvalidData`] error, which ends the connection.
+pub const MAX_MESSAGE_SIZE: usize = 16 * 1024 * 1024;
+
+/// Reads the next message from `reader`, returning [`None`] once the stream has ended.
+///
+/// Messages larger than `max_size` bytes are rejected with an [`io::ErrorKind::InvalidData`]
+/// error.
+async fn read_message(
+    reader: &mut (impl AsyncBufRead + Unpin),
+    framing: Framing,
+    max_size: usize,
+) -> io::Result<Option<Vec<u8>>> {
+    match framing {
+        Framing::NewlineDelimited => loop {
+            let mut line = Vec::new();
+            if read_line(reader, &mut line, max_size).await? == 0 {
+                return Ok(None);
+            }
+            // Skip blank lines, such as the `\r` of a `\r\n` line ending.
+            if !line.trim_ascii().is_empty() {
+                return Ok(Some(line));
+            }
+        },
+        Framing::ContentLength => {
+            let mut content_length = None;
+            loop {
+                let mut line = Vec::new();
+                if read_line(reader, &mut line, max_size).await? == 0 {
+                    return Ok(None);
+                }
+                let line = String::from_utf8_lossy(&line);
+                let line = line.trim();
+                if line.is_empty() {
+                    if content_length.is_some() {
+                        break;
+                    }
+                    continue;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.trim().eq_ignore_ascii_case("Content-Length") {
+                        let length = value.trim().parse::<usize>().map_err(|err| {
+                            io::Error::new(io::ErrorKind::InvalidData, err.to_string())
+                        })?;
+                        if length > max_size {
+                            return Err(message_too_large(max_size));
+                        }
+                        content_length = Some(length);
+                    }
+                }
+            }
+
+            let mut message = vec![0; content_length.unwrap_or_default()];
+            reader.read_exact(&mut message).await?;
+            Ok(Some(message))
+        }
+    }
+}
+
+/// Reads a line, including its `\n`, into `line`, failing if it is longer than `max_size`
+/// bytes (not counting the `\n`).
+async fn read_line(
+    reader: &mut (impl AsyncBufRead + Unpin),
+    line: &mut Vec<u8>,
+    max_size: usize,
+) -> io::Result<usize> {
+    let read = reader
+        .take(max_size as u64 + 1)
+        .read_until(b'\n', line)
+        .await?;
+    if line.len() > max_size && line.last() != Some(&b'\n') {
+        return Err(message_too_large(max_size));
+    }
+    Ok(read)
+}
+
+fn message_too_large(max_size: usize) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        format!("message is larger than {max_size} bytes"),
+    )
+}
+
+/// Writes a single message to `writer`.
+async fn write_message(
+    writer: &mut (impl AsyncWrite + Unpin),
+    framing: Framing,
+    message: &str,
+) -> io::Result<()> {
+    match framing {
+        Framing::NewlineDelimited => {
+            writer.write_all(message.as_bytes()).await?;
+            writer.write_all(b"\n").await?;
+        }
+        Framing::ContentLength => {
+            let header = format!("Content-Length: {}\r\n\r\n", message.len());
+            writer.write_all(header.as_bytes()).await?;
+            writer.write_all(message.as_bytes()).await?;
+        }
+    }
+    writer.flush().await
+}
+
+/// Serves the requests read from `reader` until it ends, writing the responses to `writer`.
+///
+/// Responses are written by a separate task, so that reading is never interrupted midway
+/// through a message.
+async fn serve_stream(
+    reader: impl AsyncBufRead + Unpin,
+    writer: impl AsyncWrite + Unpin + Send + 'static,
+    framing: Framing,
+    request_sender: Sender<BrpMessage>,
+) {
+    let (response_sender, response_receiver) = async_channel::unbounded::<String>();
+    IoTaskPool::get()
+        .spawn(write_responses(writer, framing, response_receiver))
+        .detach();
+
+    let messages = stream::unfold(reader, |mut reader| async move {
+        let message = read_message(&mut reader, framing, MAX_MESSAGE_SIZE)
+            .await
+            .ok()??;
+        Some((message, reader))
+    });
+    serve_messages(Box::pin(messages), &request_sender, &response_sender).await;
+}
+
+/// Writes each response sent to `response_receiver` to `writer`, until the channel closes or
+/// writing fails.
+async fn write_responses(
+    mut writer: impl AsyncWrite + Unpin,
+    framing: Framing,
+    response_receiver: Receiver<String>,
+) {
+    while let Ok(response) = response_receiver.recv().await {
+        if write_message(&mut writer, framing, &response)
+            .await
+            .is_err()
+        {
+            break;
+        }
+    }
+    response_receiver.close();
+}
+
+/// Add this plugin to your [`App`] to accept requests over the standard input and output of the
+/// process. It requires the [`RemotePlugin`](super::RemotePlugin).
+///
+/// Stdout is reserved for responses while this plugin is active, so anything else the app
+/// prints there will corrupt the stream. Bevy's own logs are written to stderr.
+///
+/// This BRP transport cannot be used when targeting WASM.
+#[derive(Debug, Default)]
+pub struct RemoteStdioPlugin {
+    /// How messages are delimited.
+    framing: Framing,
+}
+
+impl RemoteStdioPlugin {
+    /// Set how messages are delimited.
+    #[must_use]
+    pub fn with_framing(mut self, framing: Framing) -> Self {
+        self.framing = framing;
+        self
+    }
+}
+
+impl Plugin for RemoteStdioPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(StdioFraming(self.framing))
+            .add_systems(Startup, start_stdio_server);
+    }
+}
+
+/// A resource containing the [`Framing`] used by the [`RemoteStdioPlugin`].
+#[derive(Debug, Resource)]
+struct StdioFraming(Framing);
+
+/// A system that starts reading requests from stdin.
+///
+/// Stdin and stdout can't be used asynchronously on all platforms, so they are read and written
+/// on dedicated threads.
+fn start_stdio_server(request_sender: Res<BrpSender>, framing: Res<StdioFraming>) {
+    let framing = framing.0;
+    let (message_sender, message_receiver) = async_channel::unbounded::<Vec<u8>>();
+    let (response_sender, response_receiver) = async_channel::unbounded::<String>();
+
+    thread::Builder::new()
+        .name(String::from("BRP stdin"))
+        .spawn(move || {
+            future::block_on(async {
+                let mut stdin = BufReader::new(AssertAsync::new(io::stdin()));
+                while let Ok(Some(message)) =
+                    read_message(&mut stdin, framing, MAX_MESSAGE_SIZE).await
+                {
+                    if message_sender.send(message).await.is_err() {
+                        break;
+                    }
+                }
+            });
+        })
+        .expect("Failed to spawn the BRP stdin thread");
+    thread::Builder::new()
+        .name(String::from("BRP stdout"))
+        .spawn(move || {
+            let stdout = AssertAsync::new(io::stdout());
+            future::block_on(write_responses(stdout, framing, response_receiver));
+        })
+        .expect("Failed to spawn the BRP stdout thread");
+
+    let request_sender = request_sender.clone();
+    IoTaskPool::get()
+        .spawn(async move {
+            serve_messages(
+                Box::pin(message_receiver),
+                &request_sender,
+                &response_sender,
+            )
+            .await;
+        })
+        .detach();
+}
+
+#[cfg(unix)]
+mod unix {
+    use super::{serve_stream, Framing};
+    use crate::{BrpMessage, BrpSender};
+    use alloc::sync::Arc;
+    use anyhow::Result as AnyhowResult;
+    use async_channel::Sender;
+    use async_io::Async;
+    use bevy_app::{App, Plugin, Startup};
+    use bevy_ecs::{resource::Resource, system::Res};
+    use bevy_ecs::{result::Result, system::Commands};
+    use bevy_tasks::{
+        futures_lite::{io::BufReader, AsyncRead, AsyncWrite},
+        IoTaskPool, Task,
+    };
+    use core::{
+        pin::Pin,
+        task::{Context, Poll},
+    };
+    use std::{
+        fs, io,
+        os::unix::{
+            fs::FileTypeExt as _,
+            net::{UnixListener, UnixStream},
+        },
+        path::{Path, PathBuf},
+    };
+
+    /// The default path of the socket file, relative to the working directory.
+    pub const DEFAULT_SOCKET_PATH: &str = "bevy_remote.sock";
+
+    /// Add this plugin to your [`App`] to allow remote connections over a Unix domain socket to
+    /// inspect and modify entities. It requires the [`RemotePlugin`](crate::RemotePlugin).
+    ///
+    /// A socket already at the path is removed when the server starts, so that a socket left
+    /// behind by a previous run doesn't prevent binding. If the path holds anything other than
+    /// a socket, the server fails to start instead. The socket file is removed again when the
+    /// server shuts down, which happens when the app is dropped.
+    ///
+    /// The defaults are:
+    /// - [`DEFAULT_SOCKET_PATH`] : `bevy_remote.sock`.
+    /// - [`Framing::NewlineDelimited`].
+    #[derive(Debug)]
+    pub struct RemoteUnixSocketPlugin {
+        /// The path of the socket file.
+        path: PathBuf,
+        /// How messages are delimited.
+        framing: Framing,
+    }
+
+    impl Default for RemoteUnixSocketPlugin {
+        fn default() -> Self {
+            Self {
+                path: PathBuf::from(DEFAULT_SOCKET_PATH),
+                framing: Framing::default(),
+            }
+        }
+    }
+
+    impl RemoteUnixSocketPlugin {
+        /// Set the path of the socket file.
+        #[must_use]
+        pub fn with_path(mut self, path: impl Into<PathBuf>) -> Self {
+            self.path = path.into();
+            self
+        }
+
+        /// Set how messages are delimited.
+        #[must_use]
+        pub fn with_framing(mut self, framing: Framing) -> Self {
+            self.framing = framing;
+            self
+        }
+    }
+
+    impl Plugin for RemoteUnixSocketPlugin {
+        fn build(&self, app: &mut App) {
+            app.insert_resource(UnixSocketPath(self.path.clone()))
+                .insert_resource(UnixSocketFraming(self.framing))
+                .add_systems(Startup, start_unix_socket_server);
+        }
+    }
+
+    /// A resource containing the path of the socket file that Bevy will listen on.
+    ///
+    /// Currently, changing this while the application is running has no effect; this merely
+    /// reflects the path that is set during the setup of the [`RemoteUnixSocketPlugin`].
+    #[derive(Debug, Resource)]
+    pub struct UnixSocketPath(pub PathBuf);
+
+    /// A resource containing the [`Framing`] used by the [`RemoteUnixSocketPlugin`].
+    #[derive(Debug, Resource)]
+    struct UnixSocketFraming(Framing);
+
+    /// The running Unix socket server, which removes its socket file when dropped.
+    #[derive(Resource)]
+    struct UnixSocketServer {
+        path: PathBuf,
+        _task: Task<AnyhowResult<()>>,
+    }
+
+    impl Drop for UnixSocketServer {
+        fn drop(&mut self) {
+            let _ = fs::remove_file(&self.path);
+        }
+    }
+
+    /// A system that starts up the Bevy Remote Protocol Unix socket server.
+    fn start_unix_socket_server(
+        mut commands: Commands,
+        request_sender: Res<BrpSender>,
+        path: Res<UnixSocketPath>,
+        framing: Res<UnixSocketFraming>,
+    ) -> Result {
+        remove_stale_socket(&path.0)?;
+        let listener = Async::<UnixListener>::bind(&path.0)?;
+        let task =
+            IoTaskPool::get().spawn(server_main(listener, framing.0, request_sender.clone()));
+        commands.insert_resource(UnixSocketServer {
+            path: path.0.clone(),
+            _task: task,
+        });
+        Ok(())
+    }
+
+    /// The Bevy Remote Protocol Unix socket server main loop.
+    async fn server_main(
+        listener: Async<UnixListener>,
+        framing: Framing,
+        request_sender: Sender<BrpMessage>,
+    ) -> AnyhowResult<()> {
+        loop {
+            let (client, _) = listener.accept().await?;
+
+            // The socket is shared by the reading and the writing halves of the connection.
+            let client = Arc::new(client);
+            let request_sender = request_sender.clone();
+            IoTaskPool::get()
+                .spawn(async move {
+                    let reader = BufReader::new(ArcStream(client.clone()));
+                    serve_stream(reader, ArcStream(client), framing, request_sender).await;
+                })
+                .detach();
+        }
+    }
+
+    /// Removes a socket file left behind by a previous run, returning an error if the path holds
+    /// anything other than a socket.
+    fn remove_stale_socket(path: &Path) -> io::Result<()> {
+        match fs::symlink_metadata(path) {
+            Ok(metadata) if metadata.file_type().is_socket() => fs::remove_file(path),
+            Ok(_) => Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} exists and is not a socket", path.display()),
+            )),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(()),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// A handle to a socket shared between the halves of a connection.
+    struct ArcStream(Arc<Async<UnixStream>>);
+
+    impl AsyncRead for ArcStream {
+        fn poll_read(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &mut [u8],
+        ) -> Poll<io::Result<usize>> {
+            Pin::new(&mut &*self.0).poll_read(cx, buf)
+        }
+    }
+
+    impl AsyncWrite for ArcStream {
+        fn poll_write(
+            self: Pin<&mut Self>,
+            cx: &mut Context<'_>,
+            buf: &[u8],
+        ) -> Poll<io::Result<usize>> {
+            Pin::new(&mut &*self.0).poll_write(cx, buf)
+        }
+
+        fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Pin::new(&mut &*self.0).poll_flush(cx)
+        }
+
+        fn poll_close(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+            Pin::new(&mut &*self.0).poll_close(cx)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+        use crate::RemotePlugin;
+        use bevy_tasks::TaskPool;
+
+        fn temp_path(name: &str) -> PathBuf {
+            std::env::temp_dir().join(format!("bevy_remote_{}_{name}", std::process::id()))
+        }
+
+        #[test]
+        fn socket_file_lifecycle() {
+            IoTaskPool::get_or_init(TaskPool::new);
+            let path = temp_path("lifecycle.sock");
+
+            // A socket left behind by a previous run is replaced.
+            drop(UnixListener::bind(&path).unwrap());
+            let mut app = App::new();
+            app.add_plugins((
+                RemotePlugin::default(),
+                RemoteUnixSocketPlugin::default().with_path(&path),
+            ));
+            app.update();
+            UnixStream::connect(&path).unwrap();
+
+            // The socket file is removed once the server shuts down.
+            drop(app);
+            assert!(!path.exists());
+        }
+
+        #[test]
+        fn other_files_are_not_removed() {
+            let path = temp_path("not_a_socket");
+            fs::write(&path, "data").unwrap();
+
+            let err = remove_stale_socket(&path).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+            assert_eq!(fs::read_to_string(&path).unwrap(), "data");
+            fs::remove_file(&path).unwrap();
+
+            remove_stale_socket(&path).unwrap();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_tasks::futures_lite::io::Cursor;
+
+    #[test]
+    fn framing_round_trip() {
+        for framing in [Framing::NewlineDelimited, Framing::ContentLength] {
+            let mut buffer = Cursor::new(Vec::new());
+            future::block_on(async {
+                write_message(&mut buffer, framing, r#"{"id":0}"#)
+                    .await
+                    .unwrap();
+                write_message(&mut buffer, framing, r#"{"id":1}"#)
+                    .await
+                    .unwrap();
+            });
+
+            buffer.set_position(0);
+            let mut reader = BufReader::new(buffer);
+            future::block_on(async {
+                for expected in [r#"{"id":0}"#, r#"{"id":1}"#] {
+                    let message = read_message(&mut reader, framing, MAX_MESSAGE_SIZE)
+                        .await
+                        .unwrap()
+                        .unwrap();
+                    assert_eq!(message.trim_ascii(), expected.as_bytes());
+                }
+                assert!(read_message(&mut reader, framing, MAX_MESSAGE_SIZE)
+                    .await
+                    .unwrap()
+                    .is_none());
+            });
+        }
+    }
+
+    #[test]
+    fn oversized_messages_are_rejected() {
+        future::block_on(async {
+            let mut reader = BufReader::new(Cursor::new(
+                b"Content-Length: 99999999999\r\n\r\n{}".to_vec(),
+            ));
+            let err = read_message(&mut reader, Framing::ContentLength, MAX_MESSAGE_SIZE)
+                .await
+                .unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+            // Without a newline, the reader stops once the line exceeds the limit.
+            let mut reader = BufReader::new(Cursor::new(vec![b'x'; 64]));
+            let err = read_message(&mut reader, Framing::NewlineDelimited, 16)
+                .await
+                .unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+            // A header line can't be used to send an unbounded message either.
+            let mut reader = BufReader::new(Cursor::new(vec![b'x'; 64]));
+            let err = read_message(&mut reader, Framing::ContentLength, 16)
+                .await
+                .unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+
+            // A message of exactly the maximum size is accepted.
+            let mut reader = BufReader::new(Cursor::new(b"0123456789abcdef\n".to_vec()));
+            let message = read_message(&mut reader, Framing::NewlineDelimited, 16)
+                .await
+                .unwrap()
+                .unwrap();
+            assert_eq!(message, b"0123456789abcdef\n");
+        });
+    }
+}
@@ -0,0 +1,160 @@
+//! Per-system timing, reported by the `bevy/schedule/graph` method.
+//!
+//! Systems are timed using the `tracing` spans that `bevy_ecs` enters around every system run,
+//! so recording timings requires adding [`system_timing_layer`] as the `custom_layer` of the
+//! `LogPlugin`:
+//!
+//! ```ignore
+//! App::new().add_plugins(DefaultPlugins.set(LogPlugin {
+//!     custom_layer: bevy_remote::timing::system_timing_layer,
+//!     ..default()
+//! }));
+//! ```
+
+use alloc::sync::Arc;
+use bevy_app::App;
+use bevy_ecs::resource::Resource;
+use bevy_platform_support::{collections::HashMap, time::Instant};
+use core::{fmt, time::Duration};
+use std::sync::Mutex;
+use tracing::{
+    field::{Field, Visit},
+    span::{Attributes, Id},
+    Subscriber,
+};
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer, Registry};
+
+/// The name of the span that `bevy_ecs` enters around every system run.
+const SYSTEM_SPAN_NAME: &str = "system";
+
+/// How long each system took the last time it ran, keyed by system name.
+///
+/// Systems with the same name share an entry, so a system added to several schedules reports
+/// the duration of its most recent run in any of them.
+#[derive(Resource, Clone, Default, Debug)]
+pub struct SystemTimings(Arc<Mutex<HashMap<String, Duration>>>);
+
+impl SystemTimings {
+    /// Returns how long the system with the given name took the last time it ran.
+    pub fn get(&self, name: &str) -> Option<Duration> {
+        self.0.lock().ok()?.get(name).copied()
+    }
+
+    fn record(&self, name: &str, duration: Duration) {
+        if let Ok(mut timings) = self.0.lock() {
+            match timings.get_mut(name) {
+                Some(timing) => *timing = duration,
+                None => {
+                    timings.insert(name.to_owned(), duration);
+                }
+            }
+        }
+    }
+}
+
+/// A `tracing` [`Layer`] that records [`SystemTimings`].
+#[derive(Clone, Default, Debug)]
+pub struct SystemTimingLayer {
+    timings: SystemTimings,
+}
+
+impl SystemTimingLayer {
+    /// Creates a layer which records its timings in the given [`SystemTimings`].
+    pub fn new(timings: SystemTimings) -> Self {
+        Self { timings }
+    }
+}
+
+/// Creates a [`SystemTimingLayer`] and inserts its [`SystemTimings`] into the app.
+///
+/// This has the signature of the `custom_layer` field of the `LogPlugin`.
+pub fn system_timing_layer(app: &mut App) -> Option<Box<dyn Layer<Registry> + Send + Sync>> {
+    let timings = SystemTimings::default();
+    app.insert_resource(timings.clone());
+    Some(Box::new(SystemTimingLayer::new(timings)))
+}
+
+/// The state stored in the extensions of a system span.
+struct SystemSpan {
+    name: String,
+    entered_at: Option<Instant>,
+}
+
+/// Extracts the `name` field of a system span.
+#[derive(Default)]
+struct SystemNameVisitor(Option<String>);
+
+impl Visit for SystemNameVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        if field.name() == "name" {
+            self.0 = Some(value.to_owned());
+        }
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "name" {
+            self.0 = Some(format!("{value:?}"));
+        }
+    }
+}
+
+impl<S> Layer<S> for SystemTimingLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let metadata = attrs.metadata();
+        if metadata.name() != SYSTEM_SPAN_NAME || !metadata.target().starts_with("bevy_ecs") {
+            return;
+        }
+        let mut visitor = SystemNameVisitor::default();
+        attrs.record(&mut visitor);
+        if let (Some(name), Some(span)) = (visitor.0, ctx.span(id)) {
+            span.extensions_mut().insert(SystemSpan {
+                name,
+                entered_at: None,
+            });
+        }
+    }
+
+    fn on_enter(&self, id: &Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(system) = span.extensions_mut().get_mut::<SystemSpan>() {
+                system.entered_at = Some(Instant::now());
+            }
+        }
+    }
+
+    fn on_exit(&self, id: &Id, ctx: Context<'_, S>) {
+        if let Some(span) = ctx.span(id) {
+            if let Some(system) = span.extensions_mut().get_mut::<SystemSpan>() {
+                if let Some(entered_at) = system.entered_at.take() {
+                    self.timings.record(&system.name, entered_at.elapsed());
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tracing_subscriber::layer::SubscriberExt as _;
+
+    #[test]
+    fn records_system_spans() {
+        let timings = SystemTimings::default();
+        let subscriber = Registry::default().with(SystemTimingLayer::new(timings.clone()));
+        tracing::subscriber::with_default(subscriber, || {
+            let span =
+                tracing::info_span!(target: "bevy_ecs::system", "system", name = "my_system");
+            span.in_scope(|| std::thread::sleep(Duration::from_millis(2)));
+
+            let other = tracing::info_span!("system", name = "not_a_system");
+            other.in_scope(|| {});
+        });
+
+        assert!(timings.get("my_system").unwrap() >= Duration::from_millis(2));
+        assert!(timings.get("not_a_system").is_none());
+    }
+}
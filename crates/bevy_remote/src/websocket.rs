@@ -0,0 +1,343 @@
+//! The BRP transport using JSON-RPC over WebSocket connections.
+//!
+//! Adding the [`RemoteWebSocketPlugin`] to your [`App`] causes Bevy to accept
+//! WebSocket connections (by default, on port 15703) while your app is running.
+//!
+//! Unlike the HTTP transport, a single WebSocket connection can have any number of
+//! requests in flight at once. Each text message sent by the client is a single request
+//! or a batch of requests; responses are sent back as they complete, so clients should
+//! use the `id` field to match responses to requests.
+//!
+//! Watching methods (those whose name contains `+watch`) stay active until the client
+//! disconnects or cancels them. Every time the watched data changes the server pushes a
+//! new response message carrying the `id` of the original request. A watch can be
+//! cancelled by sending a [`BRP_UNWATCH_METHOD`] request:
+//!
+//! ```json
+//! {
+//!     "jsonrpc": "2.0",
+//!     "method": "rpc/unwatch",
+//!     "id": 1,
+//!     "params": { "id": 0 }
+//! }
+//! ```
+//!
+//! where `params.id` is the `id` of the watching request to cancel.
+
+#![cfg(not(target_family = "wasm"))]
+
+use crate::{connection::process_message, BrpMessage, BrpResult, BrpSender};
+use anyhow::Result as AnyhowResult;
+use async_channel::{Receiver, Sender};
+use async_io::Async;
+use async_tungstenite::{
+    tungstenite::{Error as WsError, Message},
+    WebSocketStream,
+};
+use bevy_app::{App, Plugin, Startup};
+use bevy_ecs::{resource::Resource, system::Res};
+use bevy_platform_support::collections::HashMap;
+use bevy_tasks::{futures_lite::future, IoTaskPool};
+use core::net::{IpAddr, Ipv4Addr};
+use futures_util::StreamExt as _;
+use std::net::{TcpListener, TcpStream};
+
+pub use crate::connection::BRP_UNWATCH_METHOD;
+
+/// The default port that Bevy will listen on for WebSocket connections.
+///
+/// This is one more than the default HTTP port, so that both transports can be enabled at once.
+pub const DEFAULT_PORT: u16 = 15703;
+
+/// The default host address that Bevy will use for its WebSocket server.
+pub const DEFAULT_ADDR: IpAddr = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1));
+
+/// Add this plugin to your [`App`] to allow remote WebSocket connections to inspect and
+/// modify entities. It requires the [`RemotePlugin`](super::RemotePlugin).
+///
+/// This BRP transport cannot be used when targeting WASM.
+///
+/// The defaults are:
+/// - [`DEFAULT_ADDR`] : 127.0.0.1.
+/// - [`DEFAULT_PORT`] : 15703.
+pub struct RemoteWebSocketPlugin {
+    /// The address that Bevy will bind to.
+    address: IpAddr,
+    /// The port that Bevy will listen on.
+    port: u16,
+}
+
+impl Default for RemoteWebSocketPlugin {
+    fn default() -> Self {
+        Self {
+            address: DEFAULT_ADDR,
+            port: DEFAULT_PORT,
+        }
+    }
+}
+
+impl Plugin for RemoteWebSocketPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(WebSocketHostAddress(self.address))
+            .insert_resource(WebSocketHostPort(self.port))
+            .add_systems(Startup, start_websocket_server);
+    }
+}
+
+impl RemoteWebSocketPlugin {
+    /// Set the IP address that the server will use.
+    #[must_use]
+    pub fn with_address(mut self, address: impl Into<IpAddr>) -> Self {
+        self.address = address.into();
+        self
+    }
+
+    /// Set the remote port that the server will listen on.
+    #[must_use]
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+}
+
+/// A resource containing the IP address that the WebSocket server will host on.
+///
+/// Currently, changing this while the application is running has no effect; this merely
+/// reflects the IP address that is set during the setup of the [`RemoteWebSocketPlugin`].
+#[derive(Debug, Resource)]
+pub struct WebSocketHostAddress(pub IpAddr);
+
+/// A resource containing the port number that the WebSocket server will listen on.
+///
+/// Currently, changing this while the application is running has no effect; this merely
+/// reflects the port that is set during the setup of the [`RemoteWebSocketPlugin`].
+#[derive(Debug, Resource)]
+pub struct WebSocketHostPort(pub u16);
+
+/// A system that starts up the Bevy Remote Protocol WebSocket server.
+fn start_websocket_server(
+    request_sender: Res<BrpSender>,
+    address: Res<WebSocketHostAddress>,
+    remote_port: Res<WebSocketHostPort>,
+) {
+    IoTaskPool::get()
+        .spawn(server_main(
+            address.0,
+            remote_port.0,
+            request_sender.clone(),
+        ))
+        .detach();
+}
+
+/// The Bevy Remote Protocol WebSocket server main loop.
+async fn server_main(
+    address: IpAddr,
+    port: u16,
+    request_sender: Sender<BrpMessage>,
+) -> AnyhowResult<()> {
+    let listener = Async::<TcpListener>::bind((address, port))?;
+    loop {
+        let (client, _) = listener.accept().await?;
+
+        let request_sender = request_sender.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                let _ = handle_client(client, request_sender).await;
+            })
+            .detach();
+    }
+}
+
+/// An event in the main loop of a single WebSocket connection.
+enum ConnectionEvent {
+    /// A message was received from the client, or the connection was closed.
+    Incoming(Option<Result<Message, WsError>>),
+    /// A serialized response is ready to be sent to the client.
+    Outgoing(String),
+}
+
+/// Handles a single WebSocket connection until the client disconnects.
+async fn handle_client(
+    client: Async<TcpStream>,
+    request_sender: Sender<BrpMessage>,
+) -> AnyhowResult<()> {
+    let mut websocket = async_tungstenite::accept_async(client).await?;
+
+    // Responses are produced by tasks running concurrently with this loop, and are funneled
+    // through this channel so that only this loop writes to the socket.
+    let (response_sender, response_receiver) = async_channel::unbounded::<String>();
+
+    // The ongoing watching requests of this connection, keyed by their serialized `id`.
+    let mut watchers = <HashMap<String, Receiver<BrpResult>>>::default();
+
+    // Which of the two event sources gets polled first, alternated every iteration so that
+    // neither a busy client nor a busy watching request can starve the other.
+    let mut outgoing_first = false;
+
+    let result = loop {
+        let incoming = async { ConnectionEvent::Incoming(websocket.next().await) };
+        let outgoing = async {
+            // `response_sender` is held by this function, so the channel never closes.
+            ConnectionEvent::Outgoing(response_receiver.recv().await.unwrap_or_default())
+        };
+        let event = if outgoing_first {
+            future::or(outgoing, incoming).await
+        } else {
+            future::or(incoming, outgoing).await
+        };
+        outgoing_first = !outgoing_first;
+
+        match event {
+            ConnectionEvent::Incoming(Some(Ok(Message::Text(text)))) => {
+                process_message(
+                    text.as_bytes(),
+                    &request_sender,
+                    &response_sender,
+                    &mut watchers,
+                )
+                .await;
+            }
+            ConnectionEvent::Incoming(Some(Ok(Message::Binary(bytes)))) => {
+                process_message(&bytes, &request_sender, &response_sender, &mut watchers).await;
+            }
+            ConnectionEvent::Incoming(Some(Ok(Message::Close(_))) | None) => break Ok(()),
+            // Pings are answered automatically when the socket is next read or written.
+            ConnectionEvent::Incoming(Some(Ok(_))) => {}
+            ConnectionEvent::Incoming(Some(Err(err))) => break Err(err.into()),
+            ConnectionEvent::Outgoing(response) => {
+                if let Err(err) = send_text(&mut websocket, response).await {
+                    break Err(err);
+                }
+            }
+        }
+
+        watchers.retain(|_, receiver| !receiver.is_closed());
+    };
+
+    // Closing the channels lets the `RemotePlugin` clean up the watching requests.
+    for receiver in watchers.values() {
+        receiver.close();
+    }
+
+    result
+}
+
+/// Sends a single text message over the socket.
+async fn send_text(
+    websocket: &mut WebSocketStream<Async<TcpStream>>,
+    text: String,
+) -> AnyhowResult<()> {
+    websocket.send(Message::Text(text)).await?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BrpError;
+    use async_tungstenite::client_async;
+    use bevy_tasks::TaskPool;
+    use serde_json::{json, Value};
+
+    /// Reads the next message sent by the server and parses it as JSON.
+    async fn receive(client: &mut WebSocketStream<Async<TcpStream>>) -> Value {
+        match client.next().await {
+            Some(Ok(Message::Text(text))) => serde_json::from_str(&text).unwrap(),
+            message => panic!("expected a text message, got {message:?}"),
+        }
+    }
+
+    #[test]
+    fn request_response_and_watch() {
+        let pool = IoTaskPool::get_or_init(TaskPool::new);
+        pool.with_local_executor(|executor| {
+            future::block_on(executor.run(async {
+                let listener = Async::<TcpListener>::bind((DEFAULT_ADDR, 0)).unwrap();
+                let address = listener.get_ref().local_addr().unwrap();
+                let (request_sender, request_receiver) = async_channel::unbounded();
+                let server = IoTaskPool::get().spawn(async move {
+                    let (client, _) = listener.accept().await.unwrap();
+                    handle_client(client, request_sender).await
+                });
+
+                // The upgrade handshake.
+                let stream = Async::<TcpStream>::connect(address).await.unwrap();
+                let (mut client, _) = client_async(format!("ws://{address}"), stream)
+                    .await
+                    .unwrap();
+
+                // An instant request gets a single response.
+                client
+                    .send(Message::Text(
+                        json!({ "jsonrpc": "2.0", "method": "bevy/list", "id": 1 }).to_string(),
+                    ))
+                    .await
+                    .unwrap();
+                let message = request_receiver.recv().await.unwrap();
+                assert_eq!(message.method, "bevy/list");
+                message.sender.send(Ok(json!(["a"]))).await.unwrap();
+                assert_eq!(
+                    receive(&mut client).await,
+                    json!({ "jsonrpc": "2.0", "id": 1, "result": ["a"] })
+                );
+
+                // Errors are sent back with the id of the request.
+                client
+                    .send(Message::Text(
+                        json!({ "jsonrpc": "2.0", "method": "bevy/get", "id": 2 }).to_string(),
+                    ))
+                    .await
+                    .unwrap();
+                let message = request_receiver.recv().await.unwrap();
+                message
+                    .sender
+                    .send(Err(BrpError::internal("failed")))
+                    .await
+                    .unwrap();
+                let response = receive(&mut client).await;
+                assert_eq!(response["id"], json!(2));
+                assert_eq!(response["error"]["message"], json!("failed"));
+
+                // A watching request pushes a message every time it produces a result.
+                client
+                    .send(Message::Text(
+                        json!({ "jsonrpc": "2.0", "method": "bevy/get+watch", "id": 3 })
+                            .to_string(),
+                    ))
+                    .await
+                    .unwrap();
+                let watch = request_receiver.recv().await.unwrap();
+                assert_eq!(watch.method, "bevy/get+watch");
+                for value in [0, 1] {
+                    watch.sender.send(Ok(json!(value))).await.unwrap();
+                    assert_eq!(
+                        receive(&mut client).await,
+                        json!({ "jsonrpc": "2.0", "id": 3, "result": value })
+                    );
+                }
+
+                // Unwatching closes the channel of the watching request.
+                client
+                    .send(Message::Text(
+                        json!({
+                            "jsonrpc": "2.0",
+                            "method": BRP_UNWATCH_METHOD,
+                            "id": 4,
+                            "params": { "id": 3 },
+                        })
+                        .to_string(),
+                    ))
+                    .await
+                    .unwrap();
+                assert_eq!(
+                    receive(&mut client).await,
+                    json!({ "jsonrpc": "2.0", "id": 4, "result": null })
+                );
+                assert!(watch.sender.is_closed());
+
+                client.close(None).await.unwrap();
+                server.await.unwrap();
+            }));
+        });
+    }
+}
@@ -62,6 +62,7 @@ impl Plugin for MeshPlugin {
         app.init_asset::<Mesh>()
             .init_asset::<skinning::SkinnedMeshInverseBindposes>()
             .register_asset_reflect::<Mesh>()
+            .init_asset_loader::<MeshLoader>()
             .register_type::<Mesh3d>()
             .register_type::<skinning::SkinnedMesh>()
             .register_type::<Vec<Entity>>()
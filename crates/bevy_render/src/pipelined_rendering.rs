@@ -1,12 +1,4 @@
-use async_channel::{Receiver, Sender};
-
-use bevy_app::{App, AppExit, AppLabel, Plugin, SubApp};
-use bevy_ecs::{
-    resource::Resource,
-    schedule::MainThreadExecutor,
-    world::{Mut, World},
-};
-use bevy_tasks::ComputeTaskPool;
+use bevy_app::{App, AppLabel, PipelinedSubAppChannels, PipelinedSubAppPlugin, Plugin};
 
 use crate::RenderApp;
 
@@ -18,52 +10,7 @@ use crate::RenderApp;
 pub struct RenderExtractApp;
 
 /// Channels used by the main app to send and receive the render app.
-#[derive(Resource)]
-pub struct RenderAppChannels {
-    app_to_render_sender: Sender<SubApp>,
-    render_to_app_receiver: Receiver<SubApp>,
-    render_app_in_render_thread: bool,
-}
-
-impl RenderAppChannels {
-    /// Create a `RenderAppChannels` from a [`async_channel::Receiver`] and [`async_channel::Sender`]
-    pub fn new(
-        app_to_render_sender: Sender<SubApp>,
-        render_to_app_receiver: Receiver<SubApp>,
-    ) -> Self {
-        Self {
-            app_to_render_sender,
-            render_to_app_receiver,
-            render_app_in_render_thread: false,
-        }
-    }
-
-    /// Send the `render_app` to the rendering thread.
-    pub fn send_blocking(&mut self, render_app: SubApp) {
-        self.app_to_render_sender.send_blocking(render_app).unwrap();
-        self.render_app_in_render_thread = true;
-    }
-
-    /// Receive the `render_app` from the rendering thread.
-    /// Return `None` if the render thread has panicked.
-    pub async fn recv(&mut self) -> Option<SubApp> {
-        let render_app = self.render_to_app_receiver.recv().await.ok()?;
-        self.render_app_in_render_thread = false;
-        Some(render_app)
-    }
-}
-
-impl Drop for RenderAppChannels {
-    fn drop(&mut self) {
-        if self.render_app_in_render_thread {
-            // Any non-send data in the render world was initialized on the main thread.
-            // So on dropping the main world and ending the app, we block and wait for
-            // the render world to return to drop it. Which allows the non-send data
-            // drop methods to run on the correct thread.
-            self.render_to_app_receiver.recv_blocking().ok();
-        }
-    }
-}
+pub type RenderAppChannels = PipelinedSubAppChannels<RenderApp>;
 
 /// The [`PipelinedRenderingPlugin`] can be added to your application to enable pipelined rendering.
 ///
@@ -81,6 +28,10 @@ impl Drop for RenderAppChannels {
 /// The plugin is dependent on the [`RenderApp`] added by [`crate::RenderPlugin`] and so must
 /// be added after that plugin. If it is not added after, the plugin will do nothing.
 ///
+/// This is a [`PipelinedSubAppPlugin`] for the [`RenderApp`], with [`RenderExtractApp`] as
+/// its proxy sub-app. With the `trace` feature, the render thread is recorded under a
+/// `pipelined sub app thread` span named `RenderApp`.
+///
 /// A single frame of execution looks something like below
 ///
 /// ```text
@@ -114,91 +65,6 @@ impl Plugin for PipelinedRenderingPlugin {
         if app.get_sub_app(RenderApp).is_none() {
             return;
         }
-        app.insert_resource(MainThreadExecutor::new());
-
-        let mut sub_app = SubApp::new();
-        sub_app.set_extract(renderer_extract);
-        app.insert_sub_app(RenderExtractApp, sub_app);
+        app.add_plugins(PipelinedSubAppPlugin::new(RenderApp, RenderExtractApp));
     }
-
-    // Sets up the render thread and inserts resources into the main app used for controlling the render thread.
-    fn cleanup(&self, app: &mut App) {
-        // skip setting up when headless
-        if app.get_sub_app(RenderExtractApp).is_none() {
-            return;
-        }
-
-        let (app_to_render_sender, app_to_render_receiver) = async_channel::bounded::<SubApp>(1);
-        let (render_to_app_sender, render_to_app_receiver) = async_channel::bounded::<SubApp>(1);
-
-        let mut render_app = app
-            .remove_sub_app(RenderApp)
-            .expect("Unable to get RenderApp. Another plugin may have removed the RenderApp before PipelinedRenderingPlugin");
-
-        // clone main thread executor to render world
-        let executor = app.world().get_resource::<MainThreadExecutor>().unwrap();
-        render_app.world_mut().insert_resource(executor.clone());
-
-        render_to_app_sender.send_blocking(render_app).unwrap();
-
-        app.insert_resource(RenderAppChannels::new(
-            app_to_render_sender,
-            render_to_app_receiver,
-        ));
-
-        std::thread::spawn(move || {
-            #[cfg(feature = "trace")]
-            let _span = tracing::info_span!("render thread").entered();
-
-            let compute_task_pool = ComputeTaskPool::get();
-            loop {
-                // run a scope here to allow main world to use this thread while it's waiting for the render app
-                let sent_app = compute_task_pool
-                    .scope(|s| {
-                        s.spawn(async { app_to_render_receiver.recv().await });
-                    })
-                    .pop();
-                let Some(Ok(mut render_app)) = sent_app else {
-                    break;
-                };
-
-                {
-                    #[cfg(feature = "trace")]
-                    let _sub_app_span = tracing::info_span!("sub app", name = ?RenderApp).entered();
-                    render_app.update();
-                }
-
-                if render_to_app_sender.send_blocking(render_app).is_err() {
-                    break;
-                }
-            }
-
-            tracing::debug!("exiting pipelined rendering thread");
-        });
-    }
-}
-
-// This function waits for the rendering world to be received,
-// runs extract, and then sends the rendering world back to the render thread.
-fn renderer_extract(app_world: &mut World, _world: &mut World) {
-    app_world.resource_scope(|world, main_thread_executor: Mut<MainThreadExecutor>| {
-        world.resource_scope(|world, mut render_channels: Mut<RenderAppChannels>| {
-            // we use a scope here to run any main thread tasks that the render world still needs to run
-            // while we wait for the render world to be received.
-            if let Some(mut render_app) = ComputeTaskPool::get()
-                .scope_with_executor(true, Some(&*main_thread_executor.0), |s| {
-                    s.spawn(async { render_channels.recv().await });
-                })
-                .pop()
-                .unwrap()
-            {
-                render_app.extract(world);
-
-                render_channels.send_blocking(render_app);
-            } else {
-                // Renderer thread panicked
-                world.send_event(AppExit::error());
-            }
-        });
-    });
 }
@@ -21,6 +21,9 @@ use alloc::sync::Arc;
 
 use bevy_platform_support::sync::Mutex;
 
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::Reflect;
+
 mod event;
 mod monitor;
 mod raw_handle;
@@ -63,6 +66,7 @@ impl Default for WindowPlugin {
 }
 
 /// A [`Plugin`] that defines an interface for windowing support in Bevy.
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
 pub struct WindowPlugin {
     /// Settings for the primary window.
     ///
@@ -169,10 +173,21 @@ impl Plugin for WindowPlugin {
         app.register_type::<Window>()
             .register_type::<PrimaryWindow>();
     }
+
+    #[cfg(feature = "bevy_reflect")]
+    fn settings_mut(&mut self) -> Option<&mut dyn bevy_reflect::PartialReflect> {
+        Some(self)
+    }
+
+    #[cfg(feature = "bevy_reflect")]
+    fn register_settings(&self, registry: &mut bevy_reflect::TypeRegistry) {
+        registry.register::<Self>();
+    }
 }
 
 /// Defines the specific conditions the application should exit on
 #[derive(Clone)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
 pub enum ExitCondition {
     /// Close application when the primary window is closed
     ///
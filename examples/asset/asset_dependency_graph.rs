@@ -0,0 +1,141 @@
+//! Shows how to inspect the assets kept alive by the [`AssetServer`] with [`AssetServer::dependency_graph`].
+//!
+//! The graph is printed in the [DOT](https://graphviz.org/doc/info/lang.html) format, which can be rendered with
+//! Graphviz:
+//!
+//! ```sh
+//! cargo run --example asset_dependency_graph | dot -Tsvg -o assets.svg
+//! ```
+//!
+//! Pass `--json` to print the graph as JSON instead. A [`LeakReport`] of the assets that aren't reachable from the
+//! current level is logged as well: the menu bundle is kept alive by a forgotten handle, so it shows up as leaked.
+
+use argh::FromArgs;
+use bevy::{
+    asset::{io::Reader, AssetLoader, LeakReport, LoadContext},
+    log::LogPlugin,
+    prelude::*,
+    reflect::TypePath,
+};
+use serde::Deserialize;
+use thiserror::Error;
+
+#[derive(FromArgs, Resource)]
+/// `asset_dependency_graph` prints the dependency graph of the loaded assets
+struct Args {
+    /// print the graph as JSON instead of DOT
+    #[argh(switch)]
+    json: bool,
+}
+
+/// A bundle of assets that includes other bundles.
+#[derive(Asset, TypePath, Debug)]
+struct Bundle {
+    #[dependency]
+    includes: Vec<Handle<Bundle>>,
+}
+
+/// The contents of a `.bundle.ron` file.
+#[derive(Deserialize)]
+struct BundleFile {
+    includes: Vec<String>,
+}
+
+#[derive(Default)]
+struct BundleLoader;
+
+/// Possible errors that can be produced by [`BundleLoader`]
+#[non_exhaustive]
+#[derive(Debug, Error)]
+enum BundleLoaderError {
+    /// An [IO](std::io) Error
+    #[error("Could not load asset: {0}")]
+    Io(#[from] std::io::Error),
+    /// A [RON](ron) Error
+    #[error("Could not parse RON: {0}")]
+    RonSpannedError(#[from] ron::error::SpannedError),
+}
+
+impl AssetLoader for BundleLoader {
+    type Asset = Bundle;
+    type Settings = ();
+    type Error = BundleLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        let file = ron::de::from_bytes::<BundleFile>(&bytes)?;
+        Ok(Bundle {
+            includes: file
+                .includes
+                .into_iter()
+                .map(|path| load_context.load(path))
+                .collect(),
+        })
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["bundle.ron"]
+    }
+}
+
+/// The handles that are expected to be alive: the root of the current level.
+#[derive(Resource)]
+struct Level(Handle<Bundle>);
+
+/// A handle that should have been dropped when the menu was closed.
+#[derive(Resource)]
+struct ForgottenMenu(#[expect(dead_code, reason = "Only held to leak the menu")] Handle<Bundle>);
+
+fn main() -> AppExit {
+    let args: Args = argh::from_env();
+
+    App::new()
+        .add_plugins((MinimalPlugins, LogPlugin::default(), AssetPlugin::default()))
+        .init_asset::<Bundle>()
+        .register_asset_loader(BundleLoader)
+        .insert_resource(args)
+        .add_systems(Startup, load_bundles)
+        .add_systems(Update, print_dependency_graph)
+        .run()
+}
+
+fn load_bundles(mut commands: Commands, asset_server: Res<AssetServer>) {
+    commands.insert_resource(Level(
+        asset_server.load("data/dependency_graph/level.bundle.ron"),
+    ));
+    commands.insert_resource(ForgottenMenu(
+        asset_server.load("data/dependency_graph/menu.bundle.ron"),
+    ));
+}
+
+fn print_dependency_graph(
+    args: Res<Args>,
+    level: Res<Level>,
+    asset_server: Res<AssetServer>,
+    mut exit: EventWriter<AppExit>,
+) {
+    if !asset_server.is_loaded_with_dependencies(&level.0) {
+        return;
+    }
+
+    let graph = asset_server.dependency_graph();
+    if args.json {
+        println!("{}", graph.to_json());
+    } else {
+        print!("{}", graph.to_dot());
+    }
+
+    let report: LeakReport = graph.leak_report([level.0.id()]);
+    if report.is_empty() {
+        info!("{report}");
+    } else {
+        warn!("{report}");
+    }
+    exit.send(AppExit::Success);
+}
@@ -0,0 +1,135 @@
+//! Shows how to rewrite outdated `.meta` files with [`AssetServer::migrate_meta_files`].
+//!
+//! When the settings of an [`AssetLoader`] change, its [`AssetLoader::SETTINGS_VERSION`] is bumped and a migration from
+//! the previous version is registered. Outdated meta files are migrated every time they are loaded, and this command
+//! rewrites them on disk, so that the old settings types and their migrations can eventually be removed.
+//!
+//! Run it on an asset folder with:
+//!
+//! ```sh
+//! cargo run --example migrate_meta_files -- path/to/assets
+//! ```
+//!
+//! A `greeting.txt.meta` file written before `GreetingSettings` had a `suffix` looks like this:
+//!
+//! ```ron
+//! (
+//!     meta_format_version: "1.0",
+//!     asset: Load(
+//!         loader: "migrate_meta_files::GreetingLoader",
+//!         settings: (
+//!             exclaim: true,
+//!         ),
+//!     ),
+//! )
+//! ```
+
+use argh::FromArgs;
+use bevy::{
+    asset::{
+        io::{AssetSourceId, Reader},
+        AssetLoader, LoadContext,
+    },
+    log::LogPlugin,
+    prelude::*,
+    reflect::TypePath,
+    tasks::block_on,
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(FromArgs)]
+/// `migrate_meta_files` rewrites the outdated `.meta` files of an asset folder
+struct Args {
+    #[argh(positional)]
+    /// the asset folder to migrate, relative to the crate root
+    folder: String,
+}
+
+#[derive(Asset, TypePath, Debug)]
+struct Greeting(
+    #[expect(
+        dead_code,
+        reason = "Greetings are never loaded by this example, only their meta files are migrated"
+    )]
+    String,
+);
+
+/// The current settings of [`GreetingLoader`].
+#[derive(Serialize, Deserialize, Default)]
+struct GreetingSettings {
+    /// Appended to the greeting.
+    suffix: String,
+}
+
+/// The settings of [`GreetingLoader`] at version 0.
+#[derive(Deserialize)]
+struct GreetingSettingsV0 {
+    exclaim: bool,
+}
+
+#[derive(Default)]
+struct GreetingLoader;
+
+impl AssetLoader for GreetingLoader {
+    type Asset = Greeting;
+    type Settings = GreetingSettings;
+    type Error = std::io::Error;
+    // Version 1 replaced `exclaim` with `suffix`.
+    const SETTINGS_VERSION: u32 = 1;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        settings: &GreetingSettings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(Greeting(
+            String::from_utf8_lossy(&bytes).into_owned() + &settings.suffix,
+        ))
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["txt"]
+    }
+}
+
+fn main() -> AppExit {
+    let args: Args = argh::from_env();
+
+    App::new()
+        .add_plugins((
+            MinimalPlugins,
+            LogPlugin::default(),
+            AssetPlugin {
+                file_path: args.folder,
+                ..default()
+            },
+        ))
+        .init_asset::<Greeting>()
+        .register_asset_loader(GreetingLoader)
+        .register_asset_loader_migration::<GreetingLoader, _, _>(0, |old: GreetingSettingsV0| {
+            GreetingSettings {
+                suffix: if old.exclaim { "!" } else { "" }.to_string(),
+            }
+        })
+        .add_systems(Startup, migrate_meta_files)
+        .run()
+}
+
+fn migrate_meta_files(asset_server: Res<AssetServer>, mut exit: EventWriter<AppExit>) {
+    match block_on(asset_server.migrate_meta_files(AssetSourceId::Default)) {
+        Ok(migrated) => {
+            info!("Migrated {} meta files", migrated.len());
+            for path in migrated {
+                info!("  {path}");
+            }
+            exit.send(AppExit::Success);
+        }
+        Err(err) => {
+            error!("Failed to migrate meta files: {err}");
+            exit.send(AppExit::error());
+        }
+    }
+}
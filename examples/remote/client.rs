@@ -59,6 +59,8 @@ fn main() -> AnyhowResult<()> {
                 },
                 strict: false,
                 filter: BrpQueryFilter::default(),
+                limit: None,
+                after: None,
             })
             .expect("Unable to convert query parameters to a valid JSON value"),
         ),
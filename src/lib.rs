@@ -55,3 +55,6 @@ pub use bevy_internal::*;
     reason = "This causes bevy to be compiled as a dylib when using dynamic linking, and as such cannot be removed or changed without affecting dynamic linking."
 )]
 use bevy_dylib;
+
+#[cfg(all(feature = "hot_reload", not(target_family = "wasm")))]
+pub use bevy_dylib::hot_reload;